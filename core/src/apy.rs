@@ -0,0 +1,156 @@
+//! Exchange rate and APY estimation.
+//!
+//! All rates are returned as `f64`s since they are only meant for display
+//! and should not be used for any onchain calculation.
+//!
+//! The SOL-per-LST exchange rate of an LST holder grows by exactly
+//! `gross_reward_rate * (1 - epoch_fee)` each epoch since the epoch fee
+//! is minted as pool tokens worth `epoch_fee` of the epoch's rewards.
+
+use crate::{Fee, StakePool};
+
+pub const SECONDS_PER_YEAR: f64 = 365.25 * 24.0 * 60.0 * 60.0;
+
+/// Change in SOL-per-LST exchange rate between 2 snapshots of the same stake pool
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[cfg_attr(
+    feature = "wasm",
+    derive(tsify_next::Tsify),
+    tsify(into_wasm_abi, from_wasm_abi, large_number_types_as_bigints)
+)]
+pub struct RateChange {
+    /// Number of epochs between the 2 snapshots
+    pub epochs: u64,
+
+    /// `end_rate / start_rate - 1`, e.g. `0.0005` for a 0.05% increase
+    pub rate_change: f64,
+}
+
+impl RateChange {
+    /// Annualizes this rate change, compounding every epoch,
+    /// given the average duration of an epoch.
+    ///
+    /// Returns `None` if `self.epochs` is 0
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn apy(&self, epoch_duration_secs: f64) -> Option<f64> {
+        if self.epochs == 0 {
+            return None;
+        }
+        let epochs_per_year = SECONDS_PER_YEAR / epoch_duration_secs;
+        Some((1.0 + self.rate_change).powf(epochs_per_year / self.epochs as f64) - 1.0)
+    }
+}
+
+impl StakePool {
+    /// Returns `total_lamports / pool_token_supply`,
+    /// `None` if either is 0
+    #[inline]
+    pub fn sol_per_lst(&self) -> Option<f64> {
+        sol_per_lst(self.total_lamports, self.pool_token_supply)
+    }
+
+    /// Returns `last_epoch_total_lamports / last_epoch_pool_token_supply`,
+    /// `None` if either is 0
+    #[inline]
+    pub fn last_epoch_sol_per_lst(&self) -> Option<f64> {
+        sol_per_lst(
+            self.last_epoch_total_lamports,
+            self.last_epoch_pool_token_supply,
+        )
+    }
+
+    /// Epoch-over-epoch change in SOL-per-LST exchange rate
+    /// of the last UpdateStakePoolBalance, net of epoch fees.
+    ///
+    /// Returns `None` if the pool has no recorded last epoch exchange rate
+    #[inline]
+    pub fn epoch_rate_change(&self) -> Option<RateChange> {
+        Some(RateChange {
+            epochs: 1,
+            rate_change: self.sol_per_lst()? / self.last_epoch_sol_per_lst()? - 1.0,
+        })
+    }
+
+    /// Annualized [`Self::epoch_rate_change`]
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn apy(&self, epoch_duration_secs: f64) -> Option<f64> {
+        self.epoch_rate_change()?.apy(epoch_duration_secs)
+    }
+
+    /// Epoch fee that will be levied on the rewards of the `n`th
+    /// UpdateStakePoolBalance from now, starting from `n = 1`.
+    ///
+    /// UpdateStakePoolBalance levies the epoch fee before applying `next_epoch_fee`,
+    /// so `next_epoch_fee` only takes effect from the update after.
+    #[inline]
+    pub const fn epoch_fee_for_update(&self, n: u64) -> Fee {
+        self.next_epoch_fee
+            .resolve(self.epoch_fee, n.saturating_sub(1))
+    }
+
+    /// Projects the SOL-per-LST exchange rate change of the `n`th UpdateStakePoolBalance
+    /// from now given the gross (before epoch fee) staking rewards rate for that epoch,
+    /// e.g. `0.0005` for 0.05%.
+    ///
+    /// Returns `None` if the epoch fee that will be levied is invalid
+    #[inline]
+    pub fn projected_epoch_rate_change(&self, gross_reward_rate: f64, n: u64) -> Option<f64> {
+        let Fee {
+            numerator,
+            denominator,
+        } = self.epoch_fee_for_update(n);
+        let fee = if denominator == 0 {
+            0.0
+        } else {
+            numerator as f64 / denominator as f64
+        };
+        if fee > 1.0 {
+            return None;
+        }
+        Some(gross_reward_rate * (1.0 - fee))
+    }
+}
+
+/// Change in SOL-per-LST exchange rate between the snapshots with the earliest
+/// and latest epoch in `snapshots`, which should all be of the same stake pool.
+///
+/// Snapshots with 0 `total_lamports` or `pool_token_supply` are ignored.
+///
+/// Returns `None` if there are no valid snapshots
+#[inline]
+pub fn rate_change_from_snapshots<'a>(
+    snapshots: impl IntoIterator<Item = (u64, &'a StakePool)>,
+) -> Option<RateChange> {
+    let (start, end) = snapshots
+        .into_iter()
+        .filter_map(|(epoch, pool)| Some((epoch, pool.sol_per_lst()?)))
+        .fold(None, |bounds: Option<((u64, f64), (u64, f64))>, s| {
+            Some(match bounds {
+                None => (s, s),
+                Some((start, end)) => (
+                    if s.0 < start.0 { s } else { start },
+                    if s.0 > end.0 { s } else { end },
+                ),
+            })
+        })?;
+    Some(RateChange {
+        epochs: end.0 - start.0,
+        rate_change: end.1 / start.1 - 1.0,
+    })
+}
+
+#[inline]
+fn sol_per_lst(lamports: u64, pool_tokens: u64) -> Option<f64> {
+    if lamports == 0 || pool_tokens == 0 {
+        None
+    } else {
+        Some(lamports as f64 / pool_tokens as f64)
+    }
+}
@@ -0,0 +1,275 @@
+//! Checks of the internal consistency of a stake pool's on-chain state.
+
+use crate::{
+    Fee, FutureEpoch, StakePool, ValidatorList, ValidatorStakeAccounts, ValidatorStakeInfo,
+    STAKE_ACCOUNT_RENT_EXEMPT_LAMPORTS,
+};
+
+/// Lamports of a validator's stake accounts, 0 if the account does not exist
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[cfg_attr(
+    feature = "wasm",
+    derive(tsify_next::Tsify),
+    tsify(into_wasm_abi, from_wasm_abi, large_number_types_as_bigints)
+)]
+pub struct ValidatorStakeAccountsLamports {
+    pub validator: u64,
+    pub transient: u64,
+}
+
+impl ValidatorStakeAccountsLamports {
+    #[inline]
+    pub const fn accounts(&self) -> ValidatorStakeAccounts {
+        ValidatorStakeAccounts {
+            validator: self.validator > 0,
+            transient: self.transient > 0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "wasm",
+    derive(tsify_next::Tsify),
+    tsify(into_wasm_abi, from_wasm_abi)
+)]
+pub enum PoolFeeField {
+    EpochFee,
+    NextEpochFee,
+    StakeDepositFee,
+    StakeWithdrawalFee,
+    NextStakeWithdrawalFee,
+    SolDepositFee,
+    SolWithdrawalFee,
+    NextSolWithdrawalFee,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "wasm",
+    derive(tsify_next::Tsify),
+    tsify(into_wasm_abi, from_wasm_abi)
+)]
+pub enum PoolReferralFeeField {
+    StakeReferralFee,
+    SolReferralFee,
+}
+
+/// A single inconsistency found by [`audit_pool_itr`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase", rename_all_fields = "camelCase")
+)]
+#[cfg_attr(
+    feature = "wasm",
+    derive(tsify_next::Tsify),
+    tsify(into_wasm_abi, from_wasm_abi, large_number_types_as_bigints)
+)]
+pub enum PoolAuditFinding {
+    /// `reserve - rent + sum(active + transient)` of all validators != `total_lamports`
+    TotalLamportsMismatch { expected: u64, total_lamports: u64 },
+
+    /// Pool mint's supply != `pool_token_supply`
+    PoolTokenSupplyMismatch {
+        mint_supply: u64,
+        pool_token_supply: u64,
+    },
+
+    /// Validator at `index` has the same vote account as the one at `first_index`
+    DuplicateVoteAccount { index: usize, first_index: usize },
+
+    /// Validator at `index` has a `last_update_epoch` after the pool's
+    ValidatorAheadOfPool {
+        index: usize,
+        last_update_epoch: u64,
+        pool_last_update_epoch: u64,
+    },
+
+    /// Referral fee percentage > 100
+    ReferralFeeTooHigh {
+        field: PoolReferralFeeField,
+        fee_pct: u8,
+    },
+
+    /// Fee with a non-zero numerator but zero denominator
+    FeeZeroDenominator { field: PoolFeeField, numerator: u64 },
+
+    /// Fee > 100%, i.e. `numerator > denominator` with a non-zero denominator
+    FeeTooHigh { field: PoolFeeField, fee: Fee },
+
+    /// Validator at `index`'s recorded lamports differ from its stake accounts'
+    StakeAccountLamportsMismatch {
+        index: usize,
+        recorded: ValidatorStakeAccountsLamports,
+        actual: ValidatorStakeAccountsLamports,
+    },
+
+    /// Validator at `index`'s stake accounts cannot exist for its status,
+    /// see [`crate::StakeStatus::is_consistent_with`]
+    InconsistentStakeStatus { index: usize },
+}
+
+/// Yields every [`PoolAuditFinding`] of the given pool state, pool-wide findings first,
+/// then per-validator findings in validator list order. Yields nothing if the state is consistent.
+///
+/// - `reserve_stake_lamports` is the reserve stake account's lamports, including rent
+/// - `pool_mint_supply` is the pool mint's supply
+/// - `stake_accounts_lamports_of` returns the lamports of a validator's stake accounts,
+///   or `None` to skip checks against them. Recorded lamports are only expected to match
+///   once the validator has been updated for the current epoch.
+///
+/// The total lamports check is only meaningful once the pool has been updated for
+/// the current epoch, and takes the reserve's rent-exempt reserve to be mainnet-beta's
+/// [`STAKE_ACCOUNT_RENT_EXEMPT_LAMPORTS`], so it reports a mismatch on clusters
+/// with other rent parameters.
+/// Duplicate vote account detection is `O(n^2)` in the number of validators.
+#[inline]
+pub fn audit_pool_itr<
+    'a,
+    F: Fn(usize, &ValidatorStakeInfo) -> Option<ValidatorStakeAccountsLamports> + 'a,
+>(
+    stake_pool: &StakePool,
+    validator_list: &ValidatorList<'a>,
+    reserve_stake_lamports: u64,
+    pool_mint_supply: u64,
+    stake_accounts_lamports_of: F,
+) -> impl Iterator<Item = PoolAuditFinding> + 'a {
+    let validators = validator_list.validators;
+
+    let expected = validators
+        .iter()
+        .map(|v| u128::from(v.active_stake_lamports()) + u128::from(v.transient_stake_lamports()))
+        .sum::<u128>()
+        + u128::from(reserve_stake_lamports.saturating_sub(STAKE_ACCOUNT_RENT_EXEMPT_LAMPORTS));
+    let total_lamports = stake_pool.total_lamports;
+    let total_lamports_finding =
+        (expected != u128::from(total_lamports)).then(|| PoolAuditFinding::TotalLamportsMismatch {
+            expected: expected.try_into().unwrap_or(u64::MAX),
+            total_lamports,
+        });
+
+    let pool_token_supply = stake_pool.pool_token_supply;
+    let supply_finding = (pool_mint_supply != pool_token_supply).then_some(
+        PoolAuditFinding::PoolTokenSupplyMismatch {
+            mint_supply: pool_mint_supply,
+            pool_token_supply,
+        },
+    );
+
+    let referral_findings = [
+        (
+            PoolReferralFeeField::StakeReferralFee,
+            stake_pool.stake_referral_fee,
+        ),
+        (
+            PoolReferralFeeField::SolReferralFee,
+            stake_pool.sol_referral_fee,
+        ),
+    ]
+    .into_iter()
+    .filter(|(_, fee_pct)| *fee_pct > 100)
+    .map(|(field, fee_pct)| PoolAuditFinding::ReferralFeeTooHigh { field, fee_pct });
+
+    let fee_findings = [
+        (PoolFeeField::EpochFee, Some(stake_pool.epoch_fee)),
+        (
+            PoolFeeField::NextEpochFee,
+            future_fee(&stake_pool.next_epoch_fee),
+        ),
+        (
+            PoolFeeField::StakeDepositFee,
+            Some(stake_pool.stake_deposit_fee),
+        ),
+        (
+            PoolFeeField::StakeWithdrawalFee,
+            Some(stake_pool.stake_withdrawal_fee),
+        ),
+        (
+            PoolFeeField::NextStakeWithdrawalFee,
+            future_fee(&stake_pool.next_stake_withdrawal_fee),
+        ),
+        (
+            PoolFeeField::SolDepositFee,
+            Some(stake_pool.sol_deposit_fee),
+        ),
+        (
+            PoolFeeField::SolWithdrawalFee,
+            Some(stake_pool.sol_withdrawal_fee),
+        ),
+        (
+            PoolFeeField::NextSolWithdrawalFee,
+            future_fee(&stake_pool.next_sol_withdrawal_fee),
+        ),
+    ]
+    .into_iter()
+    .filter_map(|(field, fee)| {
+        let fee = fee?;
+        let Fee {
+            denominator,
+            numerator,
+        } = fee;
+        if denominator == 0 {
+            (numerator != 0).then_some(PoolAuditFinding::FeeZeroDenominator { field, numerator })
+        } else {
+            (numerator > denominator).then_some(PoolAuditFinding::FeeTooHigh { field, fee })
+        }
+    });
+
+    let pool_last_update_epoch = stake_pool.last_update_epoch;
+    let validator_findings = validators.iter().enumerate().flat_map(move |(index, vsi)| {
+        let duplicate = validators[..index]
+            .iter()
+            .position(|v| v.vote_account_address() == vsi.vote_account_address())
+            .map(|first_index| PoolAuditFinding::DuplicateVoteAccount { index, first_index });
+        let last_update_epoch = vsi.last_update_epoch();
+        let ahead = (last_update_epoch > pool_last_update_epoch).then_some(
+            PoolAuditFinding::ValidatorAheadOfPool {
+                index,
+                last_update_epoch,
+                pool_last_update_epoch,
+            },
+        );
+        let actual = stake_accounts_lamports_of(index, vsi);
+        let recorded = ValidatorStakeAccountsLamports {
+            validator: vsi.active_stake_lamports(),
+            transient: vsi.transient_stake_lamports(),
+        };
+        let lamports_mismatch = actual.filter(|actual| *actual != recorded).map(|actual| {
+            PoolAuditFinding::StakeAccountLamportsMismatch {
+                index,
+                recorded,
+                actual,
+            }
+        });
+        let inconsistent_status = actual
+            .filter(|actual| !vsi.status().is_consistent_with(actual.accounts()))
+            .map(|_| PoolAuditFinding::InconsistentStakeStatus { index });
+        [duplicate, ahead, lamports_mismatch, inconsistent_status]
+            .into_iter()
+            .flatten()
+    });
+
+    [total_lamports_finding, supply_finding]
+        .into_iter()
+        .flatten()
+        .chain(referral_findings)
+        .chain(fee_findings)
+        .chain(validator_findings)
+}
+
+#[inline]
+pub(crate) const fn future_fee(fee: &FutureEpoch<Fee>) -> Option<Fee> {
+    match fee {
+        FutureEpoch::None => None,
+        FutureEpoch::One(f) | FutureEpoch::Two(f) => Some(*f),
+    }
+}
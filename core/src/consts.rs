@@ -9,6 +9,9 @@ pub const SYSVAR_STAKE_HISTORY: [u8; 32] =
 pub const SYSVAR_CLOCK: [u8; 32] =
     bs58::decode_pubkey("SysvarC1ock11111111111111111111111111111111");
 
+pub const SYSVAR_EPOCH_SCHEDULE: [u8; 32] =
+    bs58::decode_pubkey("SysvarEpochSchedu1e111111111111111111111111");
+
 pub const STAKE_PROGRAM: [u8; 32] =
     bs58::decode_pubkey("Stake11111111111111111111111111111111111111");
 
@@ -23,6 +26,35 @@ pub const TOKEN_PROGRAM: [u8; 32] =
 pub const ASSOCIATED_TOKEN_PROGRAM: [u8; 32] =
     bs58::decode_pubkey("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL");
 
+/// The original SPL stake pool program deploy
+pub const SPL_STAKE_POOL_PROGRAM: [u8; 32] =
+    bs58::decode_pubkey("SPoo1Ku8WFXoNDMHPsrGSTSG1Y47rzgn41SLUNakuHy");
+
+/// Sanctum's deploy of the SPL stake pool program for single-validator pools
+pub const SANCTUM_SPL_STAKE_POOL_PROGRAM: [u8; 32] =
+    bs58::decode_pubkey("SP12tWFxD9oJsVWNavTTBZvMbA6gkAmxtVgxdqvyvhY");
+
+/// Sanctum's deploy of the SPL stake pool program for multi-validator pools
+pub const SANCTUM_SPL_MULTI_STAKE_POOL_PROGRAM: [u8; 32] =
+    bs58::decode_pubkey("SPMBzsVUuoHA4Jm6KunbsotaahvVikZs1JyTW6iJvbn");
+
+/// All known deploys of the SPL stake pool program.
+/// They all run the same program binary.
+pub const STAKE_POOL_PROGRAMS: [[u8; 32]; 3] = [
+    SPL_STAKE_POOL_PROGRAM,
+    SANCTUM_SPL_STAKE_POOL_PROGRAM,
+    SANCTUM_SPL_MULTI_STAKE_POOL_PROGRAM,
+];
+
+/// Size of a stake account's data in bytes
+pub const STAKE_ACCOUNT_LEN: usize = 200;
+
+/// Size of a token mint account's data without extensions in bytes
+pub const MINT_ACCOUNT_LEN: usize = 82;
+
+/// Rent-exempt minimum of a stake account under mainnet-beta's rent parameters.
+///
+/// Use [`crate::Rent::minimum_balance`] for clusters with different rent parameters.
 pub const STAKE_ACCOUNT_RENT_EXEMPT_LAMPORTS: u64 = 2282880;
 
 /// Minimum amount of staked lamports required in a validator stake account
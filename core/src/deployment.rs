@@ -0,0 +1,36 @@
+use crate::{deposit_auth_seeds, withdraw_auth_seeds, STAKE_POOL_PROGRAMS};
+
+/// A stake pool and the stake pool program it belongs to
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct StakePoolDeployment {
+    pub program: [u8; 32],
+    pub stake_pool: [u8; 32],
+}
+
+impl StakePoolDeployment {
+    #[inline]
+    pub const fn new(program: [u8; 32], stake_pool: [u8; 32]) -> Self {
+        Self {
+            program,
+            stake_pool,
+        }
+    }
+
+    /// Whether `self.program` is one of [`STAKE_POOL_PROGRAMS`]
+    #[inline]
+    pub fn is_known_program(&self) -> bool {
+        STAKE_POOL_PROGRAMS.contains(&self.program)
+    }
+
+    /// Seeds of the pool's withdraw authority PDA, to be derived with `self.program`
+    #[inline]
+    pub const fn withdraw_auth_seeds(&self) -> (&[u8; 32], &[u8; 8]) {
+        withdraw_auth_seeds(&self.stake_pool)
+    }
+
+    /// Seeds of the pool's default stake deposit authority PDA, to be derived with `self.program`
+    #[inline]
+    pub const fn deposit_auth_seeds(&self) -> (&[u8; 32], &[u8; 7]) {
+        deposit_auth_seeds(&self.stake_pool)
+    }
+}
@@ -0,0 +1,360 @@
+//! Changes between two snapshots of a [`StakePool`] or [`ValidatorList`]
+
+use crate::{
+    future_fee, Fee, PoolFeeField, PoolReferralFeeField, StakePool, StakeStatus, ValidatorList,
+    ValidatorStakeInfo,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "wasm",
+    derive(tsify_next::Tsify),
+    tsify(into_wasm_abi, from_wasm_abi)
+)]
+pub enum StakePoolAuthorityField {
+    Manager,
+    Staker,
+    StakeDepositAuthority,
+    ManagerFeeAccount,
+    SolDepositAuthority,
+    SolWithdrawAuthority,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "wasm",
+    derive(tsify_next::Tsify),
+    tsify(into_wasm_abi, from_wasm_abi)
+)]
+pub enum PreferredValidatorField {
+    Deposit,
+    Withdraw,
+}
+
+/// A single change between two snapshots of a [`StakePool`], see [`stake_pool_diff_itr`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase", rename_all_fields = "camelCase")
+)]
+pub enum StakePoolChange {
+    /// `None` for optional authorities that are not set
+    Authority {
+        field: StakePoolAuthorityField,
+        old: Option<[u8; 32]>,
+        new: Option<[u8; 32]>,
+    },
+
+    /// `None` for next fees that are not set
+    Fee {
+        field: PoolFeeField,
+        old: Option<Fee>,
+        new: Option<Fee>,
+    },
+
+    ReferralFee {
+        field: PoolReferralFeeField,
+        old: u8,
+        new: u8,
+    },
+
+    TotalLamports {
+        old: u64,
+        new: u64,
+    },
+
+    PoolTokenSupply {
+        old: u64,
+        new: u64,
+    },
+
+    PreferredValidator {
+        field: PreferredValidatorField,
+        old: Option<[u8; 32]>,
+        new: Option<[u8; 32]>,
+    },
+}
+
+/// Yields every [`StakePoolChange`] from `old` to `new`.
+///
+/// Next fees are compared by their value only, so the countdown of a scheduled fee
+/// from `FutureEpoch::Two` to `FutureEpoch::One` is not a change.
+/// Fields not covered by [`StakePoolChange`], e.g. `last_update_epoch`, are ignored.
+#[inline]
+pub fn stake_pool_diff_itr(
+    old: &StakePool,
+    new: &StakePool,
+) -> impl Iterator<Item = StakePoolChange> {
+    let authorities = [
+        (
+            StakePoolAuthorityField::Manager,
+            Some(old.manager),
+            Some(new.manager),
+        ),
+        (
+            StakePoolAuthorityField::Staker,
+            Some(old.staker),
+            Some(new.staker),
+        ),
+        (
+            StakePoolAuthorityField::StakeDepositAuthority,
+            Some(old.stake_deposit_authority),
+            Some(new.stake_deposit_authority),
+        ),
+        (
+            StakePoolAuthorityField::ManagerFeeAccount,
+            Some(old.manager_fee_account),
+            Some(new.manager_fee_account),
+        ),
+        (
+            StakePoolAuthorityField::SolDepositAuthority,
+            old.sol_deposit_authority,
+            new.sol_deposit_authority,
+        ),
+        (
+            StakePoolAuthorityField::SolWithdrawAuthority,
+            old.sol_withdraw_authority,
+            new.sol_withdraw_authority,
+        ),
+    ]
+    .into_iter()
+    .filter(|(_, old, new)| old != new)
+    .map(|(field, old, new)| StakePoolChange::Authority { field, old, new });
+
+    let fees = [
+        (
+            PoolFeeField::EpochFee,
+            Some(old.epoch_fee),
+            Some(new.epoch_fee),
+        ),
+        (
+            PoolFeeField::NextEpochFee,
+            future_fee(&old.next_epoch_fee),
+            future_fee(&new.next_epoch_fee),
+        ),
+        (
+            PoolFeeField::StakeDepositFee,
+            Some(old.stake_deposit_fee),
+            Some(new.stake_deposit_fee),
+        ),
+        (
+            PoolFeeField::StakeWithdrawalFee,
+            Some(old.stake_withdrawal_fee),
+            Some(new.stake_withdrawal_fee),
+        ),
+        (
+            PoolFeeField::NextStakeWithdrawalFee,
+            future_fee(&old.next_stake_withdrawal_fee),
+            future_fee(&new.next_stake_withdrawal_fee),
+        ),
+        (
+            PoolFeeField::SolDepositFee,
+            Some(old.sol_deposit_fee),
+            Some(new.sol_deposit_fee),
+        ),
+        (
+            PoolFeeField::SolWithdrawalFee,
+            Some(old.sol_withdrawal_fee),
+            Some(new.sol_withdrawal_fee),
+        ),
+        (
+            PoolFeeField::NextSolWithdrawalFee,
+            future_fee(&old.next_sol_withdrawal_fee),
+            future_fee(&new.next_sol_withdrawal_fee),
+        ),
+    ]
+    .into_iter()
+    .filter(|(_, old, new)| old != new)
+    .map(|(field, old, new)| StakePoolChange::Fee { field, old, new });
+
+    let referral_fees = [
+        (
+            PoolReferralFeeField::StakeReferralFee,
+            old.stake_referral_fee,
+            new.stake_referral_fee,
+        ),
+        (
+            PoolReferralFeeField::SolReferralFee,
+            old.sol_referral_fee,
+            new.sol_referral_fee,
+        ),
+    ]
+    .into_iter()
+    .filter(|(_, old, new)| old != new)
+    .map(|(field, old, new)| StakePoolChange::ReferralFee { field, old, new });
+
+    let amounts = [
+        (old.total_lamports != new.total_lamports).then_some(StakePoolChange::TotalLamports {
+            old: old.total_lamports,
+            new: new.total_lamports,
+        }),
+        (old.pool_token_supply != new.pool_token_supply).then_some(
+            StakePoolChange::PoolTokenSupply {
+                old: old.pool_token_supply,
+                new: new.pool_token_supply,
+            },
+        ),
+    ]
+    .into_iter()
+    .flatten();
+
+    let preferred_validators = [
+        (
+            PreferredValidatorField::Deposit,
+            old.preferred_deposit_validator_vote_address,
+            new.preferred_deposit_validator_vote_address,
+        ),
+        (
+            PreferredValidatorField::Withdraw,
+            old.preferred_withdraw_validator_vote_address,
+            new.preferred_withdraw_validator_vote_address,
+        ),
+    ]
+    .into_iter()
+    .filter(|(_, old, new)| old != new)
+    .map(|(field, old, new)| StakePoolChange::PreferredValidator { field, old, new });
+
+    authorities
+        .chain(fees)
+        .chain(referral_fees)
+        .chain(amounts)
+        .chain(preferred_validators)
+}
+
+/// A single change between two snapshots of a [`ValidatorList`], see [`validator_list_diff_itr`].
+///
+/// Validators are matched by vote account since entries can move on cleanup.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase", rename_all_fields = "camelCase")
+)]
+#[cfg_attr(
+    feature = "wasm",
+    derive(tsify_next::Tsify),
+    tsify(into_wasm_abi, from_wasm_abi, large_number_types_as_bigints)
+)]
+pub enum ValidatorListChange {
+    /// Validator at `new_index` of the new list is not on the old list
+    Added { new_index: usize },
+
+    /// Validator at `old_index` of the old list is not on the new list
+    Removed { old_index: usize },
+
+    StatusChanged {
+        old_index: usize,
+        new_index: usize,
+        old: StakeStatus,
+        new: StakeStatus,
+    },
+
+    /// Use [`Self::active_stake_lamports_delta`] and
+    /// [`Self::transient_stake_lamports_delta`] for signed deltas
+    LamportsChanged {
+        old_index: usize,
+        new_index: usize,
+        old_active_stake_lamports: u64,
+        new_active_stake_lamports: u64,
+        old_transient_stake_lamports: u64,
+        new_transient_stake_lamports: u64,
+    },
+}
+
+impl ValidatorListChange {
+    /// `None` if `self` is not [`Self::LamportsChanged`]
+    #[inline]
+    pub const fn active_stake_lamports_delta(&self) -> Option<i128> {
+        match self {
+            Self::LamportsChanged {
+                old_active_stake_lamports,
+                new_active_stake_lamports,
+                ..
+            } => Some(*new_active_stake_lamports as i128 - *old_active_stake_lamports as i128),
+            _ => None,
+        }
+    }
+
+    /// `None` if `self` is not [`Self::LamportsChanged`]
+    #[inline]
+    pub const fn transient_stake_lamports_delta(&self) -> Option<i128> {
+        match self {
+            Self::LamportsChanged {
+                old_transient_stake_lamports,
+                new_transient_stake_lamports,
+                ..
+            } => {
+                Some(*new_transient_stake_lamports as i128 - *old_transient_stake_lamports as i128)
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Yields every [`ValidatorListChange`] from `old` to `new`:
+/// first removals in old list order, then additions, status and lamports changes in new list order.
+/// A validator whose status and lamports both changed yields a `StatusChanged` followed by
+/// a `LamportsChanged`.
+///
+/// Each validator is first looked up at the same index in the other list, falling back to a
+/// linear search, so this is `O(n)` if no entries moved and `O(n^2)` in the worst case.
+#[inline]
+pub fn validator_list_diff_itr<'a>(
+    old: &ValidatorList<'a>,
+    new: &ValidatorList<'a>,
+) -> impl Iterator<Item = ValidatorListChange> + 'a {
+    let old = old.validators;
+    let new = new.validators;
+
+    let removed = old
+        .iter()
+        .enumerate()
+        .filter(move |(i, vsi)| find_validator(new, *i, vsi.vote_account_address()).is_none())
+        .map(|(old_index, _)| ValidatorListChange::Removed { old_index });
+
+    let changed = new.iter().enumerate().flat_map(move |(new_index, n)| {
+        let Some(old_index) = find_validator(old, new_index, n.vote_account_address()) else {
+            return [Some(ValidatorListChange::Added { new_index }), None];
+        };
+        let o = &old[old_index];
+        let status_changed =
+            (o.status() != n.status()).then(|| ValidatorListChange::StatusChanged {
+                old_index,
+                new_index,
+                old: o.status(),
+                new: n.status(),
+            });
+        let lamports_changed = (o.active_stake_lamports() != n.active_stake_lamports()
+            || o.transient_stake_lamports() != n.transient_stake_lamports())
+        .then(|| ValidatorListChange::LamportsChanged {
+            old_index,
+            new_index,
+            old_active_stake_lamports: o.active_stake_lamports(),
+            new_active_stake_lamports: n.active_stake_lamports(),
+            old_transient_stake_lamports: o.transient_stake_lamports(),
+            new_transient_stake_lamports: n.transient_stake_lamports(),
+        });
+        [status_changed, lamports_changed]
+    });
+
+    removed.chain(changed.flatten())
+}
+
+/// Index of the validator with vote account `vote` in `validators`,
+/// checking `hint` first
+#[inline]
+fn find_validator(
+    validators: &[ValidatorStakeInfo],
+    hint: usize,
+    vote: &[u8; 32],
+) -> Option<usize> {
+    match validators.get(hint) {
+        Some(v) if v.vote_account_address() == vote => Some(hint),
+        _ => validators
+            .iter()
+            .position(|v| v.vote_account_address() == vote),
+    }
+}
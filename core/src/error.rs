@@ -1,5 +1,7 @@
 use core::{error::Error, fmt::Display};
 
+use crate::{AccountType, StakeStatus, StakeStatusEvent, ValidatorStakeAccounts};
+
 /// NB: this is not in the order defined by the program
 /// TODO: `seqconsts!()` this to make it so
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -11,10 +13,22 @@ pub enum SplStakePoolError {
     InvalidSolWithdrawAuthority,
     InvalidStakeDepositAuthority,
     InvalidState,
+    /// Reserve stake account cannot fund the transfer while remaining rent-exempt
+    ReserveDepleted,
     SolWithdrawalTooLarge,
     StakeLamportsNotEqualToMinimum,
     StakeListAndPoolOutOfDate,
+    /// Deposited stake account's lockup is not the same as the pool's
+    StakeLockupMismatch,
+    /// A transient stake account exists that cannot be topped up this epoch,
+    /// it must first be merged by UpdateValidatorListBalance
+    TransientAccountInUse,
     ValidatorNotFound,
+    /// Stake account withdrawn from the pool would inherit a lockup that is in force
+    WithdrawnStakeLockupInForce,
+    /// Existing transient stake account is activating/deactivating
+    /// in the opposite direction of the requested increase/decrease
+    WrongStakeStake,
 }
 
 impl Display for SplStakePoolError {
@@ -25,3 +39,46 @@ impl Display for SplStakePoolError {
 }
 
 impl Error for SplStakePoolError {}
+
+/// Errors returned by the validated account deserialization fns,
+/// e.g. [`crate::StakePool::try_from_account`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum InvalidAccountError {
+    /// Account data could not be deserialized
+    Deserialize(borsh::io::ErrorKind),
+    /// `validators.len() > max_validators`
+    NumValidatorsExceedsMax {
+        num_validators: u32,
+        max_validators: u32,
+    },
+    /// Account data is of a different account type
+    WrongAccountType(AccountType),
+    /// Account is not owned by the expected stake pool program
+    WrongOwner,
+    /// Validator entry at `index` has an invalid [`crate::StakeStatus`] byte
+    WrongStakeStatus { index: u32, status: u8 },
+}
+
+impl Display for InvalidAccountError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{self:#?}")
+    }
+}
+
+impl Error for InvalidAccountError {}
+
+/// A [`StakeStatus`] transition that the program never performs
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct InvalidStakeStatusTransition {
+    pub from: StakeStatus,
+    pub event: StakeStatusEvent,
+    pub accounts: ValidatorStakeAccounts,
+}
+
+impl Display for InvalidStakeStatusTransition {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{self:#?}")
+    }
+}
+
+impl Error for InvalidStakeStatusTransition {}
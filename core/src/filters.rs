@@ -0,0 +1,166 @@
+//! Byte offsets of the fixed-position prefix of stake pool program accounts
+//! and memcmp filters for `getProgramAccounts` built from them.
+
+use crate::AccountType;
+
+// StakePool
+
+pub const STAKE_POOL_ACCOUNT_TYPE_OFFSET: usize = 0;
+pub const STAKE_POOL_MANAGER_OFFSET: usize = STAKE_POOL_ACCOUNT_TYPE_OFFSET + 1;
+pub const STAKE_POOL_STAKER_OFFSET: usize = STAKE_POOL_MANAGER_OFFSET + 32;
+pub const STAKE_POOL_STAKE_DEPOSIT_AUTHORITY_OFFSET: usize = STAKE_POOL_STAKER_OFFSET + 32;
+pub const STAKE_POOL_STAKE_WITHDRAW_BUMP_SEED_OFFSET: usize =
+    STAKE_POOL_STAKE_DEPOSIT_AUTHORITY_OFFSET + 32;
+pub const STAKE_POOL_VALIDATOR_LIST_OFFSET: usize = STAKE_POOL_STAKE_WITHDRAW_BUMP_SEED_OFFSET + 1;
+pub const STAKE_POOL_RESERVE_STAKE_OFFSET: usize = STAKE_POOL_VALIDATOR_LIST_OFFSET + 32;
+pub const STAKE_POOL_POOL_MINT_OFFSET: usize = STAKE_POOL_RESERVE_STAKE_OFFSET + 32;
+pub const STAKE_POOL_MANAGER_FEE_ACCOUNT_OFFSET: usize = STAKE_POOL_POOL_MINT_OFFSET + 32;
+pub const STAKE_POOL_TOKEN_PROGRAM_ID_OFFSET: usize = STAKE_POOL_MANAGER_FEE_ACCOUNT_OFFSET + 32;
+pub const STAKE_POOL_TOTAL_LAMPORTS_OFFSET: usize = STAKE_POOL_TOKEN_PROGRAM_ID_OFFSET + 32;
+pub const STAKE_POOL_POOL_TOKEN_SUPPLY_OFFSET: usize = STAKE_POOL_TOTAL_LAMPORTS_OFFSET + 8;
+pub const STAKE_POOL_LAST_UPDATE_EPOCH_OFFSET: usize = STAKE_POOL_POOL_TOKEN_SUPPLY_OFFSET + 8;
+pub const STAKE_POOL_LOCKUP_OFFSET: usize = STAKE_POOL_LAST_UPDATE_EPOCH_OFFSET + 8;
+pub const STAKE_POOL_EPOCH_FEE_OFFSET: usize = STAKE_POOL_LOCKUP_OFFSET + 48;
+
+/// Length of the prefix of `StakePool` whose fields are at fixed positions.
+/// Fields after `epoch_fee` are at variable positions due to borsh `Option`s.
+pub const STAKE_POOL_FIXED_PREFIX_LEN: usize = STAKE_POOL_EPOCH_FEE_OFFSET + 16;
+
+// ValidatorList
+
+pub const VALIDATOR_LIST_ACCOUNT_TYPE_OFFSET: usize = 0;
+pub const VALIDATOR_LIST_MAX_VALIDATORS_OFFSET: usize = VALIDATOR_LIST_ACCOUNT_TYPE_OFFSET + 1;
+pub const VALIDATOR_LIST_NUM_VALIDATORS_OFFSET: usize = VALIDATOR_LIST_MAX_VALIDATORS_OFFSET + 4;
+pub const VALIDATOR_LIST_VALIDATORS_OFFSET: usize = VALIDATOR_LIST_NUM_VALIDATORS_OFFSET + 4;
+
+const STAKE_POOL_ACCOUNT_TYPE_BYTES: &[u8] = &[AccountType::StakePool as u8];
+const VALIDATOR_LIST_ACCOUNT_TYPE_BYTES: &[u8] = &[AccountType::ValidatorList as u8];
+
+/// A `getProgramAccounts` memcmp filter:
+/// account data at `offset` must be equal to `bytes`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MemcmpFilter<'a> {
+    pub offset: usize,
+    pub bytes: &'a [u8],
+}
+
+impl MemcmpFilter<'static> {
+    pub const STAKE_POOL_ACCOUNT_TYPE: Self = Self {
+        offset: STAKE_POOL_ACCOUNT_TYPE_OFFSET,
+        bytes: STAKE_POOL_ACCOUNT_TYPE_BYTES,
+    };
+
+    pub const VALIDATOR_LIST_ACCOUNT_TYPE: Self = Self {
+        offset: VALIDATOR_LIST_ACCOUNT_TYPE_OFFSET,
+        bytes: VALIDATOR_LIST_ACCOUNT_TYPE_BYTES,
+    };
+}
+
+impl<'a> MemcmpFilter<'a> {
+    #[inline]
+    pub const fn new(offset: usize, bytes: &'a [u8]) -> Self {
+        Self { offset, bytes }
+    }
+
+    #[inline]
+    pub const fn stake_pool_manager(manager: &'a [u8; 32]) -> Self {
+        Self::new(STAKE_POOL_MANAGER_OFFSET, manager)
+    }
+
+    #[inline]
+    pub const fn stake_pool_staker(staker: &'a [u8; 32]) -> Self {
+        Self::new(STAKE_POOL_STAKER_OFFSET, staker)
+    }
+
+    #[inline]
+    pub const fn stake_pool_stake_deposit_authority(auth: &'a [u8; 32]) -> Self {
+        Self::new(STAKE_POOL_STAKE_DEPOSIT_AUTHORITY_OFFSET, auth)
+    }
+
+    #[inline]
+    pub const fn stake_pool_validator_list(validator_list: &'a [u8; 32]) -> Self {
+        Self::new(STAKE_POOL_VALIDATOR_LIST_OFFSET, validator_list)
+    }
+
+    #[inline]
+    pub const fn stake_pool_reserve_stake(reserve_stake: &'a [u8; 32]) -> Self {
+        Self::new(STAKE_POOL_RESERVE_STAKE_OFFSET, reserve_stake)
+    }
+
+    #[inline]
+    pub const fn stake_pool_pool_mint(pool_mint: &'a [u8; 32]) -> Self {
+        Self::new(STAKE_POOL_POOL_MINT_OFFSET, pool_mint)
+    }
+
+    #[inline]
+    pub const fn stake_pool_manager_fee_account(manager_fee_account: &'a [u8; 32]) -> Self {
+        Self::new(STAKE_POOL_MANAGER_FEE_ACCOUNT_OFFSET, manager_fee_account)
+    }
+
+    #[inline]
+    pub const fn stake_pool_token_program_id(token_program_id: &'a [u8; 32]) -> Self {
+        Self::new(STAKE_POOL_TOKEN_PROGRAM_ID_OFFSET, token_program_id)
+    }
+
+    /// Returns false if `data` is too short
+    #[inline]
+    pub fn matches(&self, data: &[u8]) -> bool {
+        self.offset
+            .checked_add(self.bytes.len())
+            .and_then(|end| data.get(self.offset..end))
+            .is_some_and(|s| s == self.bytes)
+    }
+}
+
+/// Filters for all stake pools whose LST is `pool_mint`
+#[inline]
+pub const fn stake_pools_with_mint_filters(pool_mint: &[u8; 32]) -> [MemcmpFilter<'_>; 2] {
+    [
+        MemcmpFilter::STAKE_POOL_ACCOUNT_TYPE,
+        MemcmpFilter::stake_pool_pool_mint(pool_mint),
+    ]
+}
+
+/// Filters for all stake pools whose manager is `manager`
+#[inline]
+pub const fn stake_pools_managed_by_filters(manager: &[u8; 32]) -> [MemcmpFilter<'_>; 2] {
+    [
+        MemcmpFilter::STAKE_POOL_ACCOUNT_TYPE,
+        MemcmpFilter::stake_pool_manager(manager),
+    ]
+}
+
+/// Filters for all stake pools whose staker is `staker`
+#[inline]
+pub const fn stake_pools_staked_by_filters(staker: &[u8; 32]) -> [MemcmpFilter<'_>; 2] {
+    [
+        MemcmpFilter::STAKE_POOL_ACCOUNT_TYPE,
+        MemcmpFilter::stake_pool_staker(staker),
+    ]
+}
+
+/// Fast path for checking a candidate stake pool account
+/// before a full [`crate::StakePool::try_from_data`] decode:
+/// only reads the fixed-position prefix of `data`.
+///
+/// Returns true if `data` is long enough to contain the prefix,
+/// is of [`AccountType::StakePool`] and matches all `filters`.
+#[inline]
+pub fn stake_pool_prefix_matches(data: &[u8], filters: &[MemcmpFilter]) -> bool {
+    data.len() >= STAKE_POOL_FIXED_PREFIX_LEN
+        && MemcmpFilter::STAKE_POOL_ACCOUNT_TYPE.matches(data)
+        && filters.iter().all(|f| f.matches(data))
+}
+
+/// Fast path for checking a candidate validator list account
+/// before a full [`crate::ValidatorList::try_from_data`] decode:
+/// only reads the header of `data`.
+///
+/// Returns true if `data` is long enough to contain the header,
+/// is of [`AccountType::ValidatorList`] and matches all `filters`.
+#[inline]
+pub fn validator_list_prefix_matches(data: &[u8], filters: &[MemcmpFilter]) -> bool {
+    data.len() >= VALIDATOR_LIST_VALIDATORS_OFFSET
+        && MemcmpFilter::VALIDATOR_LIST_ACCOUNT_TYPE.matches(data)
+        && filters.iter().all(|f| f.matches(data))
+}
@@ -0,0 +1,109 @@
+//! Instructions of the associated token account program
+
+use generic_array_struct::generic_array_struct;
+
+use crate::{StakePool, SYSTEM_PROGRAM};
+
+pub const ASSOCIATED_TOKEN_IX_IDX_CREATE_IDEMPOTENT: u8 = 1;
+
+/// Creates the associated token account if it does not exist.
+/// No-op if it already does.
+#[generic_array_struct(builder pub)]
+#[repr(transparent)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "wasm",
+    derive(tsify_next::Tsify),
+    tsify(into_wasm_abi, from_wasm_abi)
+)]
+pub struct CreateAtaIdempotentIxAccs<T> {
+    pub funding: T,
+    pub ata: T,
+    pub wallet: T,
+    pub mint: T,
+    pub system_program: T,
+    pub token_program: T,
+}
+
+pub type CreateAtaIdempotentIxKeysOwned = CreateAtaIdempotentIxAccs<[u8; 32]>;
+pub type CreateAtaIdempotentIxKeys<'a> = CreateAtaIdempotentIxAccs<&'a [u8; 32]>;
+pub type CreateAtaIdempotentIxAccsFlag = CreateAtaIdempotentIxAccs<bool>;
+
+pub const CREATE_ATA_IDEMPOTENT_IX_IS_WRITER: CreateAtaIdempotentIxAccsFlag =
+    CreateAtaIdempotentIxAccs([false; CREATE_ATA_IDEMPOTENT_IX_ACCS_LEN])
+        .const_with_funding(true)
+        .const_with_ata(true);
+
+pub const CREATE_ATA_IDEMPOTENT_IX_IS_SIGNER: CreateAtaIdempotentIxAccsFlag =
+    CreateAtaIdempotentIxAccs([false; CREATE_ATA_IDEMPOTENT_IX_ACCS_LEN]).const_with_funding(true);
+
+impl<T: Clone> CreateAtaIdempotentIxAccs<T> {
+    #[inline]
+    pub const fn new(arr: [T; CREATE_ATA_IDEMPOTENT_IX_ACCS_LEN]) -> Self {
+        Self(arr)
+    }
+}
+
+impl CreateAtaIdempotentIxKeysOwned {
+    #[inline]
+    pub fn as_borrowed(&self) -> CreateAtaIdempotentIxKeys<'_> {
+        CreateAtaIdempotentIxKeys::new(self.0.each_ref())
+    }
+
+    #[inline]
+    pub fn with_keys_from_stake_pool(self, pool: &StakePool) -> Self {
+        self.as_borrowed()
+            .with_keys_from_stake_pool(pool)
+            .into_owned()
+    }
+
+    #[inline]
+    pub fn with_consts(self) -> Self {
+        self.as_borrowed().with_consts().into_owned()
+    }
+}
+
+impl<'a> CreateAtaIdempotentIxKeys<'a> {
+    #[inline]
+    pub fn into_owned(self) -> CreateAtaIdempotentIxKeysOwned {
+        CreateAtaIdempotentIxKeysOwned::new(self.0.map(|pk| *pk))
+    }
+
+    /// Sets the pool mint and token program for creating
+    /// a pool token associated token account
+    #[inline]
+    pub const fn with_keys_from_stake_pool(
+        self,
+        StakePool {
+            pool_mint,
+            token_program_id,
+            ..
+        }: &'a StakePool,
+    ) -> Self {
+        self.const_with_mint(pool_mint)
+            .const_with_token_program(token_program_id)
+    }
+
+    #[inline]
+    pub const fn with_consts(self) -> Self {
+        self.const_with_system_program(&SYSTEM_PROGRAM)
+    }
+}
+
+#[repr(transparent)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CreateAtaIdempotentIxData([u8; 1]);
+
+impl CreateAtaIdempotentIxData {
+    #[inline]
+    pub const fn new() -> Self {
+        Self([ASSOCIATED_TOKEN_IX_IDX_CREATE_IDEMPOTENT])
+    }
+
+    #[inline]
+    pub const fn to_buf(&self) -> [u8; 1] {
+        self.0
+    }
+}
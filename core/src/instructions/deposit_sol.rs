@@ -2,14 +2,16 @@ use generic_array_struct::generic_array_struct;
 
 use crate::{StakePool, SYSTEM_PROGRAM};
 
-use super::INSTRUCTION_IDX_DEPOSIT_SOL;
+use super::{keys_signer_writer_iter, INSTRUCTION_IDX_DEPOSIT_SOL};
 
 /// If the pool has a non-default sol deposit authority, then the following
 /// accounts follow after this prefix:
 ///
 ///  - `[s]`  sol deposit authority
 ///
-/// Otherwise, this is the full instruction accounts array
+/// Otherwise, this is the full instruction accounts array.
+///
+/// Use [`DepositSolIxFullKeysOwned`] to get the full accounts array for either case
 #[generic_array_struct(builder pub)]
 #[repr(transparent)]
 #[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
@@ -105,6 +107,96 @@ impl<'a> DepositSolIxPrefixKeys<'a> {
     }
 }
 
+/// Accounts that follow [`DepositSolIxPrefixAccs`] if the pool
+/// has a non-default sol deposit authority
+#[generic_array_struct(builder pub)]
+#[repr(transparent)]
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "wasm",
+    derive(tsify_next::Tsify),
+    tsify(into_wasm_abi, from_wasm_abi)
+)]
+pub struct DepositSolIxSuffixAccs<T> {
+    pub sol_deposit_auth: T,
+}
+
+pub type DepositSolIxSuffixKeysOwned = DepositSolIxSuffixAccs<[u8; 32]>;
+pub type DepositSolIxSuffixKeys<'a> = DepositSolIxSuffixAccs<&'a [u8; 32]>;
+pub type DepositSolIxSuffixAccsFlag = DepositSolIxSuffixAccs<bool>;
+
+pub const DEPOSIT_SOL_IX_SUFFIX_IS_WRITER: DepositSolIxSuffixAccsFlag =
+    DepositSolIxSuffixAccs([false; DEPOSIT_SOL_IX_SUFFIX_ACCS_LEN]);
+
+pub const DEPOSIT_SOL_IX_SUFFIX_IS_SIGNER: DepositSolIxSuffixAccsFlag =
+    DepositSolIxSuffixAccs([false; DEPOSIT_SOL_IX_SUFFIX_ACCS_LEN])
+        .const_with_sol_deposit_auth(true);
+
+impl<T: Clone> DepositSolIxSuffixAccs<T> {
+    #[inline]
+    pub const fn new(arr: [T; DEPOSIT_SOL_IX_SUFFIX_ACCS_LEN]) -> Self {
+        Self(arr)
+    }
+}
+
+impl DepositSolIxSuffixKeysOwned {
+    #[inline]
+    pub fn as_borrowed(&self) -> DepositSolIxSuffixKeys<'_> {
+        DepositSolIxSuffixKeys::new(self.0.each_ref())
+    }
+}
+
+impl DepositSolIxSuffixKeys<'_> {
+    #[inline]
+    pub fn into_owned(self) -> DepositSolIxSuffixKeysOwned {
+        DepositSolIxSuffixKeysOwned::new(self.0.map(|pk| *pk))
+    }
+}
+
+/// All accounts of a DepositSol instruction,
+/// for both permissioned and permissionless pools
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct DepositSolIxFullKeysOwned {
+    pub prefix: DepositSolIxPrefixKeysOwned,
+
+    /// `Some` only if the pool has a non-default sol deposit authority
+    pub suffix: Option<DepositSolIxSuffixKeysOwned>,
+}
+
+impl DepositSolIxFullKeysOwned {
+    /// Sets `suffix` according to `pool.sol_deposit_authority`
+    #[inline]
+    pub fn from_prefix_and_stake_pool(
+        prefix: DepositSolIxPrefixKeysOwned,
+        pool: &StakePool,
+    ) -> Self {
+        Self {
+            prefix,
+            suffix: pool
+                .sol_deposit_authority
+                .map(|auth| DepositSolIxSuffixKeysOwned::default().with_sol_deposit_auth(auth)),
+        }
+    }
+
+    /// Returns `(pubkey, is_signer, is_writer)` of every account in order
+    #[inline]
+    pub fn keys_signer_writer(&self) -> impl Iterator<Item = (&[u8; 32], bool, bool)> {
+        keys_signer_writer_iter(
+            &self.prefix.0,
+            &DEPOSIT_SOL_IX_PREFIX_IS_SIGNER.0,
+            &DEPOSIT_SOL_IX_PREFIX_IS_WRITER.0,
+        )
+        .chain(self.suffix.iter().flat_map(|suffix| {
+            keys_signer_writer_iter(
+                &suffix.0,
+                &DEPOSIT_SOL_IX_SUFFIX_IS_SIGNER.0,
+                &DEPOSIT_SOL_IX_SUFFIX_IS_WRITER.0,
+            )
+        }))
+    }
+}
+
 #[repr(transparent)]
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
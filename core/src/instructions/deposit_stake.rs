@@ -2,7 +2,7 @@ use generic_array_struct::generic_array_struct;
 
 use crate::{StakePool, STAKE_PROGRAM, SYSVAR_CLOCK, SYSVAR_STAKE_HISTORY};
 
-use super::INSTRUCTION_IDX_DEPOSIT_STAKE;
+use super::{keys_signer_writer_iter, INSTRUCTION_IDX_DEPOSIT_STAKE};
 
 #[generic_array_struct(builder pub)]
 #[repr(transparent)]
@@ -50,6 +50,11 @@ pub const DEPOSIT_STAKE_IX_IS_WRITER: DepositStakeIxAccsFlag =
 pub const DEPOSIT_STAKE_IX_IS_SIGNER: DepositStakeIxAccsFlag =
     DepositStakeIxAccs([false; DEPOSIT_STAKE_IX_ACCS_LEN]);
 
+/// Signer flags for pools with a custom stake deposit authority,
+/// which must sign the instruction
+pub const DEPOSIT_STAKE_IX_PERMISSIONED_IS_SIGNER: DepositStakeIxAccsFlag =
+    DEPOSIT_STAKE_IX_IS_SIGNER.const_with_deposit_auth(true);
+
 impl<T: Clone> DepositStakeIxAccs<T> {
     #[inline]
     pub const fn new(arr: [T; DEPOSIT_STAKE_IX_ACCS_LEN]) -> Self {
@@ -109,6 +114,52 @@ impl<'a> DepositStakeIxKeys<'a> {
     }
 }
 
+/// All accounts of a DepositStake instruction,
+/// for both permissioned and permissionless pools
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DepositStakeIxFullKeysOwned {
+    pub keys: DepositStakeIxKeysOwned,
+
+    /// `true` if `keys.deposit_auth` is a custom stake deposit authority
+    /// instead of the pool's deposit authority PDA
+    pub is_permissioned: bool,
+}
+
+impl DepositStakeIxFullKeysOwned {
+    /// Sets `keys.deposit_auth` to `pool.stake_deposit_authority`,
+    /// which must sign the instruction if it is not `deposit_auth_pda`
+    #[inline]
+    pub fn from_keys_and_stake_pool(
+        keys: DepositStakeIxKeysOwned,
+        pool: &StakePool,
+        deposit_auth_pda: &[u8; 32],
+    ) -> Self {
+        Self {
+            keys: keys.with_deposit_auth(pool.stake_deposit_authority),
+            is_permissioned: pool.stake_deposit_authority != *deposit_auth_pda,
+        }
+    }
+
+    #[inline]
+    pub const fn is_signer(&self) -> &'static DepositStakeIxAccsFlag {
+        if self.is_permissioned {
+            &DEPOSIT_STAKE_IX_PERMISSIONED_IS_SIGNER
+        } else {
+            &DEPOSIT_STAKE_IX_IS_SIGNER
+        }
+    }
+
+    /// Returns `(pubkey, is_signer, is_writer)` of every account in order
+    #[inline]
+    pub fn keys_signer_writer(&self) -> impl Iterator<Item = (&[u8; 32], bool, bool)> {
+        keys_signer_writer_iter(
+            &self.keys.0,
+            &self.is_signer().0,
+            &DEPOSIT_STAKE_IX_IS_WRITER.0,
+        )
+    }
+}
+
 #[repr(transparent)]
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -65,6 +65,8 @@ impl InitializeIxPrefixKeys<'_> {
 pub struct InitializeIxData(#[cfg_attr(feature = "serde", serde(with = "serde_bytes"))] [u8; 54]);
 
 impl InitializeIxData {
+    /// The validator list account must have been allocated with
+    /// at least [`crate::ValidatorList::account_len`]`(max_validators)` bytes
     #[inline]
     pub fn new(
         fee: Fee,
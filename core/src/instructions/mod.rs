@@ -1,20 +1,30 @@
+mod associated_token;
 mod cleanup_removed_validator_entries;
 mod decrease_additional_validator_stake;
 mod deposit_sol;
 mod deposit_stake;
 mod increase_additional_validator_stake;
 mod initialize;
+#[cfg(feature = "solana-pubkey")]
+mod solana_compat;
+mod stake_program;
+mod system_program;
+mod token_program;
 mod update_stake_pool_balance;
 mod update_validator_list_balance;
 mod withdraw_sol;
 mod withdraw_stake;
 
+pub use associated_token::*;
 pub use cleanup_removed_validator_entries::*;
 pub use decrease_additional_validator_stake::*;
 pub use deposit_sol::*;
 pub use deposit_stake::*;
 pub use increase_additional_validator_stake::*;
 pub use initialize::*;
+pub use stake_program::*;
+pub use system_program::*;
+pub use token_program::*;
 pub use update_stake_pool_balance::*;
 pub use update_validator_list_balance::*;
 pub use withdraw_sol::*;
@@ -54,3 +64,15 @@ seqconsts!(
     INSTRUCTION_IDX_DEPOSIT_SOL_WITH_SLIPPAGE,
     INSTRUCTION_IDX_WITHDRAW_SOL_WITH_SLIPPAGE,
 );
+
+/// Zips each key with its signer and writer flag
+#[inline]
+pub(crate) fn keys_signer_writer_iter<'a, const N: usize>(
+    keys: &'a [[u8; 32]; N],
+    signer: &'a [bool; N],
+    writer: &'a [bool; N],
+) -> impl Iterator<Item = (&'a [u8; 32], bool, bool)> + 'a {
+    keys.iter()
+        .zip(signer.iter().zip(writer.iter()))
+        .map(|(k, (s, w))| (k, *s, *w))
+}
@@ -0,0 +1,122 @@
+//! Conversions of `*IxKeys` to solana-sdk types.
+//!
+//! Each `*IxKeys` type gets:
+//!
+//! - `to_pubkeys()` with feature `solana-pubkey`
+//! - `to_account_metas()` and `to_instruction()` with feature `solana-instruction`
+//!
+//! For `*IxPrefixKeys` types, any suffix accounts must be appended to
+//! the returned `AccountMeta`s / `Instruction::accounts` by the caller,
+//! or use the corresponding `*IxFullKeysOwned` type instead.
+
+use crate::*;
+
+#[cfg(feature = "solana-instruction")]
+#[inline]
+fn keys_signer_writer_to_account_metas<const N: usize>(
+    keys: &[&[u8; 32]; N],
+    signer: &[bool; N],
+    writer: &[bool; N],
+) -> [solana_instruction::AccountMeta; N] {
+    core::array::from_fn(|i| solana_instruction::AccountMeta {
+        pubkey: solana_pubkey::Pubkey::new_from_array(*keys[i]),
+        is_signer: signer[i],
+        is_writable: writer[i],
+    })
+}
+
+macro_rules! impl_solana_compat {
+    ($($Keys:ident, $LEN:ident, $IS_SIGNER:ident, $IS_WRITER:ident);* $(;)?) => {
+        $(
+            impl $Keys<'_> {
+                #[inline]
+                pub fn to_pubkeys(&self) -> [solana_pubkey::Pubkey; $LEN] {
+                    self.0.map(|k| solana_pubkey::Pubkey::new_from_array(*k))
+                }
+
+                #[cfg(feature = "solana-instruction")]
+                #[inline]
+                pub fn to_account_metas(&self) -> [solana_instruction::AccountMeta; $LEN] {
+                    keys_signer_writer_to_account_metas(&self.0, &$IS_SIGNER.0, &$IS_WRITER.0)
+                }
+
+                #[cfg(feature = "solana-instruction")]
+                #[inline]
+                pub fn to_instruction(
+                    &self,
+                    program_id: &[u8; 32],
+                    data: &[u8],
+                ) -> solana_instruction::Instruction {
+                    solana_instruction::Instruction {
+                        program_id: solana_pubkey::Pubkey::new_from_array(*program_id),
+                        accounts: self.to_account_metas().into(),
+                        data: data.into(),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_solana_compat!(
+    CleanupRemovedValidatorEntriesIxKeys, CLEANUP_REMOVED_VALIDATOR_ENTRIES_IX_ACCS_LEN, CLEANUP_REMOVED_VALIDATOR_ENTRIES_IX_IS_SIGNER, CLEANUP_REMOVED_VALIDATOR_ENTRIES_IX_IS_WRITER;
+    CreateAccountIxKeys, CREATE_ACCOUNT_IX_ACCS_LEN, CREATE_ACCOUNT_IX_IS_SIGNER, CREATE_ACCOUNT_IX_IS_WRITER;
+    CreateAtaIdempotentIxKeys, CREATE_ATA_IDEMPOTENT_IX_ACCS_LEN, CREATE_ATA_IDEMPOTENT_IX_IS_SIGNER, CREATE_ATA_IDEMPOTENT_IX_IS_WRITER;
+    DecreaseAdditionalValidatorStakeIxKeys, DECREASE_ADDITIONAL_VALIDATOR_STAKE_IX_ACCS_LEN, DECREASE_ADDITIONAL_VALIDATOR_STAKE_IX_IS_SIGNER, DECREASE_ADDITIONAL_VALIDATOR_STAKE_IX_IS_WRITER;
+    DepositSolIxPrefixKeys, DEPOSIT_SOL_IX_PREFIX_ACCS_LEN, DEPOSIT_SOL_IX_PREFIX_IS_SIGNER, DEPOSIT_SOL_IX_PREFIX_IS_WRITER;
+    DepositStakeIxKeys, DEPOSIT_STAKE_IX_ACCS_LEN, DEPOSIT_STAKE_IX_IS_SIGNER, DEPOSIT_STAKE_IX_IS_WRITER;
+    IncreaseAdditionalValidatorStakeIxKeys, INCREASE_ADDITIONAL_VALIDATOR_STAKE_IX_ACCS_LEN, INCREASE_ADDITIONAL_VALIDATOR_STAKE_IX_IS_SIGNER, INCREASE_ADDITIONAL_VALIDATOR_STAKE_IX_IS_WRITER;
+    TokenInitializeMintIxKeys, TOKEN_INITIALIZE_MINT_IX_ACCS_LEN, TOKEN_INITIALIZE_MINT_IX_IS_SIGNER, TOKEN_INITIALIZE_MINT_IX_IS_WRITER;
+    InitializeIxPrefixKeys, INITIALIZE_IX_PREFIX_ACCS_LEN, INITIALIZE_IX_PREFIX_IS_SIGNER, INITIALIZE_IX_PREFIX_IS_WRITER;
+    StakeAuthorizeCheckedIxPrefixKeys, STAKE_AUTHORIZE_CHECKED_IX_PREFIX_ACCS_LEN, STAKE_AUTHORIZE_CHECKED_IX_PREFIX_IS_SIGNER, STAKE_AUTHORIZE_CHECKED_IX_PREFIX_IS_WRITER;
+    StakeAuthorizeIxPrefixKeys, STAKE_AUTHORIZE_IX_PREFIX_ACCS_LEN, STAKE_AUTHORIZE_IX_PREFIX_IS_SIGNER, STAKE_AUTHORIZE_IX_PREFIX_IS_WRITER;
+    StakeDeactivateIxKeys, STAKE_DEACTIVATE_IX_ACCS_LEN, STAKE_DEACTIVATE_IX_IS_SIGNER, STAKE_DEACTIVATE_IX_IS_WRITER;
+    StakeDelegateStakeIxKeys, STAKE_DELEGATE_STAKE_IX_ACCS_LEN, STAKE_DELEGATE_STAKE_IX_IS_SIGNER, STAKE_DELEGATE_STAKE_IX_IS_WRITER;
+    StakeInitializeIxKeys, STAKE_INITIALIZE_IX_ACCS_LEN, STAKE_INITIALIZE_IX_IS_SIGNER, STAKE_INITIALIZE_IX_IS_WRITER;
+    StakeMergeIxKeys, STAKE_MERGE_IX_ACCS_LEN, STAKE_MERGE_IX_IS_SIGNER, STAKE_MERGE_IX_IS_WRITER;
+    StakeSplitIxKeys, STAKE_SPLIT_IX_ACCS_LEN, STAKE_SPLIT_IX_IS_SIGNER, STAKE_SPLIT_IX_IS_WRITER;
+    StakeWithdrawIxPrefixKeys, STAKE_WITHDRAW_IX_PREFIX_ACCS_LEN, STAKE_WITHDRAW_IX_PREFIX_IS_SIGNER, STAKE_WITHDRAW_IX_PREFIX_IS_WRITER;
+    UpdateStakePoolBalanceIxKeys, UPDATE_STAKE_POOL_BALANCE_IX_ACCS_LEN, UPDATE_STAKE_POOL_BALANCE_IX_IS_SIGNER, UPDATE_STAKE_POOL_BALANCE_IX_IS_WRITER;
+    UpdateValidatorListBalanceIxPrefixKeys, UPDATE_VALIDATOR_LIST_BALANCE_IX_PREFIX_ACCS_LEN, UPDATE_VALIDATOR_LIST_BALANCE_IX_PREFIX_IS_SIGNER, UPDATE_VALIDATOR_LIST_BALANCE_IX_PREFIX_IS_WRITER;
+    WithdrawSolIxPrefixKeys, WITHDRAW_SOL_IX_PREFIX_ACCS_LEN, WITHDRAW_SOL_IX_PREFIX_IS_SIGNER, WITHDRAW_SOL_IX_PREFIX_IS_WRITER;
+    WithdrawStakeIxKeys, WITHDRAW_STAKE_IX_ACCS_LEN, WITHDRAW_STAKE_IX_PREFIX_IS_SIGNER, WITHDRAW_STAKE_IX_PREFIX_IS_WRITER;
+);
+
+macro_rules! impl_solana_compat_full {
+    ($($FullKeys:ident),* $(,)?) => {
+        $(
+            #[cfg(feature = "solana-instruction")]
+            impl $FullKeys {
+                #[inline]
+                pub fn to_account_metas(&self) -> Vec<solana_instruction::AccountMeta> {
+                    self.keys_signer_writer()
+                        .map(|(k, is_signer, is_writable)| solana_instruction::AccountMeta {
+                            pubkey: solana_pubkey::Pubkey::new_from_array(*k),
+                            is_signer,
+                            is_writable,
+                        })
+                        .collect()
+                }
+
+                #[inline]
+                pub fn to_instruction(
+                    &self,
+                    program_id: &[u8; 32],
+                    data: &[u8],
+                ) -> solana_instruction::Instruction {
+                    solana_instruction::Instruction {
+                        program_id: solana_pubkey::Pubkey::new_from_array(*program_id),
+                        accounts: self.to_account_metas(),
+                        data: data.into(),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_solana_compat_full!(
+    DepositSolIxFullKeysOwned,
+    DepositStakeIxFullKeysOwned,
+    WithdrawSolIxFullKeysOwned,
+);
@@ -0,0 +1,93 @@
+use generic_array_struct::generic_array_struct;
+
+use crate::SYSVAR_CLOCK;
+
+use super::{StakeAuthorize, STAKE_INSTRUCTION_IDX_AUTHORIZE};
+
+/// If the stake account's lockup is in force and the withdrawer is being changed,
+/// then the following accounts follow after this prefix:
+///
+///  - `[s]`  lockup custodian
+///
+/// Otherwise, this is the full instruction accounts array
+#[generic_array_struct(builder pub)]
+#[repr(transparent)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "wasm",
+    derive(tsify_next::Tsify),
+    tsify(into_wasm_abi, from_wasm_abi)
+)]
+pub struct StakeAuthorizeIxPrefixAccs<T> {
+    pub stake: T,
+    pub sysvar_clock: T,
+    pub authority: T,
+}
+
+pub type StakeAuthorizeIxPrefixKeysOwned = StakeAuthorizeIxPrefixAccs<[u8; 32]>;
+pub type StakeAuthorizeIxPrefixKeys<'a> = StakeAuthorizeIxPrefixAccs<&'a [u8; 32]>;
+pub type StakeAuthorizeIxPrefixAccsFlag = StakeAuthorizeIxPrefixAccs<bool>;
+
+pub const STAKE_AUTHORIZE_IX_PREFIX_IS_WRITER: StakeAuthorizeIxPrefixAccsFlag =
+    StakeAuthorizeIxPrefixAccs([false; STAKE_AUTHORIZE_IX_PREFIX_ACCS_LEN]).const_with_stake(true);
+
+pub const STAKE_AUTHORIZE_IX_PREFIX_IS_SIGNER: StakeAuthorizeIxPrefixAccsFlag =
+    StakeAuthorizeIxPrefixAccs([false; STAKE_AUTHORIZE_IX_PREFIX_ACCS_LEN])
+        .const_with_authority(true);
+
+impl<T: Clone> StakeAuthorizeIxPrefixAccs<T> {
+    #[inline]
+    pub const fn new(arr: [T; STAKE_AUTHORIZE_IX_PREFIX_ACCS_LEN]) -> Self {
+        Self(arr)
+    }
+}
+
+impl StakeAuthorizeIxPrefixKeysOwned {
+    #[inline]
+    pub fn as_borrowed(&self) -> StakeAuthorizeIxPrefixKeys<'_> {
+        StakeAuthorizeIxPrefixKeys::new(self.0.each_ref())
+    }
+
+    #[inline]
+    pub fn with_consts(self) -> Self {
+        self.as_borrowed().with_consts().into_owned()
+    }
+}
+
+impl StakeAuthorizeIxPrefixKeys<'_> {
+    #[inline]
+    pub fn into_owned(self) -> StakeAuthorizeIxPrefixKeysOwned {
+        StakeAuthorizeIxPrefixKeysOwned::new(self.0.map(|pk| *pk))
+    }
+
+    #[inline]
+    pub const fn with_consts(self) -> Self {
+        self.const_with_sysvar_clock(&SYSVAR_CLOCK)
+    }
+}
+
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StakeAuthorizeIxData(
+    #[cfg_attr(feature = "serde", serde(with = "serde_bytes"))] [u8; 40],
+);
+
+impl StakeAuthorizeIxData {
+    #[inline]
+    pub fn new(new_authority: &[u8; 32], stake_authorize: StakeAuthorize) -> Self {
+        let mut buf = [0u8; 40];
+
+        buf[0..4].copy_from_slice(&STAKE_INSTRUCTION_IDX_AUTHORIZE.to_le_bytes());
+        buf[4..36].copy_from_slice(new_authority);
+        buf[36..40].copy_from_slice(&stake_authorize.as_u32().to_le_bytes());
+
+        Self(buf)
+    }
+
+    #[inline]
+    pub const fn to_buf(&self) -> [u8; 40] {
+        self.0
+    }
+}
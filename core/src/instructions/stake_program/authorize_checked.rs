@@ -0,0 +1,95 @@
+use generic_array_struct::generic_array_struct;
+
+use crate::SYSVAR_CLOCK;
+
+use super::{StakeAuthorize, STAKE_INSTRUCTION_IDX_AUTHORIZE_CHECKED};
+
+/// Same as [`crate::StakeAuthorizeIxPrefixAccs`], but the new authority must also sign.
+///
+/// If the stake account's lockup is in force and the withdrawer is being changed,
+/// then the following accounts follow after this prefix:
+///
+///  - `[s]`  lockup custodian
+///
+/// Otherwise, this is the full instruction accounts array
+#[generic_array_struct(builder pub)]
+#[repr(transparent)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "wasm",
+    derive(tsify_next::Tsify),
+    tsify(into_wasm_abi, from_wasm_abi)
+)]
+pub struct StakeAuthorizeCheckedIxPrefixAccs<T> {
+    pub stake: T,
+    pub sysvar_clock: T,
+    pub authority: T,
+    pub new_authority: T,
+}
+
+pub type StakeAuthorizeCheckedIxPrefixKeysOwned = StakeAuthorizeCheckedIxPrefixAccs<[u8; 32]>;
+pub type StakeAuthorizeCheckedIxPrefixKeys<'a> = StakeAuthorizeCheckedIxPrefixAccs<&'a [u8; 32]>;
+pub type StakeAuthorizeCheckedIxPrefixAccsFlag = StakeAuthorizeCheckedIxPrefixAccs<bool>;
+
+pub const STAKE_AUTHORIZE_CHECKED_IX_PREFIX_IS_WRITER: StakeAuthorizeCheckedIxPrefixAccsFlag =
+    StakeAuthorizeCheckedIxPrefixAccs([false; STAKE_AUTHORIZE_CHECKED_IX_PREFIX_ACCS_LEN])
+        .const_with_stake(true);
+
+pub const STAKE_AUTHORIZE_CHECKED_IX_PREFIX_IS_SIGNER: StakeAuthorizeCheckedIxPrefixAccsFlag =
+    StakeAuthorizeCheckedIxPrefixAccs([false; STAKE_AUTHORIZE_CHECKED_IX_PREFIX_ACCS_LEN])
+        .const_with_authority(true)
+        .const_with_new_authority(true);
+
+impl<T: Clone> StakeAuthorizeCheckedIxPrefixAccs<T> {
+    #[inline]
+    pub const fn new(arr: [T; STAKE_AUTHORIZE_CHECKED_IX_PREFIX_ACCS_LEN]) -> Self {
+        Self(arr)
+    }
+}
+
+impl StakeAuthorizeCheckedIxPrefixKeysOwned {
+    #[inline]
+    pub fn as_borrowed(&self) -> StakeAuthorizeCheckedIxPrefixKeys<'_> {
+        StakeAuthorizeCheckedIxPrefixKeys::new(self.0.each_ref())
+    }
+
+    #[inline]
+    pub fn with_consts(self) -> Self {
+        self.as_borrowed().with_consts().into_owned()
+    }
+}
+
+impl StakeAuthorizeCheckedIxPrefixKeys<'_> {
+    #[inline]
+    pub fn into_owned(self) -> StakeAuthorizeCheckedIxPrefixKeysOwned {
+        StakeAuthorizeCheckedIxPrefixKeysOwned::new(self.0.map(|pk| *pk))
+    }
+
+    #[inline]
+    pub const fn with_consts(self) -> Self {
+        self.const_with_sysvar_clock(&SYSVAR_CLOCK)
+    }
+}
+
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StakeAuthorizeCheckedIxData([u8; 8]);
+
+impl StakeAuthorizeCheckedIxData {
+    #[inline]
+    pub fn new(stake_authorize: StakeAuthorize) -> Self {
+        let mut buf = [0u8; 8];
+
+        buf[0..4].copy_from_slice(&STAKE_INSTRUCTION_IDX_AUTHORIZE_CHECKED.to_le_bytes());
+        buf[4..8].copy_from_slice(&stake_authorize.as_u32().to_le_bytes());
+
+        Self(buf)
+    }
+
+    #[inline]
+    pub const fn to_buf(&self) -> [u8; 8] {
+        self.0
+    }
+}
@@ -0,0 +1,78 @@
+use generic_array_struct::generic_array_struct;
+
+use crate::SYSVAR_CLOCK;
+
+use super::STAKE_INSTRUCTION_IDX_DEACTIVATE;
+
+#[generic_array_struct(builder pub)]
+#[repr(transparent)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "wasm",
+    derive(tsify_next::Tsify),
+    tsify(into_wasm_abi, from_wasm_abi)
+)]
+pub struct StakeDeactivateIxAccs<T> {
+    pub stake: T,
+    pub sysvar_clock: T,
+    pub stake_authority: T,
+}
+
+pub type StakeDeactivateIxKeysOwned = StakeDeactivateIxAccs<[u8; 32]>;
+pub type StakeDeactivateIxKeys<'a> = StakeDeactivateIxAccs<&'a [u8; 32]>;
+pub type StakeDeactivateIxAccsFlag = StakeDeactivateIxAccs<bool>;
+
+pub const STAKE_DEACTIVATE_IX_IS_WRITER: StakeDeactivateIxAccsFlag =
+    StakeDeactivateIxAccs([false; STAKE_DEACTIVATE_IX_ACCS_LEN]).const_with_stake(true);
+
+pub const STAKE_DEACTIVATE_IX_IS_SIGNER: StakeDeactivateIxAccsFlag =
+    StakeDeactivateIxAccs([false; STAKE_DEACTIVATE_IX_ACCS_LEN]).const_with_stake_authority(true);
+
+impl<T: Clone> StakeDeactivateIxAccs<T> {
+    #[inline]
+    pub const fn new(arr: [T; STAKE_DEACTIVATE_IX_ACCS_LEN]) -> Self {
+        Self(arr)
+    }
+}
+
+impl StakeDeactivateIxKeysOwned {
+    #[inline]
+    pub fn as_borrowed(&self) -> StakeDeactivateIxKeys<'_> {
+        StakeDeactivateIxKeys::new(self.0.each_ref())
+    }
+
+    #[inline]
+    pub fn with_consts(self) -> Self {
+        self.as_borrowed().with_consts().into_owned()
+    }
+}
+
+impl StakeDeactivateIxKeys<'_> {
+    #[inline]
+    pub fn into_owned(self) -> StakeDeactivateIxKeysOwned {
+        StakeDeactivateIxKeysOwned::new(self.0.map(|pk| *pk))
+    }
+
+    #[inline]
+    pub const fn with_consts(self) -> Self {
+        self.const_with_sysvar_clock(&SYSVAR_CLOCK)
+    }
+}
+
+#[repr(transparent)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StakeDeactivateIxData([u8; 4]);
+
+impl StakeDeactivateIxData {
+    #[inline]
+    pub const fn new() -> Self {
+        Self(STAKE_INSTRUCTION_IDX_DEACTIVATE.to_le_bytes())
+    }
+
+    #[inline]
+    pub const fn to_buf(&self) -> [u8; 4] {
+        self.0
+    }
+}
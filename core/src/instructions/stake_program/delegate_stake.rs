@@ -0,0 +1,84 @@
+use generic_array_struct::generic_array_struct;
+
+use crate::{SYSVAR_CLOCK, SYSVAR_STAKE_CONFIG, SYSVAR_STAKE_HISTORY};
+
+use super::STAKE_INSTRUCTION_IDX_DELEGATE_STAKE;
+
+#[generic_array_struct(builder pub)]
+#[repr(transparent)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "wasm",
+    derive(tsify_next::Tsify),
+    tsify(into_wasm_abi, from_wasm_abi)
+)]
+pub struct StakeDelegateStakeIxAccs<T> {
+    pub stake: T,
+    pub vote: T,
+    pub sysvar_clock: T,
+    pub sysvar_stake_history: T,
+    pub stake_config: T,
+    pub stake_authority: T,
+}
+
+pub type StakeDelegateStakeIxKeysOwned = StakeDelegateStakeIxAccs<[u8; 32]>;
+pub type StakeDelegateStakeIxKeys<'a> = StakeDelegateStakeIxAccs<&'a [u8; 32]>;
+pub type StakeDelegateStakeIxAccsFlag = StakeDelegateStakeIxAccs<bool>;
+
+pub const STAKE_DELEGATE_STAKE_IX_IS_WRITER: StakeDelegateStakeIxAccsFlag =
+    StakeDelegateStakeIxAccs([false; STAKE_DELEGATE_STAKE_IX_ACCS_LEN]).const_with_stake(true);
+
+pub const STAKE_DELEGATE_STAKE_IX_IS_SIGNER: StakeDelegateStakeIxAccsFlag =
+    StakeDelegateStakeIxAccs([false; STAKE_DELEGATE_STAKE_IX_ACCS_LEN])
+        .const_with_stake_authority(true);
+
+impl<T: Clone> StakeDelegateStakeIxAccs<T> {
+    #[inline]
+    pub const fn new(arr: [T; STAKE_DELEGATE_STAKE_IX_ACCS_LEN]) -> Self {
+        Self(arr)
+    }
+}
+
+impl StakeDelegateStakeIxKeysOwned {
+    #[inline]
+    pub fn as_borrowed(&self) -> StakeDelegateStakeIxKeys<'_> {
+        StakeDelegateStakeIxKeys::new(self.0.each_ref())
+    }
+
+    #[inline]
+    pub fn with_consts(self) -> Self {
+        self.as_borrowed().with_consts().into_owned()
+    }
+}
+
+impl StakeDelegateStakeIxKeys<'_> {
+    #[inline]
+    pub fn into_owned(self) -> StakeDelegateStakeIxKeysOwned {
+        StakeDelegateStakeIxKeysOwned::new(self.0.map(|pk| *pk))
+    }
+
+    #[inline]
+    pub const fn with_consts(self) -> Self {
+        self.const_with_sysvar_clock(&SYSVAR_CLOCK)
+            .const_with_sysvar_stake_history(&SYSVAR_STAKE_HISTORY)
+            .const_with_stake_config(&SYSVAR_STAKE_CONFIG)
+    }
+}
+
+#[repr(transparent)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StakeDelegateStakeIxData([u8; 4]);
+
+impl StakeDelegateStakeIxData {
+    #[inline]
+    pub const fn new() -> Self {
+        Self(STAKE_INSTRUCTION_IDX_DELEGATE_STAKE.to_le_bytes())
+    }
+
+    #[inline]
+    pub const fn to_buf(&self) -> [u8; 4] {
+        self.0
+    }
+}
@@ -0,0 +1,89 @@
+use generic_array_struct::generic_array_struct;
+
+use crate::{Lockup, SYSVAR_RENT};
+
+use super::STAKE_INSTRUCTION_IDX_INITIALIZE;
+
+/// The stake account must be a rent exempt, uninitialized stake account
+#[generic_array_struct(builder pub)]
+#[repr(transparent)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "wasm",
+    derive(tsify_next::Tsify),
+    tsify(into_wasm_abi, from_wasm_abi)
+)]
+pub struct StakeInitializeIxAccs<T> {
+    pub stake: T,
+    pub sysvar_rent: T,
+}
+
+pub type StakeInitializeIxKeysOwned = StakeInitializeIxAccs<[u8; 32]>;
+pub type StakeInitializeIxKeys<'a> = StakeInitializeIxAccs<&'a [u8; 32]>;
+pub type StakeInitializeIxAccsFlag = StakeInitializeIxAccs<bool>;
+
+pub const STAKE_INITIALIZE_IX_IS_WRITER: StakeInitializeIxAccsFlag =
+    StakeInitializeIxAccs([false; STAKE_INITIALIZE_IX_ACCS_LEN]).const_with_stake(true);
+
+pub const STAKE_INITIALIZE_IX_IS_SIGNER: StakeInitializeIxAccsFlag =
+    StakeInitializeIxAccs([false; STAKE_INITIALIZE_IX_ACCS_LEN]);
+
+impl<T: Clone> StakeInitializeIxAccs<T> {
+    #[inline]
+    pub const fn new(arr: [T; STAKE_INITIALIZE_IX_ACCS_LEN]) -> Self {
+        Self(arr)
+    }
+}
+
+impl StakeInitializeIxKeysOwned {
+    #[inline]
+    pub fn as_borrowed(&self) -> StakeInitializeIxKeys<'_> {
+        StakeInitializeIxKeys::new(self.0.each_ref())
+    }
+
+    #[inline]
+    pub fn with_consts(self) -> Self {
+        self.as_borrowed().with_consts().into_owned()
+    }
+}
+
+impl StakeInitializeIxKeys<'_> {
+    #[inline]
+    pub fn into_owned(self) -> StakeInitializeIxKeysOwned {
+        StakeInitializeIxKeysOwned::new(self.0.map(|pk| *pk))
+    }
+
+    #[inline]
+    pub const fn with_consts(self) -> Self {
+        self.const_with_sysvar_rent(&SYSVAR_RENT)
+    }
+}
+
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StakeInitializeIxData(
+    #[cfg_attr(feature = "serde", serde(with = "serde_bytes"))] [u8; 116],
+);
+
+impl StakeInitializeIxData {
+    #[inline]
+    pub fn new(staker: &[u8; 32], withdrawer: &[u8; 32], lockup: &Lockup) -> Self {
+        let mut buf = [0u8; 116];
+
+        buf[0..4].copy_from_slice(&STAKE_INSTRUCTION_IDX_INITIALIZE.to_le_bytes());
+        buf[4..36].copy_from_slice(staker);
+        buf[36..68].copy_from_slice(withdrawer);
+        buf[68..76].copy_from_slice(&lockup.unix_timestamp.to_le_bytes());
+        buf[76..84].copy_from_slice(&lockup.epoch.to_le_bytes());
+        buf[84..116].copy_from_slice(&lockup.custodian);
+
+        Self(buf)
+    }
+
+    #[inline]
+    pub const fn to_buf(&self) -> [u8; 116] {
+        self.0
+    }
+}
@@ -0,0 +1,84 @@
+use generic_array_struct::generic_array_struct;
+
+use crate::{SYSVAR_CLOCK, SYSVAR_STAKE_HISTORY};
+
+use super::STAKE_INSTRUCTION_IDX_MERGE;
+
+/// Merges `source` into `destination`, draining `source`
+#[generic_array_struct(builder pub)]
+#[repr(transparent)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "wasm",
+    derive(tsify_next::Tsify),
+    tsify(into_wasm_abi, from_wasm_abi)
+)]
+pub struct StakeMergeIxAccs<T> {
+    pub destination: T,
+    pub source: T,
+    pub sysvar_clock: T,
+    pub sysvar_stake_history: T,
+    pub stake_authority: T,
+}
+
+pub type StakeMergeIxKeysOwned = StakeMergeIxAccs<[u8; 32]>;
+pub type StakeMergeIxKeys<'a> = StakeMergeIxAccs<&'a [u8; 32]>;
+pub type StakeMergeIxAccsFlag = StakeMergeIxAccs<bool>;
+
+pub const STAKE_MERGE_IX_IS_WRITER: StakeMergeIxAccsFlag =
+    StakeMergeIxAccs([false; STAKE_MERGE_IX_ACCS_LEN])
+        .const_with_destination(true)
+        .const_with_source(true);
+
+pub const STAKE_MERGE_IX_IS_SIGNER: StakeMergeIxAccsFlag =
+    StakeMergeIxAccs([false; STAKE_MERGE_IX_ACCS_LEN]).const_with_stake_authority(true);
+
+impl<T: Clone> StakeMergeIxAccs<T> {
+    #[inline]
+    pub const fn new(arr: [T; STAKE_MERGE_IX_ACCS_LEN]) -> Self {
+        Self(arr)
+    }
+}
+
+impl StakeMergeIxKeysOwned {
+    #[inline]
+    pub fn as_borrowed(&self) -> StakeMergeIxKeys<'_> {
+        StakeMergeIxKeys::new(self.0.each_ref())
+    }
+
+    #[inline]
+    pub fn with_consts(self) -> Self {
+        self.as_borrowed().with_consts().into_owned()
+    }
+}
+
+impl StakeMergeIxKeys<'_> {
+    #[inline]
+    pub fn into_owned(self) -> StakeMergeIxKeysOwned {
+        StakeMergeIxKeysOwned::new(self.0.map(|pk| *pk))
+    }
+
+    #[inline]
+    pub const fn with_consts(self) -> Self {
+        self.const_with_sysvar_clock(&SYSVAR_CLOCK)
+            .const_with_sysvar_stake_history(&SYSVAR_STAKE_HISTORY)
+    }
+}
+
+#[repr(transparent)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StakeMergeIxData([u8; 4]);
+
+impl StakeMergeIxData {
+    #[inline]
+    pub const fn new() -> Self {
+        Self(STAKE_INSTRUCTION_IDX_MERGE.to_le_bytes())
+    }
+
+    #[inline]
+    pub const fn to_buf(&self) -> [u8; 4] {
+        self.0
+    }
+}
@@ -0,0 +1,71 @@
+//! Instructions of the native stake program.
+//!
+//! Instruction data is a bincode-serialized enum, which has a u32 LE discriminant.
+
+mod authorize;
+mod authorize_checked;
+mod deactivate;
+mod delegate_stake;
+mod initialize;
+mod merge;
+mod split;
+mod withdraw;
+
+pub use authorize::*;
+pub use authorize_checked::*;
+pub use deactivate::*;
+pub use delegate_stake::*;
+pub use initialize::*;
+pub use merge::*;
+pub use split::*;
+pub use withdraw::*;
+
+use crate::internal_utils::seqconsts;
+
+seqconsts!(
+    ty = u32;
+    count = STAKE_INSTRUCTION_COUNT;
+
+    STAKE_INSTRUCTION_IDX_INITIALIZE,
+    STAKE_INSTRUCTION_IDX_AUTHORIZE,
+    STAKE_INSTRUCTION_IDX_DELEGATE_STAKE,
+    STAKE_INSTRUCTION_IDX_SPLIT,
+    STAKE_INSTRUCTION_IDX_WITHDRAW,
+    STAKE_INSTRUCTION_IDX_DEACTIVATE,
+    STAKE_INSTRUCTION_IDX_SET_LOCKUP,
+    STAKE_INSTRUCTION_IDX_MERGE,
+    STAKE_INSTRUCTION_IDX_AUTHORIZE_WITH_SEED,
+    STAKE_INSTRUCTION_IDX_INITIALIZE_CHECKED,
+    STAKE_INSTRUCTION_IDX_AUTHORIZE_CHECKED,
+    STAKE_INSTRUCTION_IDX_AUTHORIZE_CHECKED_WITH_SEED,
+    STAKE_INSTRUCTION_IDX_SET_LOCKUP_CHECKED,
+    STAKE_INSTRUCTION_IDX_GET_MINIMUM_DELEGATION,
+    STAKE_INSTRUCTION_IDX_DEACTIVATE_DELINQUENT,
+    STAKE_INSTRUCTION_IDX_REDELEGATE,
+    STAKE_INSTRUCTION_IDX_MOVE_STAKE,
+    STAKE_INSTRUCTION_IDX_MOVE_LAMPORTS,
+);
+
+/// The stake account authority to change in
+/// [`StakeAuthorizeIxData`] and [`StakeAuthorizeCheckedIxData`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "wasm",
+    derive(tsify_next::Tsify),
+    tsify(into_wasm_abi, from_wasm_abi)
+)]
+pub enum StakeAuthorize {
+    Staker,
+    Withdrawer,
+}
+
+impl StakeAuthorize {
+    #[inline]
+    pub const fn as_u32(&self) -> u32 {
+        match self {
+            Self::Staker => 0,
+            Self::Withdrawer => 1,
+        }
+    }
+}
@@ -0,0 +1,74 @@
+use generic_array_struct::generic_array_struct;
+
+use super::STAKE_INSTRUCTION_IDX_SPLIT;
+
+/// The split destination must be a rent exempt, uninitialized stake account
+#[generic_array_struct(builder pub)]
+#[repr(transparent)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "wasm",
+    derive(tsify_next::Tsify),
+    tsify(into_wasm_abi, from_wasm_abi)
+)]
+pub struct StakeSplitIxAccs<T> {
+    pub stake: T,
+    pub split_to: T,
+    pub stake_authority: T,
+}
+
+pub type StakeSplitIxKeysOwned = StakeSplitIxAccs<[u8; 32]>;
+pub type StakeSplitIxKeys<'a> = StakeSplitIxAccs<&'a [u8; 32]>;
+pub type StakeSplitIxAccsFlag = StakeSplitIxAccs<bool>;
+
+pub const STAKE_SPLIT_IX_IS_WRITER: StakeSplitIxAccsFlag =
+    StakeSplitIxAccs([false; STAKE_SPLIT_IX_ACCS_LEN])
+        .const_with_stake(true)
+        .const_with_split_to(true);
+
+pub const STAKE_SPLIT_IX_IS_SIGNER: StakeSplitIxAccsFlag =
+    StakeSplitIxAccs([false; STAKE_SPLIT_IX_ACCS_LEN]).const_with_stake_authority(true);
+
+impl<T: Clone> StakeSplitIxAccs<T> {
+    #[inline]
+    pub const fn new(arr: [T; STAKE_SPLIT_IX_ACCS_LEN]) -> Self {
+        Self(arr)
+    }
+}
+
+impl StakeSplitIxKeysOwned {
+    #[inline]
+    pub fn as_borrowed(&self) -> StakeSplitIxKeys<'_> {
+        StakeSplitIxKeys::new(self.0.each_ref())
+    }
+}
+
+impl StakeSplitIxKeys<'_> {
+    #[inline]
+    pub fn into_owned(self) -> StakeSplitIxKeysOwned {
+        StakeSplitIxKeysOwned::new(self.0.map(|pk| *pk))
+    }
+}
+
+#[repr(transparent)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StakeSplitIxData([u8; 12]);
+
+impl StakeSplitIxData {
+    #[inline]
+    pub fn new(lamports: u64) -> Self {
+        let mut buf = [0u8; 12];
+
+        buf[0..4].copy_from_slice(&STAKE_INSTRUCTION_IDX_SPLIT.to_le_bytes());
+        buf[4..12].copy_from_slice(&lamports.to_le_bytes());
+
+        Self(buf)
+    }
+
+    #[inline]
+    pub const fn to_buf(&self) -> [u8; 12] {
+        self.0
+    }
+}
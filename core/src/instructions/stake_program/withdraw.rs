@@ -0,0 +1,95 @@
+use generic_array_struct::generic_array_struct;
+
+use crate::{SYSVAR_CLOCK, SYSVAR_STAKE_HISTORY};
+
+use super::STAKE_INSTRUCTION_IDX_WITHDRAW;
+
+/// If the stake account's lockup is in force,
+/// then the following accounts follow after this prefix:
+///
+///  - `[s]`  lockup custodian
+///
+/// Otherwise, this is the full instruction accounts array
+#[generic_array_struct(builder pub)]
+#[repr(transparent)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "wasm",
+    derive(tsify_next::Tsify),
+    tsify(into_wasm_abi, from_wasm_abi)
+)]
+pub struct StakeWithdrawIxPrefixAccs<T> {
+    pub stake: T,
+    pub to: T,
+    pub sysvar_clock: T,
+    pub sysvar_stake_history: T,
+    pub withdraw_authority: T,
+}
+
+pub type StakeWithdrawIxPrefixKeysOwned = StakeWithdrawIxPrefixAccs<[u8; 32]>;
+pub type StakeWithdrawIxPrefixKeys<'a> = StakeWithdrawIxPrefixAccs<&'a [u8; 32]>;
+pub type StakeWithdrawIxPrefixAccsFlag = StakeWithdrawIxPrefixAccs<bool>;
+
+pub const STAKE_WITHDRAW_IX_PREFIX_IS_WRITER: StakeWithdrawIxPrefixAccsFlag =
+    StakeWithdrawIxPrefixAccs([false; STAKE_WITHDRAW_IX_PREFIX_ACCS_LEN])
+        .const_with_stake(true)
+        .const_with_to(true);
+
+pub const STAKE_WITHDRAW_IX_PREFIX_IS_SIGNER: StakeWithdrawIxPrefixAccsFlag =
+    StakeWithdrawIxPrefixAccs([false; STAKE_WITHDRAW_IX_PREFIX_ACCS_LEN])
+        .const_with_withdraw_authority(true);
+
+impl<T: Clone> StakeWithdrawIxPrefixAccs<T> {
+    #[inline]
+    pub const fn new(arr: [T; STAKE_WITHDRAW_IX_PREFIX_ACCS_LEN]) -> Self {
+        Self(arr)
+    }
+}
+
+impl StakeWithdrawIxPrefixKeysOwned {
+    #[inline]
+    pub fn as_borrowed(&self) -> StakeWithdrawIxPrefixKeys<'_> {
+        StakeWithdrawIxPrefixKeys::new(self.0.each_ref())
+    }
+
+    #[inline]
+    pub fn with_consts(self) -> Self {
+        self.as_borrowed().with_consts().into_owned()
+    }
+}
+
+impl StakeWithdrawIxPrefixKeys<'_> {
+    #[inline]
+    pub fn into_owned(self) -> StakeWithdrawIxPrefixKeysOwned {
+        StakeWithdrawIxPrefixKeysOwned::new(self.0.map(|pk| *pk))
+    }
+
+    #[inline]
+    pub const fn with_consts(self) -> Self {
+        self.const_with_sysvar_clock(&SYSVAR_CLOCK)
+            .const_with_sysvar_stake_history(&SYSVAR_STAKE_HISTORY)
+    }
+}
+
+#[repr(transparent)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StakeWithdrawIxData([u8; 12]);
+
+impl StakeWithdrawIxData {
+    #[inline]
+    pub fn new(lamports: u64) -> Self {
+        let mut buf = [0u8; 12];
+
+        buf[0..4].copy_from_slice(&STAKE_INSTRUCTION_IDX_WITHDRAW.to_le_bytes());
+        buf[4..12].copy_from_slice(&lamports.to_le_bytes());
+
+        Self(buf)
+    }
+
+    #[inline]
+    pub const fn to_buf(&self) -> [u8; 12] {
+        self.0
+    }
+}
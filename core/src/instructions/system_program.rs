@@ -0,0 +1,77 @@
+//! Instructions of the system program
+
+use generic_array_struct::generic_array_struct;
+
+pub const SYSTEM_IX_IDX_CREATE_ACCOUNT: u32 = 0;
+
+/// Both `from` and `to` must sign
+#[generic_array_struct(builder pub)]
+#[repr(transparent)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "wasm",
+    derive(tsify_next::Tsify),
+    tsify(into_wasm_abi, from_wasm_abi)
+)]
+pub struct CreateAccountIxAccs<T> {
+    pub from: T,
+    pub to: T,
+}
+
+pub type CreateAccountIxKeysOwned = CreateAccountIxAccs<[u8; 32]>;
+pub type CreateAccountIxKeys<'a> = CreateAccountIxAccs<&'a [u8; 32]>;
+pub type CreateAccountIxAccsFlag = CreateAccountIxAccs<bool>;
+
+pub const CREATE_ACCOUNT_IX_IS_WRITER: CreateAccountIxAccsFlag =
+    CreateAccountIxAccs([true; CREATE_ACCOUNT_IX_ACCS_LEN]);
+
+pub const CREATE_ACCOUNT_IX_IS_SIGNER: CreateAccountIxAccsFlag =
+    CreateAccountIxAccs([true; CREATE_ACCOUNT_IX_ACCS_LEN]);
+
+impl<T: Clone> CreateAccountIxAccs<T> {
+    #[inline]
+    pub const fn new(arr: [T; CREATE_ACCOUNT_IX_ACCS_LEN]) -> Self {
+        Self(arr)
+    }
+}
+
+impl CreateAccountIxKeysOwned {
+    #[inline]
+    pub fn as_borrowed(&self) -> CreateAccountIxKeys<'_> {
+        CreateAccountIxKeys::new(self.0.each_ref())
+    }
+}
+
+impl CreateAccountIxKeys<'_> {
+    #[inline]
+    pub fn into_owned(self) -> CreateAccountIxKeysOwned {
+        CreateAccountIxKeysOwned::new(self.0.map(|pk| *pk))
+    }
+}
+
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CreateAccountIxData(
+    #[cfg_attr(feature = "serde", serde(with = "serde_bytes"))] [u8; 52],
+);
+
+impl CreateAccountIxData {
+    #[inline]
+    pub fn new(lamports: u64, space: u64, owner: &[u8; 32]) -> Self {
+        let mut buf = [0u8; 52];
+
+        buf[0..4].copy_from_slice(&SYSTEM_IX_IDX_CREATE_ACCOUNT.to_le_bytes());
+        buf[4..12].copy_from_slice(&lamports.to_le_bytes());
+        buf[12..20].copy_from_slice(&space.to_le_bytes());
+        buf[20..52].copy_from_slice(owner);
+
+        Self(buf)
+    }
+
+    #[inline]
+    pub const fn to_buf(&self) -> [u8; 52] {
+        self.0
+    }
+}
@@ -0,0 +1,76 @@
+//! Instructions of the token programs (both Tokenkeg and Token-2022)
+
+use generic_array_struct::generic_array_struct;
+
+pub const TOKEN_IX_IDX_INITIALIZE_MINT_2: u8 = 20;
+
+/// InitializeMint2, which unlike InitializeMint does not require the rent sysvar
+#[generic_array_struct(builder pub)]
+#[repr(transparent)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "wasm",
+    derive(tsify_next::Tsify),
+    tsify(into_wasm_abi, from_wasm_abi)
+)]
+pub struct TokenInitializeMintIxAccs<T> {
+    pub mint: T,
+}
+
+pub type TokenInitializeMintIxKeysOwned = TokenInitializeMintIxAccs<[u8; 32]>;
+pub type TokenInitializeMintIxKeys<'a> = TokenInitializeMintIxAccs<&'a [u8; 32]>;
+pub type TokenInitializeMintIxAccsFlag = TokenInitializeMintIxAccs<bool>;
+
+pub const TOKEN_INITIALIZE_MINT_IX_IS_WRITER: TokenInitializeMintIxAccsFlag =
+    TokenInitializeMintIxAccs([true; TOKEN_INITIALIZE_MINT_IX_ACCS_LEN]);
+
+pub const TOKEN_INITIALIZE_MINT_IX_IS_SIGNER: TokenInitializeMintIxAccsFlag =
+    TokenInitializeMintIxAccs([false; TOKEN_INITIALIZE_MINT_IX_ACCS_LEN]);
+
+impl<T: Clone> TokenInitializeMintIxAccs<T> {
+    #[inline]
+    pub const fn new(arr: [T; TOKEN_INITIALIZE_MINT_IX_ACCS_LEN]) -> Self {
+        Self(arr)
+    }
+}
+
+impl TokenInitializeMintIxKeysOwned {
+    #[inline]
+    pub fn as_borrowed(&self) -> TokenInitializeMintIxKeys<'_> {
+        TokenInitializeMintIxKeys::new(self.0.each_ref())
+    }
+}
+
+impl TokenInitializeMintIxKeys<'_> {
+    #[inline]
+    pub fn into_owned(self) -> TokenInitializeMintIxKeysOwned {
+        TokenInitializeMintIxKeysOwned::new(self.0.map(|pk| *pk))
+    }
+}
+
+/// Always initializes the mint without a freeze authority,
+/// since the stake pool program does not accept pool mints that have one
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TokenInitializeMintIxData([u8; 35]);
+
+impl TokenInitializeMintIxData {
+    #[inline]
+    pub fn new(decimals: u8, mint_authority: &[u8; 32]) -> Self {
+        let mut buf = [0u8; 35];
+
+        buf[0] = TOKEN_IX_IDX_INITIALIZE_MINT_2;
+        buf[1] = decimals;
+        buf[2..34].copy_from_slice(mint_authority);
+        // buf[34] = 0: COption::None freeze authority
+
+        Self(buf)
+    }
+
+    #[inline]
+    pub const fn to_buf(&self) -> [u8; 35] {
+        self.0
+    }
+}
@@ -1,3 +1,5 @@
+use core::num::NonZeroUsize;
+
 use generic_array_struct::generic_array_struct;
 
 use super::INSTRUCTION_IDX_UPDATE_VALIDATOR_LIST_BALANCE;
@@ -115,3 +117,39 @@ impl UpdateValidatorListBalanceIxData {
         self.0
     }
 }
+
+/// Max number of validator + transient stake account pairs that fit in a single
+/// UpdateValidatorListBalance instruction in a legacy transaction
+/// that has a single fee payer and no other instructions.
+///
+/// Depending on the number of merges required, an instruction with this
+/// many pairs may still exceed the compute limit, so cranks might want to use a lower number.
+pub const UPDATE_VALIDATOR_LIST_BALANCE_MAX_PAIRS_PER_TX: usize = 12;
+
+/// Yields `(start_index, count)` of each UpdateValidatorListBalance instruction
+/// required to update a validator list of `num_validators` validators,
+/// with at most `max_pairs_per_ix` validator + transient stake account pairs per instruction.
+#[inline]
+pub fn update_validator_list_balance_chunks_itr(
+    num_validators: usize,
+    max_pairs_per_ix: NonZeroUsize,
+) -> impl Iterator<Item = (usize, usize)> {
+    (0..num_validators)
+        .step_by(max_pairs_per_ix.get())
+        .map(move |start_index| {
+            (
+                start_index,
+                max_pairs_per_ix.get().min(num_validators - start_index),
+            )
+        })
+}
+
+/// Number of UpdateValidatorListBalance instructions
+/// [`update_validator_list_balance_chunks_itr`] yields
+#[inline]
+pub const fn update_validator_list_balance_ix_count(
+    num_validators: usize,
+    max_pairs_per_ix: NonZeroUsize,
+) -> usize {
+    num_validators.div_ceil(max_pairs_per_ix.get())
+}
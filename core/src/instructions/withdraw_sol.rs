@@ -2,8 +2,16 @@ use generic_array_struct::generic_array_struct;
 
 use crate::{StakePool, STAKE_PROGRAM, SYSVAR_CLOCK, SYSVAR_STAKE_HISTORY};
 
-use super::INSTRUCTION_IDX_WITHDRAW_SOL;
-
+use super::{keys_signer_writer_iter, INSTRUCTION_IDX_WITHDRAW_SOL};
+
+/// If the pool has a non-default sol withdraw authority, then the following
+/// accounts follow after this prefix:
+///
+///  - `[s]`  sol withdraw authority
+///
+/// Otherwise, this is the full instruction accounts array.
+///
+/// Use [`WithdrawSolIxFullKeysOwned`] to get the full accounts array for either case
 #[generic_array_struct(builder pub)]
 #[repr(transparent)]
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
@@ -102,6 +110,96 @@ impl<'a> WithdrawSolIxPrefixKeys<'a> {
     }
 }
 
+/// Accounts that follow [`WithdrawSolIxPrefixAccs`] if the pool
+/// has a non-default sol withdraw authority
+#[generic_array_struct(builder pub)]
+#[repr(transparent)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "wasm",
+    derive(tsify_next::Tsify),
+    tsify(into_wasm_abi, from_wasm_abi)
+)]
+pub struct WithdrawSolIxSuffixAccs<T> {
+    pub sol_withdraw_auth: T,
+}
+
+pub type WithdrawSolIxSuffixKeysOwned = WithdrawSolIxSuffixAccs<[u8; 32]>;
+pub type WithdrawSolIxSuffixKeys<'a> = WithdrawSolIxSuffixAccs<&'a [u8; 32]>;
+pub type WithdrawSolIxSuffixAccsFlag = WithdrawSolIxSuffixAccs<bool>;
+
+pub const WITHDRAW_SOL_IX_SUFFIX_IS_WRITER: WithdrawSolIxSuffixAccsFlag =
+    WithdrawSolIxSuffixAccs([false; WITHDRAW_SOL_IX_SUFFIX_ACCS_LEN]);
+
+pub const WITHDRAW_SOL_IX_SUFFIX_IS_SIGNER: WithdrawSolIxSuffixAccsFlag =
+    WithdrawSolIxSuffixAccs([false; WITHDRAW_SOL_IX_SUFFIX_ACCS_LEN])
+        .const_with_sol_withdraw_auth(true);
+
+impl<T: Clone> WithdrawSolIxSuffixAccs<T> {
+    #[inline]
+    pub const fn new(arr: [T; WITHDRAW_SOL_IX_SUFFIX_ACCS_LEN]) -> Self {
+        Self(arr)
+    }
+}
+
+impl WithdrawSolIxSuffixKeysOwned {
+    #[inline]
+    pub fn as_borrowed(&self) -> WithdrawSolIxSuffixKeys<'_> {
+        WithdrawSolIxSuffixKeys::new(self.0.each_ref())
+    }
+}
+
+impl WithdrawSolIxSuffixKeys<'_> {
+    #[inline]
+    pub fn into_owned(self) -> WithdrawSolIxSuffixKeysOwned {
+        WithdrawSolIxSuffixKeysOwned::new(self.0.map(|pk| *pk))
+    }
+}
+
+/// All accounts of a WithdrawSol instruction,
+/// for both permissioned and permissionless pools
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct WithdrawSolIxFullKeysOwned {
+    pub prefix: WithdrawSolIxPrefixKeysOwned,
+
+    /// `Some` only if the pool has a non-default sol withdraw authority
+    pub suffix: Option<WithdrawSolIxSuffixKeysOwned>,
+}
+
+impl WithdrawSolIxFullKeysOwned {
+    /// Sets `suffix` according to `pool.sol_withdraw_authority`
+    #[inline]
+    pub fn from_prefix_and_stake_pool(
+        prefix: WithdrawSolIxPrefixKeysOwned,
+        pool: &StakePool,
+    ) -> Self {
+        Self {
+            prefix,
+            suffix: pool
+                .sol_withdraw_authority
+                .map(|auth| WithdrawSolIxSuffixKeysOwned::default().with_sol_withdraw_auth(auth)),
+        }
+    }
+
+    /// Returns `(pubkey, is_signer, is_writer)` of every account in order
+    #[inline]
+    pub fn keys_signer_writer(&self) -> impl Iterator<Item = (&[u8; 32], bool, bool)> {
+        keys_signer_writer_iter(
+            &self.prefix.0,
+            &WITHDRAW_SOL_IX_PREFIX_IS_SIGNER.0,
+            &WITHDRAW_SOL_IX_PREFIX_IS_WRITER.0,
+        )
+        .chain(self.suffix.iter().flat_map(|suffix| {
+            keys_signer_writer_iter(
+                &suffix.0,
+                &WITHDRAW_SOL_IX_SUFFIX_IS_SIGNER.0,
+                &WITHDRAW_SOL_IX_SUFFIX_IS_WRITER.0,
+            )
+        }))
+    }
+}
+
 #[repr(transparent)]
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -0,0 +1,56 @@
+//! Registry of well-known stake pools.
+
+use const_crypto::bs58;
+
+use crate::{
+    StakePoolDeployment, SANCTUM_SPL_MULTI_STAKE_POOL_PROGRAM, SANCTUM_SPL_STAKE_POOL_PROGRAM,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct KnownStakePool {
+    /// Symbol of the pool's LST
+    pub symbol: &'static str,
+    pub deployment: StakePoolDeployment,
+    pub pool_mint: [u8; 32],
+}
+
+pub const JUPSOL: KnownStakePool = KnownStakePool {
+    symbol: "jupSOL",
+    deployment: StakePoolDeployment::new(
+        SANCTUM_SPL_MULTI_STAKE_POOL_PROGRAM,
+        bs58::decode_pubkey("8VpRhuxa7sUUepdY3kQiTmX9rS5vx4WgaXiAnXq4KCtr"),
+    ),
+    pool_mint: bs58::decode_pubkey("jupSoLaHXQiZZTSfEWMTRRgpnyFm8f6sZdosWBjx93v"),
+};
+
+pub const PICOSOL: KnownStakePool = KnownStakePool {
+    symbol: "picoSOL",
+    deployment: StakePoolDeployment::new(
+        SANCTUM_SPL_STAKE_POOL_PROGRAM,
+        bs58::decode_pubkey("8Dv3hNYcEWEaa4qVx9BTN1Wfvtha1z8cWDUXb7KVACVe"),
+    ),
+    pool_mint: bs58::decode_pubkey("picobAEvs6w7QEknPce34wAE4gknZA9v5tTonnmHYdX"),
+};
+
+pub const BPSOL: KnownStakePool = KnownStakePool {
+    symbol: "bpSOL",
+    deployment: StakePoolDeployment::new(
+        SANCTUM_SPL_STAKE_POOL_PROGRAM,
+        bs58::decode_pubkey("ETVc1GBAiKzv2gNaA3Hfq4hsS1Mzh1NwQSxRFst7k8vz"),
+    ),
+    pool_mint: bs58::decode_pubkey("BPSoLzmLQn47EP5aa7jmFngRL8KC3TWAeAwXwZD8ip3P"),
+};
+
+pub const KNOWN_STAKE_POOLS: [KnownStakePool; 3] = [JUPSOL, PICOSOL, BPSOL];
+
+#[inline]
+pub fn known_stake_pool_by_addr(stake_pool: &[u8; 32]) -> Option<&'static KnownStakePool> {
+    KNOWN_STAKE_POOLS
+        .iter()
+        .find(|p| p.deployment.stake_pool == *stake_pool)
+}
+
+#[inline]
+pub fn known_stake_pool_by_mint(pool_mint: &[u8; 32]) -> Option<&'static KnownStakePool> {
+    KNOWN_STAKE_POOLS.iter().find(|p| p.pool_mint == *pool_mint)
+}
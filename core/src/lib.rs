@@ -6,19 +6,45 @@
 
 #![cfg_attr(all(not(test), not(feature = "std")), no_std)]
 
+mod apy;
+mod audit;
 mod consts;
+mod deployment;
+mod diff;
 mod error;
+mod filters;
 mod instructions;
 mod internal_utils;
+#[cfg(feature = "known-pools")]
+mod known_pools;
+mod lifecycle;
 mod pda;
+mod rebalance;
+mod report;
 mod state;
+mod sysvars;
+mod trade;
+mod transient;
 mod typedefs;
 mod utils;
 
+pub use apy::*;
+pub use audit::*;
 pub use consts::*;
+pub use deployment::*;
+pub use diff::*;
 pub use error::*;
+pub use filters::*;
 pub use instructions::*;
+#[cfg(feature = "known-pools")]
+pub use known_pools::*;
+pub use lifecycle::*;
 pub use pda::*;
+pub use rebalance::*;
+pub use report::*;
 pub use state::*;
+pub use sysvars::*;
+pub use trade::*;
+pub use transient::*;
 pub use typedefs::*;
 pub use utils::*;
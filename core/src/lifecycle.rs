@@ -0,0 +1,367 @@
+//! Planning of validator onboarding (AddValidatorToPool) and removal
+//! (DecreaseValidatorStakeWithReserve, RemoveValidatorFromPool, CleanupRemovedValidatorEntries)
+//! across epochs towards a target validator set.
+//!
+//! A removed validator's entry goes through
+//! `Active` -> `DeactivatingValidator` / `DeactivatingAll` -> `ReadyForRemoval` -> removed,
+//! where each `->` after the first requires an epoch boundary followed by
+//! UpdateValidatorListBalance, and the final removal is done by CleanupRemovedValidatorEntries.
+
+use core::iter::Enumerate;
+
+use crate::{
+    StakeStatus, ValidatorList, ValidatorStakeInfo, MIN_DECREASE_LAMPORTS,
+    MIN_VALIDATOR_STAKE_ACCOUNT_LAMPORTS, STAKE_ACCOUNT_RENT_EXEMPT_LAMPORTS,
+};
+
+/// Lamports transferred out of the reserve by AddValidatorToPool
+/// to create and delegate the new validator stake account
+pub const ADD_VALIDATOR_LAMPORTS: u64 = MIN_VALIDATOR_STAKE_ACCOUNT_LAMPORTS;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "wasm",
+    derive(tsify_next::Tsify),
+    tsify(into_wasm_abi, from_wasm_abi)
+)]
+pub enum ValidatorLifecycleAction {
+    /// Validator is in the target set and active, nothing to do
+    Keep,
+
+    /// Validator has not been updated for the current epoch,
+    /// UpdateValidatorListBalance must be run before anything else can be planned
+    AwaitUpdate,
+
+    /// Validator is not in the target set and has more than the minimum stake.
+    /// Decrease its stake to the minimum this epoch so that the stake
+    /// returns to the reserve, then remove it in a following epoch.
+    Decrease,
+
+    /// Validator is not in the target set and can be removed with RemoveValidatorFromPool
+    Remove,
+
+    /// Validator has been removed and its stake accounts are deactivating
+    AwaitDeactivation,
+
+    /// Validator's entry can be removed with CleanupRemovedValidatorEntries
+    Cleanup,
+}
+
+/// Planned action for a single validator already on the validator list
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[cfg_attr(
+    feature = "wasm",
+    derive(tsify_next::Tsify),
+    tsify(into_wasm_abi, from_wasm_abi, large_number_types_as_bigints)
+)]
+pub struct ValidatorLifecycleStep {
+    /// Index of the validator on the validator list
+    pub validator_index: usize,
+
+    pub action: ValidatorLifecycleAction,
+
+    /// Lamports to decrease by for [`ValidatorLifecycleAction::Decrease`], 0 otherwise
+    pub lamports: u64,
+
+    /// Expected status of the validator once the validator list
+    /// has been updated for the next epoch, assuming all stake deactivates
+    /// within a single epoch. `None` if the entry is expected to no longer exist.
+    pub next_status: Option<StakeStatus>,
+}
+
+impl ValidatorLifecycleStep {
+    /// Lamports that executing this step takes out of the reserve:
+    /// the transient stake account rent of a decrease,
+    /// taken to be mainnet-beta's [`STAKE_ACCOUNT_RENT_EXEMPT_LAMPORTS`]
+    #[inline]
+    pub const fn reserve_lamports_cost(&self) -> u64 {
+        match self.action {
+            // transient stake account rent is funded from the reserve
+            ValidatorLifecycleAction::Decrease => STAKE_ACCOUNT_RENT_EXEMPT_LAMPORTS,
+            _ => 0,
+        }
+    }
+}
+
+/// Iterator returned by [`validator_lifecycle_plan_itr`]
+#[derive(Debug, Clone)]
+pub struct ValidatorLifecyclePlanItr<'a, F> {
+    validators: Enumerate<core::slice::Iter<'a, ValidatorStakeInfo>>,
+    is_target: F,
+    current_epoch: u64,
+}
+
+/// Yields one [`ValidatorLifecycleStep`] for each validator on the validator list,
+/// in validator list order, that moves the pool towards only containing
+/// validators for which `is_target(vote)` returns true.
+///
+/// - validators not updated for `current_epoch` are [`ValidatorLifecycleAction::AwaitUpdate`]
+/// - non-target active validators with at least [`MIN_DECREASE_LAMPORTS`] above
+///   [`MIN_VALIDATOR_STAKE_ACCOUNT_LAMPORTS`] and no pending transient stake are decreased first
+/// - other non-target active validators are removed
+/// - validators already being removed continue being removed even if they are in the target set,
+///   they can only be re-added once cleaned up, which can be done in the same epoch
+///   for [`StakeStatus::ReadyForRemoval`] entries, see [`validator_adds_plan_itr`]
+#[inline]
+pub fn validator_lifecycle_plan_itr<'a, F: Fn(&[u8; 32]) -> bool>(
+    validator_list: &ValidatorList<'a>,
+    current_epoch: u64,
+    is_target: F,
+) -> ValidatorLifecyclePlanItr<'a, F> {
+    ValidatorLifecyclePlanItr {
+        validators: validator_list.validators.iter().enumerate(),
+        is_target,
+        current_epoch,
+    }
+}
+
+impl<F: Fn(&[u8; 32]) -> bool> ValidatorLifecyclePlanItr<'_, F> {
+    #[inline]
+    fn step_for(&self, validator_index: usize, vsi: &ValidatorStakeInfo) -> ValidatorLifecycleStep {
+        let status = vsi.status();
+        let (action, lamports, next_status) = if !vsi.is_updated_for_epoch(self.current_epoch) {
+            (ValidatorLifecycleAction::AwaitUpdate, 0, Some(status))
+        } else {
+            match status {
+                StakeStatus::Active => {
+                    if (self.is_target)(vsi.vote_account_address()) {
+                        (ValidatorLifecycleAction::Keep, 0, Some(StakeStatus::Active))
+                    } else {
+                        let excess = vsi
+                            .active_stake_lamports()
+                            .saturating_sub(MIN_VALIDATOR_STAKE_ACCOUNT_LAMPORTS);
+                        if vsi.transient_stake_lamports() == 0
+                            && excess >= MIN_DECREASE_LAMPORTS
+                            && vsi.next_transient_seed().is_some()
+                        {
+                            (
+                                ValidatorLifecycleAction::Decrease,
+                                excess,
+                                Some(StakeStatus::Active),
+                            )
+                        } else {
+                            (
+                                ValidatorLifecycleAction::Remove,
+                                0,
+                                Some(StakeStatus::ReadyForRemoval),
+                            )
+                        }
+                    }
+                }
+                StakeStatus::DeactivatingTransient
+                | StakeStatus::DeactivatingValidator
+                | StakeStatus::DeactivatingAll => (
+                    ValidatorLifecycleAction::AwaitDeactivation,
+                    0,
+                    Some(StakeStatus::ReadyForRemoval),
+                ),
+                StakeStatus::ReadyForRemoval => (ValidatorLifecycleAction::Cleanup, 0, None),
+            }
+        };
+        ValidatorLifecycleStep {
+            validator_index,
+            action,
+            lamports,
+            next_status,
+        }
+    }
+}
+
+impl<F: Fn(&[u8; 32]) -> bool> Iterator for ValidatorLifecyclePlanItr<'_, F> {
+    type Item = ValidatorLifecycleStep;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let (i, vsi) = self.validators.next()?;
+        Some(self.step_for(i, vsi))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.validators.size_hint()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "wasm",
+    derive(tsify_next::Tsify),
+    tsify(into_wasm_abi, from_wasm_abi)
+)]
+pub enum ValidatorAddStatus {
+    /// AddValidatorToPool can be executed this epoch
+    Fundable,
+
+    /// Validator is already active on the validator list,
+    /// or is a repeat of an earlier vote account planned as [`Self::Fundable`]
+    AlreadyAdded,
+
+    /// Validator's entry is still deactivating on the validator list,
+    /// it can only be added again once it is [`StakeStatus::ReadyForRemoval`]
+    /// and has been removed by CleanupRemovedValidatorEntries
+    AwaitingCleanup,
+
+    /// Not enough lamports in the reserve to fund the validator stake account
+    InsufficientReserve,
+
+    /// No space left in the validator list
+    ValidatorListFull,
+}
+
+/// Planned AddValidatorToPool for a single validator not yet on the validator list
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[cfg_attr(
+    feature = "wasm",
+    derive(tsify_next::Tsify),
+    tsify(into_wasm_abi, from_wasm_abi)
+)]
+pub struct ValidatorAddStep {
+    /// Index of the vote account in the iterator passed to [`validator_adds_plan_itr`]
+    pub add_index: usize,
+
+    pub status: ValidatorAddStatus,
+}
+
+/// Iterator returned by [`validator_adds_plan_itr`]
+#[derive(Debug, Clone)]
+pub struct ValidatorAddsPlanItr<'a, I> {
+    validators: &'a [ValidatorStakeInfo],
+
+    /// Copy of the vote accounts iterator before any were yielded,
+    /// for finding repeats of earlier vote accounts
+    all_vote_accounts: I,
+
+    vote_accounts: Enumerate<I>,
+    reserve_stake_lamports: u64,
+    free_slots: usize,
+
+    /// Index of the first vote account that was not fundable
+    /// for lack of free slots or reserve lamports
+    first_unfundable_index: Option<usize>,
+}
+
+/// Yields one [`ValidatorAddStep`] for each vote account in `vote_accounts`, in order.
+///
+/// Each [`ValidatorAddStatus::Fundable`] add takes [`ADD_VALIDATOR_LAMPORTS`]
+/// out of `reserve_stake_lamports`, keeping the reserve rent-exempt, and a slot in the
+/// validator list. Slots of [`StakeStatus::ReadyForRemoval`] entries are counted as free
+/// since CleanupRemovedValidatorEntries can be run before the adds in the same epoch,
+/// so their vote accounts can be added again like vote accounts not on the validator list.
+///
+/// Repeats of a vote account planned as [`ValidatorAddStatus::Fundable`] earlier are
+/// [`ValidatorAddStatus::AlreadyAdded`], so this is `O(n^2)` in the number of vote accounts.
+///
+/// Added validators are expected to be [`StakeStatus::Active`] next epoch.
+///
+/// To account for the reserve rent used by decreases planned by [`validator_lifecycle_plan_itr`],
+/// pass `reserve_stake_lamports` minus the sum of their [`ValidatorLifecycleStep::reserve_lamports_cost`].
+#[inline]
+pub fn validator_adds_plan_itr<'a, 'v, I: Iterator<Item = &'v [u8; 32]> + Clone>(
+    validator_list: &ValidatorList<'a>,
+    reserve_stake_lamports: u64,
+    vote_accounts: I,
+) -> ValidatorAddsPlanItr<'a, I> {
+    let validators = validator_list.validators;
+    let ready_for_removal = validators
+        .iter()
+        .filter(|v| v.status() == StakeStatus::ReadyForRemoval)
+        .count();
+    let used = validators.len().saturating_sub(ready_for_removal);
+    let free_slots = usize::try_from(validator_list.header.max_validators)
+        .unwrap_or(usize::MAX)
+        .saturating_sub(used);
+    ValidatorAddsPlanItr {
+        validators,
+        all_vote_accounts: vote_accounts.clone(),
+        vote_accounts: vote_accounts.enumerate(),
+        reserve_stake_lamports,
+        free_slots,
+        first_unfundable_index: None,
+    }
+}
+
+impl<'v, I: Iterator<Item = &'v [u8; 32]> + Clone> ValidatorAddsPlanItr<'_, I> {
+    /// Lamports in the reserve that can be spent while keeping it rent-exempt
+    #[inline]
+    const fn reserve_available(&self) -> u64 {
+        self.reserve_stake_lamports
+            .saturating_sub(STAKE_ACCOUNT_RENT_EXEMPT_LAMPORTS)
+    }
+
+    /// `add_index` is the index of `vote` in the vote accounts iterator
+    #[inline]
+    fn status_for(&mut self, add_index: usize, vote: &[u8; 32]) -> ValidatorAddStatus {
+        if let Some(vsi) = self
+            .validators
+            .iter()
+            .find(|v| v.vote_account_address() == vote)
+        {
+            match vsi.status() {
+                StakeStatus::Active => return ValidatorAddStatus::AlreadyAdded,
+                // slot is counted as free, entry is cleaned up before the adds
+                StakeStatus::ReadyForRemoval => (),
+                _ => return ValidatorAddStatus::AwaitingCleanup,
+            }
+        }
+        // free slots and reserve lamports only decrease, so every vote account
+        // before the first unfundable one that was not already added or awaiting cleanup
+        // was fundable
+        let first_index = self
+            .all_vote_accounts
+            .clone()
+            .take(add_index)
+            .position(|v| v == vote);
+        let is_repeat_of_fundable = match (first_index, self.first_unfundable_index) {
+            (None, _) => false,
+            (Some(_), None) => true,
+            (Some(first_index), Some(unfundable_index)) => first_index < unfundable_index,
+        };
+        if is_repeat_of_fundable {
+            return ValidatorAddStatus::AlreadyAdded;
+        }
+        let unfundable = if self.free_slots == 0 {
+            Some(ValidatorAddStatus::ValidatorListFull)
+        } else if self.reserve_available() < ADD_VALIDATOR_LAMPORTS {
+            Some(ValidatorAddStatus::InsufficientReserve)
+        } else {
+            None
+        };
+        if let Some(status) = unfundable {
+            self.first_unfundable_index.get_or_insert(add_index);
+            return status;
+        }
+        self.free_slots -= 1;
+        self.reserve_stake_lamports -= ADD_VALIDATOR_LAMPORTS;
+        ValidatorAddStatus::Fundable
+    }
+}
+
+impl<'v, I: Iterator<Item = &'v [u8; 32]> + Clone> Iterator for ValidatorAddsPlanItr<'_, I> {
+    type Item = ValidatorAddStep;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let (add_index, vote) = self.vote_accounts.next()?;
+        Some(ValidatorAddStep {
+            add_index,
+            status: self.status_for(add_index, vote),
+        })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.vote_accounts.size_hint()
+    }
+}
@@ -1,5 +1,7 @@
 use core::num::NonZeroU32;
 
+use crate::StakePool;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum OptionalSeed<S> {
     None,
@@ -73,3 +75,27 @@ pub const fn transient_stake_seeds<'a>(
 pub const fn ephemeral_stake_seeds(stake_pool: &[u8; 32]) -> (&[u8; 9], &[u8; 32], [u8; 8]) {
     (&EPHEMERAL_SEED, stake_pool, [0u8; 8])
 }
+
+#[inline]
+pub const fn ata_seeds<'a>(
+    owner: &'a [u8; 32],
+    token_program: &'a [u8; 32],
+    mint: &'a [u8; 32],
+) -> (&'a [u8; 32], &'a [u8; 32], &'a [u8; 32]) {
+    (owner, token_program, mint)
+}
+
+/// [`ata_seeds`] of `owner`'s pool token account.
+///
+/// Uses the pool's `token_program_id` so that this works for Token-2022 pool mints too.
+#[inline]
+pub const fn pool_token_ata_seeds<'a>(
+    owner: &'a [u8; 32],
+    StakePool {
+        pool_mint,
+        token_program_id,
+        ..
+    }: &'a StakePool,
+) -> (&'a [u8; 32], &'a [u8; 32], &'a [u8; 32]) {
+    ata_seeds(owner, token_program_id, pool_mint)
+}
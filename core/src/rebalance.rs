@@ -0,0 +1,236 @@
+//! Planning of IncreaseAdditionalValidatorStake and DecreaseAdditionalValidatorStake
+//! instructions that move a pool's stake distribution towards target weights.
+//!
+//! The ephemeral stake account used by both instructions is created and closed
+//! within the same instruction, so the fixed seed of [`crate::ephemeral_stake_seeds`]
+//! is always valid.
+//!
+//! All rent-exempt minimums here are mainnet-beta's, i.e. [`STAKE_ACCOUNT_RENT_EXEMPT_LAMPORTS`].
+//! Plans for clusters with higher rent may be rejected by the program.
+
+use core::iter::Enumerate;
+
+use crate::{
+    StakeStatus, TransientStakeState, ValidatorList, ValidatorStakeInfo, MIN_ACTIVE_STAKE,
+    STAKE_ACCOUNT_RENT_EXEMPT_LAMPORTS,
+};
+
+/// Minimum lamports that must remain in a validator stake account
+/// after a decrease, enforced by the spl stake pool program, under mainnet-beta's rent parameters
+pub const MIN_VALIDATOR_STAKE_ACCOUNT_LAMPORTS: u64 =
+    STAKE_ACCOUNT_RENT_EXEMPT_LAMPORTS + MIN_ACTIVE_STAKE;
+
+/// Minimum lamports of a single increase, enforced by the spl stake pool program
+pub const MIN_INCREASE_LAMPORTS: u64 = MIN_ACTIVE_STAKE;
+
+/// Minimum lamports of a single decrease.
+///
+/// Conservatively requires the split-off transient stake account
+/// to be rent-exempt and meet the minimum delegation on its own.
+pub const MIN_DECREASE_LAMPORTS: u64 = STAKE_ACCOUNT_RENT_EXEMPT_LAMPORTS + MIN_ACTIVE_STAKE;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "wasm",
+    derive(tsify_next::Tsify),
+    tsify(into_wasm_abi, from_wasm_abi)
+)]
+pub enum RebalanceDirection {
+    Increase,
+    Decrease,
+}
+
+/// A single IncreaseAdditionalValidatorStake or DecreaseAdditionalValidatorStake instruction
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[cfg_attr(
+    feature = "wasm",
+    derive(tsify_next::Tsify),
+    tsify(into_wasm_abi, from_wasm_abi, large_number_types_as_bigints)
+)]
+pub struct RebalanceStep {
+    /// Index of the validator on the validator list
+    pub validator_index: usize,
+    pub direction: RebalanceDirection,
+    pub lamports: u64,
+    pub transient_stake_seed: u64,
+}
+
+/// Iterator returned by [`rebalance_plan_itr`]
+#[derive(Debug, Clone)]
+pub struct RebalancePlanItr<'a, F, G> {
+    validators: Enumerate<core::slice::Iter<'a, ValidatorStakeInfo>>,
+    weight_of: F,
+    transient_stake_state_of: G,
+    total_weight: u128,
+    total_lamports: u128,
+    reserve_stake_lamports: u64,
+    current_epoch: u64,
+}
+
+/// Yields at most one increase or decrease for each validator,
+/// in validator list order, that moves the validator's stake towards
+/// `weight_of(vote) / sum(weight_of(vote))` of the pool's total stake,
+/// which is the stake of all [`StakeStatus::Active`] validators plus the reserve's.
+///
+/// - only validators that are [`StakeStatus::Active`] and updated for `current_epoch` are considered
+/// - `transient_stake_state_of` is only called for validators with transient stake and returns
+///   the state of their transient stake account, see [`TransientStakeState::from_stake_account_data`].
+///   Such validators are only topped up in the same direction,
+///   see [`ValidatorStakeInfo::check_increase_additional_stake`],
+///   and are skipped if `None` is returned.
+/// - activating transient stake counts towards the validator's stake,
+///   deactivating transient stake towards the reserve's
+/// - increases are funded from `reserve_stake_lamports`, keeping the reserve rent-exempt,
+///   and each cost an additional [`STAKE_ACCOUNT_RENT_EXEMPT_LAMPORTS`] for the transient stake account
+/// - decreases never bring a validator stake account below [`MIN_VALIDATOR_STAKE_ACCOUNT_LAMPORTS`]
+/// - amounts below [`MIN_INCREASE_LAMPORTS`] / [`MIN_DECREASE_LAMPORTS`] are not yielded
+///
+/// To keep some liquidity in the reserve, pass `reserve_stake_lamports` minus the amount to keep.
+#[inline]
+pub fn rebalance_plan_itr<
+    'a,
+    F: Fn(&[u8; 32]) -> u64,
+    G: Fn(usize, &ValidatorStakeInfo) -> Option<TransientStakeState>,
+>(
+    validator_list: &ValidatorList<'a>,
+    reserve_stake_lamports: u64,
+    current_epoch: u64,
+    weight_of: F,
+    transient_stake_state_of: G,
+) -> RebalancePlanItr<'a, F, G> {
+    let validators = validator_list.validators;
+    let total_weight = validators
+        .iter()
+        .filter(|v| v.status() == StakeStatus::Active)
+        .map(|v| u128::from(weight_of(v.vote_account_address())))
+        .sum();
+    let total_lamports = validators
+        .iter()
+        .filter(|v| v.status() == StakeStatus::Active)
+        .map(|v| u128::from(v.active_stake_lamports()) + u128::from(v.transient_stake_lamports()))
+        .sum::<u128>()
+        + u128::from(reserve_stake_lamports.saturating_sub(STAKE_ACCOUNT_RENT_EXEMPT_LAMPORTS));
+    RebalancePlanItr {
+        validators: validators.iter().enumerate(),
+        weight_of,
+        transient_stake_state_of,
+        total_weight,
+        total_lamports,
+        reserve_stake_lamports,
+        current_epoch,
+    }
+}
+
+impl<F: Fn(&[u8; 32]) -> u64, G: Fn(usize, &ValidatorStakeInfo) -> Option<TransientStakeState>>
+    RebalancePlanItr<'_, F, G>
+{
+    /// Target lamports of a validator's stake accounts, including rent
+    #[inline]
+    fn target_lamports(&self, vsi: &ValidatorStakeInfo) -> u64 {
+        if self.total_weight == 0 {
+            return 0;
+        }
+        let target = self.total_lamports * u128::from((self.weight_of)(vsi.vote_account_address()))
+            / self.total_weight;
+        target.try_into().unwrap_or(u64::MAX)
+    }
+
+    /// Lamports in the reserve that can be spent while keeping it rent-exempt
+    #[inline]
+    const fn reserve_available(&self) -> u64 {
+        self.reserve_stake_lamports
+            .saturating_sub(STAKE_ACCOUNT_RENT_EXEMPT_LAMPORTS)
+    }
+
+    #[inline]
+    fn step_for(
+        &mut self,
+        validator_index: usize,
+        vsi: &ValidatorStakeInfo,
+    ) -> Option<RebalanceStep> {
+        if vsi.status() != StakeStatus::Active || !vsi.is_updated_for_epoch(self.current_epoch) {
+            return None;
+        }
+        let transient_stake_state = if vsi.transient_stake_lamports() == 0 {
+            None
+        } else {
+            Some((self.transient_stake_state_of)(validator_index, vsi)?)
+        };
+        let transient_stake_seed = vsi.next_transient_seed()?;
+        let active = vsi.active_stake_lamports();
+        let current = match transient_stake_state {
+            Some(TransientStakeState::Activating) => {
+                active.saturating_add(vsi.transient_stake_lamports())
+            }
+            _ => active,
+        };
+        let target = self.target_lamports(vsi);
+
+        if current > target {
+            if matches!(
+                transient_stake_state,
+                Some(TransientStakeState::Activating | TransientStakeState::AwaitingMerge)
+            ) {
+                return None;
+            }
+            let lamports =
+                (current - target).min(active.saturating_sub(MIN_VALIDATOR_STAKE_ACCOUNT_LAMPORTS));
+            // transient stake account rent is funded from the reserve
+            if lamports < MIN_DECREASE_LAMPORTS
+                || self.reserve_available() < STAKE_ACCOUNT_RENT_EXEMPT_LAMPORTS
+            {
+                return None;
+            }
+            self.reserve_stake_lamports -= STAKE_ACCOUNT_RENT_EXEMPT_LAMPORTS;
+            Some(RebalanceStep {
+                validator_index,
+                direction: RebalanceDirection::Decrease,
+                lamports,
+                transient_stake_seed,
+            })
+        } else {
+            if matches!(
+                transient_stake_state,
+                Some(TransientStakeState::Deactivating | TransientStakeState::AwaitingMerge)
+            ) {
+                return None;
+            }
+            let lamports = (target - current).min(
+                self.reserve_available()
+                    .saturating_sub(STAKE_ACCOUNT_RENT_EXEMPT_LAMPORTS),
+            );
+            if lamports < MIN_INCREASE_LAMPORTS {
+                return None;
+            }
+            self.reserve_stake_lamports -= lamports + STAKE_ACCOUNT_RENT_EXEMPT_LAMPORTS;
+            Some(RebalanceStep {
+                validator_index,
+                direction: RebalanceDirection::Increase,
+                lamports,
+                transient_stake_seed,
+            })
+        }
+    }
+}
+
+impl<F: Fn(&[u8; 32]) -> u64, G: Fn(usize, &ValidatorStakeInfo) -> Option<TransientStakeState>>
+    Iterator for RebalancePlanItr<'_, F, G>
+{
+    type Item = RebalanceStep;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let (i, vsi) = self.validators.next()?;
+            if let Some(step) = self.step_for(i, vsi) {
+                return Some(step);
+            }
+        }
+    }
+}
@@ -0,0 +1,237 @@
+//! Summary statistics of a pool's stake distribution and liquidity for display.
+//!
+//! All ratios are returned as `f64`s since they are only meant for display
+
+use crate::{
+    StakePool, StakeStatus, ValidatorList, ValidatorStakeInfo,
+    MIN_VALIDATOR_STAKE_ACCOUNT_LAMPORTS, STAKE_ACCOUNT_RENT_EXEMPT_LAMPORTS,
+};
+
+/// Number of validators on the validator list with each [`StakeStatus`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[cfg_attr(
+    feature = "wasm",
+    derive(tsify_next::Tsify),
+    tsify(into_wasm_abi, from_wasm_abi)
+)]
+pub struct ValidatorStatusCounts {
+    pub active: u32,
+    pub deactivating_transient: u32,
+    pub ready_for_removal: u32,
+    pub deactivating_validator: u32,
+    pub deactivating_all: u32,
+}
+
+impl ValidatorStatusCounts {
+    #[inline]
+    pub fn from_validators(validators: &[ValidatorStakeInfo]) -> Self {
+        validators.iter().fold(Self::default(), |mut counts, v| {
+            let count = match v.status() {
+                StakeStatus::Active => &mut counts.active,
+                StakeStatus::DeactivatingTransient => &mut counts.deactivating_transient,
+                StakeStatus::ReadyForRemoval => &mut counts.ready_for_removal,
+                StakeStatus::DeactivatingValidator => &mut counts.deactivating_validator,
+                StakeStatus::DeactivatingAll => &mut counts.deactivating_all,
+            };
+            *count += 1;
+            counts
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[cfg_attr(
+    feature = "wasm",
+    derive(tsify_next::Tsify),
+    tsify(into_wasm_abi, from_wasm_abi, large_number_types_as_bigints)
+)]
+pub struct PoolHealthReport {
+    /// The pool's `total_lamports`
+    pub total_lamports: u64,
+
+    /// Lamports of the reserve stake account, excluding
+    /// mainnet-beta's rent-exempt minimum [`STAKE_ACCOUNT_RENT_EXEMPT_LAMPORTS`]
+    pub reserve_lamports: u64,
+
+    /// Sum of all validators' active stake lamports, including rent
+    pub total_active_stake_lamports: u64,
+
+    /// Sum of all validators' transient stake lamports in flight, including rent
+    pub total_transient_stake_lamports: u64,
+
+    /// `reserve_lamports / total_lamports`, 0.0 if `total_lamports` is 0
+    pub reserve_ratio: f64,
+
+    /// Number of validators used for `top_n_share`
+    pub top_n: u32,
+
+    /// Share of validator stake (active + transient) held by the `top_n` validators with the most stake
+    pub top_n_share: f64,
+
+    /// Herfindahl-Hirschman index of validator stake (active + transient) shares,
+    /// from 1 / number of validators (evenly distributed) to 1.0 (all stake on a single validator).
+    /// 0.0 if there is no validator stake.
+    pub hhi: f64,
+
+    pub status_counts: ValidatorStatusCounts,
+
+    /// Number of active validators whose active stake is within
+    /// `near_min_stake_margin_lamports` of the program's minimum.
+    ///
+    /// Only the count is kept since this report does not allocate:
+    /// [`validators_near_min_stake_itr`] with the same margin is the source of
+    /// the list of these validators.
+    pub validators_near_min_stake: u32,
+
+    /// Estimated lamports that can currently be withdrawn with WithdrawSol before fees:
+    /// same as `reserve_lamports`
+    pub withdraw_sol_liquidity_lamports: u64,
+
+    /// Estimated lamports that can currently be withdrawn with WithdrawStake before fees:
+    /// active validators' active stake above [`MIN_VALIDATOR_STAKE_ACCOUNT_LAMPORTS`]
+    pub withdraw_stake_liquidity_lamports: u64,
+}
+
+impl PoolHealthReport {
+    /// `reserve_stake_lamports` is the reserve stake account's lamports, including rent
+    #[inline]
+    pub fn new(
+        stake_pool: &StakePool,
+        validator_list: &ValidatorList,
+        reserve_stake_lamports: u64,
+        top_n: u32,
+        near_min_stake_margin_lamports: u64,
+    ) -> Self {
+        let validators = validator_list.validators;
+        let reserve_lamports =
+            reserve_stake_lamports.saturating_sub(STAKE_ACCOUNT_RENT_EXEMPT_LAMPORTS);
+
+        let (total_active_stake_lamports, total_transient_stake_lamports) =
+            validators.iter().fold((0u64, 0u64), |(a, t), v| {
+                (
+                    a.saturating_add(v.active_stake_lamports()),
+                    t.saturating_add(v.transient_stake_lamports()),
+                )
+            });
+        let total_stake =
+            u128::from(total_active_stake_lamports) + u128::from(total_transient_stake_lamports);
+
+        let hhi = if total_stake == 0 {
+            0.0
+        } else {
+            validators
+                .iter()
+                .map(|v| {
+                    let share = validator_stake(v) as f64 / total_stake as f64;
+                    share * share
+                })
+                .sum()
+        };
+
+        let withdraw_stake_liquidity_lamports = validators
+            .iter()
+            .filter(|v| v.status() == StakeStatus::Active)
+            .map(|v| {
+                v.active_stake_lamports()
+                    .saturating_sub(MIN_VALIDATOR_STAKE_ACCOUNT_LAMPORTS)
+            })
+            .fold(0u64, u64::saturating_add);
+
+        Self {
+            total_lamports: stake_pool.total_lamports,
+            reserve_lamports,
+            total_active_stake_lamports,
+            total_transient_stake_lamports,
+            reserve_ratio: ratio(
+                u128::from(reserve_lamports),
+                u128::from(stake_pool.total_lamports),
+            ),
+            top_n,
+            top_n_share: ratio(top_n_stake(validators, top_n), total_stake),
+            hhi,
+            status_counts: ValidatorStatusCounts::from_validators(validators),
+            validators_near_min_stake: validators_near_min_stake_itr(
+                validator_list,
+                near_min_stake_margin_lamports,
+            )
+            .count()
+            .try_into()
+            .unwrap_or(u32::MAX),
+            withdraw_sol_liquidity_lamports: reserve_lamports,
+            withdraw_stake_liquidity_lamports,
+        }
+    }
+}
+
+/// Yields the validator list indices of active validators whose active stake
+/// is at most [`MIN_VALIDATOR_STAKE_ACCOUNT_LAMPORTS`] + `margin_lamports`,
+/// i.e. whose delegation is at or near [`crate::MIN_ACTIVE_STAKE`].
+/// Validators at the minimum can no longer be decreased or withdrawn from.
+#[inline]
+pub fn validators_near_min_stake_itr<'a>(
+    validator_list: &ValidatorList<'a>,
+    margin_lamports: u64,
+) -> impl Iterator<Item = usize> + 'a {
+    let threshold = MIN_VALIDATOR_STAKE_ACCOUNT_LAMPORTS.saturating_add(margin_lamports);
+    validator_list
+        .validators
+        .iter()
+        .enumerate()
+        .filter(move |(_, v)| {
+            v.status() == StakeStatus::Active && v.active_stake_lamports() <= threshold
+        })
+        .map(|(i, _)| i)
+}
+
+#[inline]
+fn validator_stake(vsi: &ValidatorStakeInfo) -> u128 {
+    u128::from(vsi.active_stake_lamports()) + u128::from(vsi.transient_stake_lamports())
+}
+
+/// Sum of the `n` largest validator stakes.
+///
+/// `O(n * validators.len())` since there is no allocation to sort with:
+/// each round selects the largest `(stake, index)` strictly less than the previous round's.
+#[inline]
+fn top_n_stake(validators: &[ValidatorStakeInfo], n: u32) -> u128 {
+    let mut prev: Option<(u128, usize)> = None;
+    let mut sum = 0;
+    for _ in 0..n {
+        let next = validators
+            .iter()
+            .enumerate()
+            .map(|(i, v)| (validator_stake(v), i))
+            .filter(|k| match prev {
+                Some(p) => *k < p,
+                None => true,
+            })
+            .max();
+        match next {
+            Some(k) => {
+                sum += k.0;
+                prev = Some(k);
+            }
+            None => break,
+        }
+    }
+    sum
+}
+
+#[inline]
+fn ratio(n: u128, d: u128) -> f64 {
+    if d == 0 {
+        0.0
+    } else {
+        n as f64 / d as f64
+    }
+}
@@ -0,0 +1,101 @@
+use crate::{Fee, StakePool};
+
+/// All fees of a stake pool
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[cfg_attr(
+    feature = "wasm",
+    derive(tsify_next::Tsify),
+    tsify(into_wasm_abi, from_wasm_abi, large_number_types_as_bigints)
+)]
+pub struct StakePoolFees {
+    pub epoch_fee: Fee,
+    pub stake_deposit_fee: Fee,
+    pub stake_withdrawal_fee: Fee,
+    pub stake_referral_fee: u8,
+    pub sol_deposit_fee: Fee,
+    pub sol_referral_fee: u8,
+    pub sol_withdrawal_fee: Fee,
+}
+
+impl StakePool {
+    /// Fees currently in effect
+    #[inline]
+    pub const fn fees(&self) -> StakePoolFees {
+        StakePoolFees {
+            epoch_fee: self.epoch_fee,
+            stake_deposit_fee: self.stake_deposit_fee,
+            stake_withdrawal_fee: self.stake_withdrawal_fee,
+            stake_referral_fee: self.stake_referral_fee,
+            sol_deposit_fee: self.sol_deposit_fee,
+            sol_referral_fee: self.sol_referral_fee,
+            sol_withdrawal_fee: self.sol_withdrawal_fee,
+        }
+    }
+
+    /// Number of UpdateStakePoolBalance instructions for new epochs
+    /// required to update the pool for `epoch`, assuming it is updated every epoch
+    #[inline]
+    pub const fn updates_until(&self, epoch: u64) -> u64 {
+        epoch.saturating_sub(self.last_update_epoch)
+    }
+
+    /// Fees that will be in effect at `epoch` once the pool has been updated for it,
+    /// assuming it is updated every epoch and the manager does not schedule any more fee changes.
+    ///
+    /// `next_epoch_fee`, `next_stake_withdrawal_fee` and `next_sol_withdrawal_fee`
+    /// each take effect after 1 (`FutureEpoch::One`) or 2 (`FutureEpoch::Two`) updates.
+    /// All other fees take effect immediately when set so they are unchanged.
+    #[inline]
+    pub fn effective_fees_at(&self, epoch: u64) -> StakePoolFees {
+        let updates = self.updates_until(epoch);
+        StakePoolFees {
+            epoch_fee: self.next_epoch_fee.resolve(self.epoch_fee, updates),
+            stake_withdrawal_fee: self
+                .next_stake_withdrawal_fee
+                .resolve(self.stake_withdrawal_fee, updates),
+            sol_withdrawal_fee: self
+                .next_sol_withdrawal_fee
+                .resolve(self.sol_withdrawal_fee, updates),
+            ..self.fees()
+        }
+    }
+
+    /// Returns a copy of this pool as it will be once updated for `epoch`,
+    /// with fees set to [`Self::effective_fees_at`] and scheduled fee changes advanced,
+    /// so that quotes can be evaluated against the future fee schedule.
+    ///
+    /// Does nothing if `epoch` is not after `self.last_update_epoch`.
+    ///
+    /// NB: `total_lamports` and `pool_token_supply` are left unchanged
+    /// since the epoch's staking rewards are unknown.
+    #[inline]
+    pub fn projected_to_epoch(&self, epoch: u64) -> Self {
+        let updates = self.updates_until(epoch);
+        if updates == 0 {
+            return self.clone();
+        }
+        let StakePoolFees {
+            epoch_fee,
+            stake_withdrawal_fee,
+            sol_withdrawal_fee,
+            ..
+        } = self.effective_fees_at(epoch);
+        Self {
+            epoch_fee,
+            next_epoch_fee: self.next_epoch_fee.advance(updates),
+            stake_withdrawal_fee,
+            next_stake_withdrawal_fee: self.next_stake_withdrawal_fee.advance(updates),
+            sol_withdrawal_fee,
+            next_sol_withdrawal_fee: self.next_sol_withdrawal_fee.advance(updates),
+            last_update_epoch: epoch,
+            last_epoch_pool_token_supply: self.pool_token_supply,
+            last_epoch_total_lamports: self.total_lamports,
+            ..self.clone()
+        }
+    }
+}
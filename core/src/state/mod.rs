@@ -1,4 +1,8 @@
+mod fee_schedule;
 mod pool;
+mod update_status;
 mod validator_list;
+pub use fee_schedule::*;
 pub use pool::*;
+pub use update_status::*;
 pub use validator_list::*;
@@ -1,13 +1,14 @@
 use core::ops::RangeInclusive;
 
 use borsh::{BorshDeserialize, BorshSerialize};
-use sanctum_u64_ratio::{Floor, Ratio};
+use sanctum_u64_ratio::{Ceil, Floor, Ratio};
 
 use crate::{
-    reserve_has_sufficient_lamports, AccountType, DepositSolQuote, DepositSolQuoteArgs,
-    DepositStakeQuote, DepositStakeQuoteArgs, Fee, FutureEpoch, Lockup, ReferralFee,
-    SplStakePoolError, StakeAccountLamports, StakeStatus, WithdrawSolQuote, WithdrawSolQuoteArgs,
-    WithdrawStakeQuote, WithdrawStakeQuoteArgs,
+    reserve_has_sufficient_lamports_with_rent, AccountType, Clock, DepositSolQuote,
+    DepositSolQuoteArgs, DepositStakeQuote, DepositStakeQuoteArgs, Fee, FutureEpoch,
+    InvalidAccountError, Lockup, ReferralFee, Rent, SplStakePoolError, StakeAccountLamports,
+    StakeStatus, WithdrawSolQuote, WithdrawSolQuoteArgs, WithdrawStakeQuote,
+    WithdrawStakeQuoteArgs, WithdrawStakeValidatorContext, STAKE_POOL_PROGRAMS,
 };
 
 #[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize)]
@@ -189,6 +190,37 @@ impl StakePool {
             .is_none_or(|preferred| vote == &preferred)
     }
 
+    /// Checks that a stake account with lockup `stake_lockup` can be deposited,
+    /// which requires it to be the same as the pool's
+    #[inline]
+    pub fn check_deposit_stake_lockup(
+        &self,
+        stake_lockup: &Lockup,
+    ) -> Result<(), SplStakePoolError> {
+        if *stake_lockup == self.lockup {
+            Ok(())
+        } else {
+            Err(SplStakePoolError::StakeLockupMismatch)
+        }
+    }
+
+    /// Stake accounts split off by WithdrawStake inherit the pool's lockup.
+    ///
+    /// Checks that the lockup is not in force at `clock` for `custodian`,
+    /// else the withdrawn stake account cannot be withdrawn from until it expires
+    #[inline]
+    pub fn check_withdrawn_stake_lockup(
+        &self,
+        clock: &Clock,
+        custodian: Option<&[u8; 32]>,
+    ) -> Result<(), SplStakePoolError> {
+        if self.lockup.is_in_force(clock, custodian) {
+            Err(SplStakePoolError::WithdrawnStakeLockupInForce)
+        } else {
+            Ok(())
+        }
+    }
+
     /// Performs the checks needed to be serviceable along with calculation logic
     #[inline]
     pub fn quote_deposit_sol(
@@ -232,6 +264,47 @@ impl StakePool {
         })
     }
 
+    /// Performs the checks needed to be serviceable along with calculation logic
+    #[inline]
+    pub fn quote_rev_deposit_sol(
+        &self,
+        pool_tokens: u64,
+        DepositSolQuoteArgs {
+            depositor,
+            current_epoch,
+        }: &DepositSolQuoteArgs,
+    ) -> Result<DepositSolQuote, SplStakePoolError> {
+        if !self.can_pk_deposit(depositor) {
+            return Err(SplStakePoolError::InvalidSolDepositAuthority);
+        }
+        if !self.is_updated_for_epoch(*current_epoch) {
+            return Err(SplStakePoolError::StakeListAndPoolOutOfDate);
+        }
+        self.quote_rev_deposit_sol_unchecked(pool_tokens)
+            .ok_or(SplStakePoolError::CalculationFailure)
+    }
+
+    /// Reverse of [`Self::quote_deposit_sol_unchecked`]: returns the smallest number
+    /// of lamports required for the deposit given the desired amount of pool tokens out
+    ///
+    /// If the exchange rate is < 1 lamport per pool token, not every amount of pool tokens
+    /// can be minted. The returned quote is then of the smallest deposit that mints
+    /// at least `pool_tokens`, so its `out_amount` may be larger than `pool_tokens`.
+    ///
+    /// Returns `None` on arithmetic overflow.
+    ///
+    /// NB: returned quote might not be applicable if pool has not been updated for the current epoch
+    #[inline]
+    pub fn quote_rev_deposit_sol_unchecked(&self, pool_tokens: u64) -> Option<DepositSolQuote> {
+        let new_pool_tokens = *self
+            .sol_deposit_fee
+            .to_fee_ceil()?
+            .reverse_from_rem(pool_tokens)?
+            .start();
+        let lamports = self.min_lamports_for_pool_tokens(new_pool_tokens)?;
+        self.quote_deposit_sol_unchecked(lamports)
+    }
+
     /// Performs the checks needed to be serviceable along with calculation logic
     #[inline]
     pub fn quote_deposit_stake(
@@ -242,6 +315,7 @@ impl StakePool {
             validator_vote,
             current_epoch,
             depositor,
+            stake_lockup,
         }: &DepositStakeQuoteArgs,
     ) -> Result<DepositStakeQuote, SplStakePoolError> {
         if !self.is_updated_for_epoch(*current_epoch) {
@@ -256,6 +330,9 @@ impl StakePool {
         if depositor.is_some_and(|d| *d != self.stake_deposit_authority) {
             return Err(SplStakePoolError::InvalidStakeDepositAuthority);
         }
+        if let Some(stake_lockup) = stake_lockup {
+            self.check_deposit_stake_lockup(stake_lockup)?;
+        }
 
         self.quote_deposit_stake_unchecked(stake_account_lamports)
             .ok_or(SplStakePoolError::CalculationFailure)
@@ -314,6 +391,7 @@ impl StakePool {
             withdrawer,
             reserve_stake_lamports,
             current_epoch,
+            rent,
         }: &WithdrawSolQuoteArgs,
     ) -> Result<WithdrawSolQuote, SplStakePoolError> {
         if !self.is_updated_for_epoch(*current_epoch) {
@@ -331,7 +409,11 @@ impl StakePool {
             Some(x) => x,
         };
 
-        if !reserve_has_sufficient_lamports(*reserve_stake_lamports, quote.out_amount) {
+        if !reserve_has_sufficient_lamports_with_rent(
+            *reserve_stake_lamports,
+            quote.out_amount,
+            *rent,
+        ) {
             return Err(SplStakePoolError::SolWithdrawalTooLarge);
         }
         Ok(quote)
@@ -346,12 +428,13 @@ impl StakePool {
             withdrawer,
             reserve_stake_lamports,
             current_epoch,
+            rent,
         }: &WithdrawSolQuoteArgs,
     ) -> Result<WithdrawSolQuote, SplStakePoolError> {
         if !self.is_updated_for_epoch(*current_epoch) {
             return Err(SplStakePoolError::StakeListAndPoolOutOfDate);
         }
-        if !reserve_has_sufficient_lamports(*reserve_stake_lamports, lamports) {
+        if !reserve_has_sufficient_lamports_with_rent(*reserve_stake_lamports, lamports, *rent) {
             return Err(SplStakePoolError::SolWithdrawalTooLarge);
         }
         if self
@@ -443,6 +526,25 @@ impl StakePool {
         }
     }
 
+    /// [`Self::quote_withdraw_stake`] that also checks that the validator's stake account
+    /// is left with the minimum lamports required by the program
+    #[inline]
+    pub const fn quote_withdraw_stake_from_validator(
+        &self,
+        pool_tokens: u64,
+        args: WithdrawStakeQuoteArgs,
+        WithdrawStakeValidatorContext { validator, rent }: WithdrawStakeValidatorContext,
+    ) -> Result<WithdrawStakeQuote, SplStakePoolError> {
+        let quote = match self.quote_withdraw_stake(pool_tokens, args) {
+            Ok(x) => x,
+            Err(e) => return Err(e),
+        };
+        match validator.check_withdraw_stake(quote.lamports_staked, rent) {
+            Ok(()) => Ok(quote),
+            Err(e) => Err(e),
+        }
+    }
+
     /// Performs the checks needed to be serviceable along with calculation logic
     #[inline]
     pub const fn quote_rev_withdraw_stake(
@@ -459,12 +561,31 @@ impl StakePool {
         }
     }
 
+    /// [`Self::quote_rev_withdraw_stake`] that also checks that the validator's stake account
+    /// is left with the minimum lamports required by the program
+    #[inline]
+    pub const fn quote_rev_withdraw_stake_from_validator(
+        &self,
+        lamports_staked: u64,
+        args: WithdrawStakeQuoteArgs,
+        WithdrawStakeValidatorContext { validator, rent }: WithdrawStakeValidatorContext,
+    ) -> Result<WithdrawStakeQuote, SplStakePoolError> {
+        if !self.is_updated_for_epoch(args.current_epoch) {
+            return Err(SplStakePoolError::StakeListAndPoolOutOfDate);
+        }
+        if let Err(e) = validator.check_withdraw_stake(lamports_staked, rent) {
+            return Err(e);
+        }
+        self.quote_rev_withdraw_stake(lamports_staked, args)
+    }
+
     /// Returns `None` on arithmetic overflow.
     ///
     /// NB: returned quote might not be applicable if:
     /// - pool has not been updated for the current epoch
     /// - stake account is not active
-    /// - there are insufficient lamports in the stake account to cover the minimum leftover needed
+    /// - there are insufficient lamports in the stake account to cover the minimum leftover needed,
+    ///   see [`crate::ValidatorStakeInfo::check_withdraw_stake`]
     /// - the stake_to_receive account is not a rent exempt uninitialized stake account
     #[inline]
     pub const fn quote_withdraw_stake_unchecked(
@@ -598,6 +719,39 @@ impl StakePool {
         ratio.reverse_est(lamports)
     }
 
+    /// Given output `pool_tokens`, return range of `lamports`
+    /// that may have been fed into [`Self::lamports_to_pool_tokens`]
+    #[inline]
+    pub const fn rev_lamports_to_pool_tokens(
+        &self,
+        pool_tokens: u64,
+    ) -> Option<RangeInclusive<u64>> {
+        let ratio = self.supply_over_lamports();
+        if ratio.0.is_zero() {
+            return Some(pool_tokens..=pool_tokens);
+        }
+        ratio.reverse_est(pool_tokens)
+    }
+
+    /// Smallest number of `lamports` that [`Self::lamports_to_pool_tokens`]
+    /// converts to at least `pool_tokens`.
+    ///
+    /// Unlike [`Self::rev_lamports_to_pool_tokens`], this is defined even if `pool_tokens`
+    /// cannot be minted exactly, which can happen when the exchange rate is < 1 lamport per pool token.
+    ///
+    /// Returns `None` on overflow
+    #[inline]
+    pub const fn min_lamports_for_pool_tokens(&self, pool_tokens: u64) -> Option<u64> {
+        if self.pool_token_supply == 0 || self.total_lamports == 0 {
+            return Some(pool_tokens);
+        }
+        Ceil(Ratio {
+            n: self.total_lamports,
+            d: self.pool_token_supply,
+        })
+        .apply(pool_tokens)
+    }
+
     /// Returns None if self.sol_referral_fee > 100
     #[inline]
     pub const fn sol_referral_fee(&self) -> Option<ReferralFee> {
@@ -614,3 +768,61 @@ impl StakePool {
 impl StakePool {
     inherent_borsh_serde!();
 }
+
+impl StakePool {
+    /// Size of a stake pool account's data.
+    ///
+    /// This is the program's max borsh-serialized length of a [`StakePool`],
+    /// with all `Option`s being `Some` and all [`FutureEpoch`]s being `One` or `Two`,
+    /// which the account is allocated with. Most pools serialize to fewer bytes.
+    pub const ACCOUNT_LEN: usize = 611;
+
+    /// Minimum lamports a stake pool account must hold to be rent-exempt.
+    ///
+    /// `rent` defaults to mainnet-beta's rent parameters if `None`
+    #[inline]
+    pub fn rent_exempt_lamports(rent: Option<&Rent>) -> u64 {
+        rent.unwrap_or(&Rent::DEFAULT)
+            .minimum_balance(Self::ACCOUNT_LEN)
+    }
+}
+
+impl StakePool {
+    /// Deserializes and validates a stake pool account owned by `owner`:
+    /// - `owner` is `expected_program`
+    /// - see [`Self::try_from_data`]
+    #[inline]
+    pub fn try_from_account(
+        expected_program: &[u8; 32],
+        owner: &[u8; 32],
+        data: &[u8],
+    ) -> Result<Self, InvalidAccountError> {
+        if owner != expected_program {
+            return Err(InvalidAccountError::WrongOwner);
+        }
+        Self::try_from_data(data)
+    }
+
+    /// Same as [`Self::try_from_account`], but accepts any of [`STAKE_POOL_PROGRAMS`] as `owner`
+    #[inline]
+    pub fn try_from_known_program_account(
+        owner: &[u8; 32],
+        data: &[u8],
+    ) -> Result<Self, InvalidAccountError> {
+        if !STAKE_POOL_PROGRAMS.contains(owner) {
+            return Err(InvalidAccountError::WrongOwner);
+        }
+        Self::try_from_data(data)
+    }
+
+    /// Deserializes and validates stake pool account data:
+    /// - `account_type` is [`AccountType::StakePool`]
+    #[inline]
+    pub fn try_from_data(data: &[u8]) -> Result<Self, InvalidAccountError> {
+        let res = Self::borsh_de(data).map_err(|e| InvalidAccountError::Deserialize(e.kind()))?;
+        if res.account_type != AccountType::StakePool {
+            return Err(InvalidAccountError::WrongAccountType(res.account_type));
+        }
+        Ok(res)
+    }
+}
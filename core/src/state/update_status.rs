@@ -0,0 +1,102 @@
+use core::num::NonZeroUsize;
+
+use crate::{update_validator_list_balance_ix_count, StakePool, ValidatorList};
+
+/// Summary of the work required to fully update a stake pool for an epoch,
+/// assuming the UpdateValidatorListBalance instructions are chunked
+/// according to [`crate::update_validator_list_balance_chunks_itr`] and each sent
+/// in their own transaction, followed by a final transaction containing
+/// UpdateStakePoolBalance and CleanupRemovedValidatorEntries
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[cfg_attr(
+    feature = "wasm",
+    derive(tsify_next::Tsify),
+    tsify(into_wasm_abi, from_wasm_abi, large_number_types_as_bigints)
+)]
+pub struct UpdateStatus {
+    /// `StakePool::last_update_epoch` is before the current epoch
+    pub stake_pool_stale: bool,
+
+    /// Number of validator list entries not yet updated for the current epoch
+    pub stale_validators: usize,
+
+    /// At least 1 validator list entry is `ReadyForRemoval`
+    pub cleanup_required: bool,
+
+    /// Number of UpdateValidatorListBalance instructions required
+    pub update_validator_list_balance_ixs: usize,
+
+    /// Total number of instructions required
+    pub ixs: usize,
+
+    /// Total number of transactions required
+    pub txs: usize,
+}
+
+impl UpdateStatus {
+    #[inline]
+    pub fn new(
+        stake_pool: &StakePool,
+        validator_list: &ValidatorList,
+        current_epoch: u64,
+        max_pairs_per_ix: NonZeroUsize,
+    ) -> Self {
+        let stake_pool_stale = !stake_pool.is_updated_for_epoch(current_epoch);
+        let stale_validators = validator_list
+            .stale_validator_indices_itr(current_epoch)
+            .count();
+        let cleanup_required = validator_list.has_ready_for_removal();
+
+        // UpdateStakePoolBalance requires every entry to be updated,
+        // so the whole list must be cranked if any single entry is stale
+        let update_validator_list_balance_ixs = if stale_validators > 0 {
+            update_validator_list_balance_ix_count(
+                validator_list.validators.len(),
+                max_pairs_per_ix,
+            )
+        } else {
+            0
+        };
+        let update_stake_pool_balance_ixs = usize::from(Self::update_stake_pool_balance_required(
+            stake_pool_stale,
+            stale_validators,
+        ));
+        let cleanup_ixs = usize::from(cleanup_required);
+        let final_txs = usize::from(update_stake_pool_balance_ixs + cleanup_ixs > 0);
+
+        Self {
+            stake_pool_stale,
+            stale_validators,
+            cleanup_required,
+            update_validator_list_balance_ixs,
+            ixs: update_validator_list_balance_ixs + update_stake_pool_balance_ixs + cleanup_ixs,
+            txs: update_validator_list_balance_ixs + final_txs,
+        }
+    }
+
+    /// UpdateStakePoolBalance must be sent if the pool or any validator list entry is stale
+    #[inline]
+    const fn update_stake_pool_balance_required(
+        stake_pool_stale: bool,
+        stale_validators: usize,
+    ) -> bool {
+        stake_pool_stale || stale_validators > 0
+    }
+
+    /// Whether an UpdateStakePoolBalance instruction needs to be sent
+    #[inline]
+    pub const fn is_update_stake_pool_balance_required(&self) -> bool {
+        Self::update_stake_pool_balance_required(self.stake_pool_stale, self.stale_validators)
+    }
+
+    /// Whether any instruction needs to be sent at all
+    #[inline]
+    pub const fn is_update_required(&self) -> bool {
+        self.ixs > 0
+    }
+}
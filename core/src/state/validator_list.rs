@@ -1,6 +1,7 @@
 use crate::{
-    transient_stake_seeds, validator_stake_seeds, AccountType, OptionalSeed, ValidatorListHeader,
-    ValidatorStakeInfo,
+    transient_stake_seeds, validator_stake_seeds, AccountType, InvalidAccountError, OptionalSeed,
+    Rent, StakeStatus, ValidatorListHeader, ValidatorStakeInfo, STAKE_POOL_PROGRAMS,
+    VALIDATOR_LIST_VALIDATORS_OFFSET,
 };
 use borsh::{BorshDeserialize, BorshSerialize};
 
@@ -22,10 +23,133 @@ struct ValidatorListHeaderExt {
     num_validators: u32,
 }
 
+impl ValidatorList<'_> {
+    /// Size of each entry in the list
+    pub const VALIDATOR_LEN: usize = core::mem::size_of::<ValidatorStakeInfo>();
+
+    /// Size of a validator list account's data that can hold up to `max_validators` validators.
+    ///
+    /// Returns `None` if the size overflows `usize`, which can happen on 32-bit targets
+    #[inline]
+    pub const fn account_len(max_validators: u32) -> Option<usize> {
+        match Self::VALIDATOR_LEN.checked_mul(max_validators as usize) {
+            None => None,
+            Some(validators_len) => VALIDATOR_LIST_VALIDATORS_OFFSET.checked_add(validators_len),
+        }
+    }
+
+    /// Inverse of [`Self::account_len`]: max number of validators
+    /// a validator list account with `account_len` bytes of data can hold
+    #[inline]
+    pub const fn max_validators_for_account_len(account_len: usize) -> u32 {
+        let n = account_len.saturating_sub(VALIDATOR_LIST_VALIDATORS_OFFSET) / Self::VALIDATOR_LEN;
+        if n > u32::MAX as usize {
+            u32::MAX
+        } else {
+            n as u32
+        }
+    }
+
+    /// Minimum lamports a validator list account that can hold up to `max_validators`
+    /// validators must hold to be rent-exempt.
+    ///
+    /// `rent` defaults to mainnet-beta's rent parameters if `None`.
+    /// Returns `None` if [`Self::account_len`] overflows
+    #[inline]
+    pub fn rent_exempt_lamports(max_validators: u32, rent: Option<&Rent>) -> Option<u64> {
+        Self::account_len(max_validators)
+            .map(|len| rent.unwrap_or(&Rent::DEFAULT).minimum_balance(len))
+    }
+}
+
 impl<'a> ValidatorList<'a> {
-    /// Deserializes the header and the validators
+    /// Deserializes the header and the validators.
+    ///
+    /// Only checks that every validator has a valid [`StakeStatus`] byte,
+    /// use [`Self::try_from_account`] to validate the rest of the account
     #[inline]
     pub fn deserialize(data: &'a [u8]) -> borsh::io::Result<Self> {
+        let res = Self::deserialize_unchecked(data)?;
+        if res.first_invalid_status().is_some() {
+            return Err(borsh::io::Error::new(
+                borsh::io::ErrorKind::InvalidData,
+                "Invalid validator stake status",
+            ));
+        }
+        Ok(res)
+    }
+
+    /// Deserializes and validates a validator list account owned by `owner`:
+    /// - `owner` is `expected_program`
+    /// - see [`Self::try_from_data`]
+    #[inline]
+    pub fn try_from_account(
+        expected_program: &[u8; 32],
+        owner: &[u8; 32],
+        data: &'a [u8],
+    ) -> Result<Self, InvalidAccountError> {
+        if owner != expected_program {
+            return Err(InvalidAccountError::WrongOwner);
+        }
+        Self::try_from_data(data)
+    }
+
+    /// Same as [`Self::try_from_account`], but accepts any of [`STAKE_POOL_PROGRAMS`] as `owner`
+    #[inline]
+    pub fn try_from_known_program_account(
+        owner: &[u8; 32],
+        data: &'a [u8],
+    ) -> Result<Self, InvalidAccountError> {
+        if !STAKE_POOL_PROGRAMS.contains(owner) {
+            return Err(InvalidAccountError::WrongOwner);
+        }
+        Self::try_from_data(data)
+    }
+
+    /// Deserializes and validates validator list account data:
+    /// - `account_type` is [`AccountType::ValidatorList`]
+    /// - `validators.len() <= max_validators`
+    /// - every validator has a valid [`StakeStatus`] byte
+    #[inline]
+    pub fn try_from_data(data: &'a [u8]) -> Result<Self, InvalidAccountError> {
+        let res = Self::deserialize_unchecked(data)
+            .map_err(|e| InvalidAccountError::Deserialize(e.kind()))?;
+        let ValidatorListHeader {
+            account_type,
+            max_validators,
+        } = res.header;
+        if account_type != AccountType::ValidatorList {
+            return Err(InvalidAccountError::WrongAccountType(account_type));
+        }
+        // as-safety: len was deserialized from a u32
+        let num_validators = res.validators.len() as u32;
+        if num_validators > max_validators {
+            return Err(InvalidAccountError::NumValidatorsExceedsMax {
+                num_validators,
+                max_validators,
+            });
+        }
+        if let Some((index, status)) = res.first_invalid_status() {
+            return Err(InvalidAccountError::WrongStakeStatus { index, status });
+        }
+        Ok(res)
+    }
+
+    /// Returns `(index, status_byte)` of the first validator
+    /// with an invalid [`StakeStatus`] byte
+    #[inline]
+    fn first_invalid_status(&self) -> Option<(u32, u8)> {
+        self.validators
+            .iter()
+            .enumerate()
+            .find(|(_, v)| v.try_status().is_none())
+            // as-safety: len was deserialized from a u32
+            .map(|(i, v)| (i as u32, v.status_byte()))
+    }
+
+    /// Deserializes the header and the validators without validating anything
+    #[inline]
+    fn deserialize_unchecked(data: &'a [u8]) -> borsh::io::Result<Self> {
         let mut remaining = data;
         let ValidatorListHeaderExt {
             account_type,
@@ -34,8 +158,7 @@ impl<'a> ValidatorList<'a> {
         } = ValidatorListHeaderExt::deserialize(&mut remaining)?;
         // CHECK: If data size matches expected size for num_validators
         let num_validators = num_validators as usize;
-        let validator_size = core::mem::size_of::<ValidatorStakeInfo>();
-        let expected_size = validator_size * num_validators;
+        let expected_size = Self::VALIDATOR_LEN * num_validators;
         if remaining.len() < expected_size {
             return Err(borsh::io::Error::new(
                 borsh::io::ErrorKind::InvalidData,
@@ -144,4 +267,26 @@ impl<'a> ValidatorList<'a> {
         self.validator_stake_account_seeds_itr(stake_pool)
             .zip(self.transient_stake_account_seeds_itr(stake_pool))
     }
+
+    /// Yields the indices of the validators that have not yet been updated
+    /// by UpdateValidatorListBalance for `current_epoch`
+    pub fn stale_validator_indices_itr(
+        &'a self,
+        current_epoch: u64,
+    ) -> impl Iterator<Item = usize> + 'a {
+        self.validators
+            .iter()
+            .enumerate()
+            .filter(move |(_, v)| !v.is_updated_for_epoch(current_epoch))
+            .map(|(i, _)| i)
+    }
+
+    /// Whether any entry is [`StakeStatus::ReadyForRemoval`]
+    /// and needs to be removed with CleanupRemovedValidatorEntries
+    #[inline]
+    pub fn has_ready_for_removal(&self) -> bool {
+        self.validators
+            .iter()
+            .any(|v| v.status() == StakeStatus::ReadyForRemoval)
+    }
 }
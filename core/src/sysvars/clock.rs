@@ -0,0 +1,69 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+
+use crate::EpochSchedule;
+
+/// Contents of the [`crate::SYSVAR_CLOCK`] account.
+///
+/// Deserialize the account's data with [`Clock::borsh_de`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, BorshDeserialize, BorshSerialize)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[cfg_attr(
+    feature = "wasm",
+    derive(tsify_next::Tsify),
+    tsify(into_wasm_abi, from_wasm_abi, large_number_types_as_bigints)
+)]
+pub struct Clock {
+    pub slot: u64,
+
+    /// Estimated unix timestamp of the first slot of `epoch`
+    pub epoch_start_timestamp: i64,
+
+    pub epoch: u64,
+
+    /// Future epoch for which the leader schedule has most recently been calculated
+    pub leader_schedule_epoch: u64,
+
+    /// Estimated current unix timestamp
+    pub unix_timestamp: i64,
+}
+
+impl Clock {
+    /// Size of the [`crate::SYSVAR_CLOCK`] account's data in bytes
+    pub const ACCOUNT_LEN: usize = 40;
+
+    /// Index of `self.slot` in the current epoch.
+    ///
+    /// Returns `None` if `epoch_schedule` is invalid, see [`EpochSchedule::epoch_and_slot_index`]
+    #[inline]
+    pub const fn slot_index(&self, epoch_schedule: &EpochSchedule) -> Option<u64> {
+        match epoch_schedule.epoch_and_slot_index(self.slot) {
+            Some((_, slot_index)) => Some(slot_index),
+            None => None,
+        }
+    }
+
+    /// Estimated number of seconds until the next epoch boundary,
+    /// given the average duration of a slot in milliseconds,
+    /// e.g. [`crate::DEFAULT_MS_PER_SLOT`]
+    ///
+    /// Returns `None` if `epoch_schedule` is invalid, see [`EpochSchedule::epoch_and_slot_index`]
+    #[inline]
+    pub const fn est_secs_until_next_epoch(
+        &self,
+        epoch_schedule: &EpochSchedule,
+        ms_per_slot: u64,
+    ) -> Option<u64> {
+        match epoch_schedule.slots_until_next_epoch(self.slot) {
+            Some(slots) => Some(slots.saturating_mul(ms_per_slot) / 1000),
+            None => None,
+        }
+    }
+}
+
+impl Clock {
+    inherent_borsh_serde!();
+}
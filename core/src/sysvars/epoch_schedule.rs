@@ -0,0 +1,131 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+
+/// Slots per epoch of the first epoch of a cluster with warmup enabled
+pub const MINIMUM_SLOTS_PER_EPOCH: u64 = 32;
+
+/// Target duration of a slot
+pub const DEFAULT_MS_PER_SLOT: u64 = 400;
+
+/// Contents of the [`crate::SYSVAR_EPOCH_SCHEDULE`] account.
+///
+/// Deserialize the account's data with [`EpochSchedule::borsh_de`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, BorshDeserialize, BorshSerialize)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[cfg_attr(
+    feature = "wasm",
+    derive(tsify_next::Tsify),
+    tsify(into_wasm_abi, from_wasm_abi, large_number_types_as_bigints)
+)]
+pub struct EpochSchedule {
+    /// Slots per epoch after warmup
+    pub slots_per_epoch: u64,
+
+    /// Number of slots before the start of an epoch to calculate its leader schedule
+    pub leader_schedule_slot_offset: u64,
+
+    /// Whether epochs start short and double in length until `slots_per_epoch` is reached
+    pub warmup: bool,
+
+    /// First epoch with `slots_per_epoch` slots
+    pub first_normal_epoch: u64,
+
+    /// First slot of `first_normal_epoch`
+    pub first_normal_slot: u64,
+}
+
+impl Default for EpochSchedule {
+    #[inline]
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+impl EpochSchedule {
+    /// Size of the [`crate::SYSVAR_EPOCH_SCHEDULE`] account's data in bytes
+    pub const ACCOUNT_LEN: usize = 33;
+
+    /// Epoch schedule of mainnet-beta
+    pub const DEFAULT: Self = Self {
+        slots_per_epoch: 432_000,
+        leader_schedule_slot_offset: 432_000,
+        warmup: false,
+        first_normal_epoch: 0,
+        first_normal_slot: 0,
+    };
+
+    /// Number of slots in `epoch`
+    #[inline]
+    pub const fn slots_in_epoch(&self, epoch: u64) -> u64 {
+        if epoch < self.first_normal_epoch {
+            1 << (epoch + MINIMUM_SLOTS_PER_EPOCH.trailing_zeros() as u64)
+        } else {
+            self.slots_per_epoch
+        }
+    }
+
+    /// Returns `(epoch, index of slot in epoch)` of `slot`.
+    ///
+    /// Returns `None` if `slot` is a normal slot and `slots_per_epoch` is 0
+    #[inline]
+    pub const fn epoch_and_slot_index(&self, slot: u64) -> Option<(u64, u64)> {
+        if slot < self.first_normal_slot {
+            let epoch = (slot + MINIMUM_SLOTS_PER_EPOCH + 1)
+                .next_power_of_two()
+                .trailing_zeros()
+                - MINIMUM_SLOTS_PER_EPOCH.trailing_zeros()
+                - 1;
+            let epoch_len = 1 << (epoch + MINIMUM_SLOTS_PER_EPOCH.trailing_zeros());
+            Some((epoch as u64, slot - (epoch_len - MINIMUM_SLOTS_PER_EPOCH)))
+        } else {
+            let normal_slot_index = slot - self.first_normal_slot;
+            let (epochs, slot_index) = match (
+                normal_slot_index.checked_div(self.slots_per_epoch),
+                normal_slot_index.checked_rem(self.slots_per_epoch),
+            ) {
+                (Some(e), Some(i)) => (e, i),
+                _ => return None,
+            };
+            Some((self.first_normal_epoch + epochs, slot_index))
+        }
+    }
+
+    /// Epoch that `slot` is in.
+    ///
+    /// Returns `None` under the same conditions as [`Self::epoch_and_slot_index`]
+    #[inline]
+    pub const fn epoch(&self, slot: u64) -> Option<u64> {
+        match self.epoch_and_slot_index(slot) {
+            Some((epoch, _)) => Some(epoch),
+            None => None,
+        }
+    }
+
+    /// First slot of `epoch`
+    #[inline]
+    pub const fn first_slot_in_epoch(&self, epoch: u64) -> u64 {
+        if epoch <= self.first_normal_epoch {
+            ((1 << epoch) - 1) * MINIMUM_SLOTS_PER_EPOCH
+        } else {
+            (epoch - self.first_normal_epoch) * self.slots_per_epoch + self.first_normal_slot
+        }
+    }
+
+    /// Number of slots from `slot` to the first slot of the next epoch.
+    ///
+    /// Returns `None` under the same conditions as [`Self::epoch_and_slot_index`]
+    #[inline]
+    pub const fn slots_until_next_epoch(&self, slot: u64) -> Option<u64> {
+        match self.epoch_and_slot_index(slot) {
+            Some((epoch, slot_index)) => Some(self.slots_in_epoch(epoch) - slot_index),
+            None => None,
+        }
+    }
+}
+
+impl EpochSchedule {
+    inherent_borsh_serde!();
+}
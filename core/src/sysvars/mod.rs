@@ -0,0 +1,7 @@
+mod clock;
+mod epoch_schedule;
+mod rent;
+
+pub use clock::*;
+pub use epoch_schedule::*;
+pub use rent::*;
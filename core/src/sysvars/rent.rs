@@ -0,0 +1,71 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+
+use crate::{STAKE_ACCOUNT_LEN, STAKE_ACCOUNT_RENT_EXEMPT_LAMPORTS};
+
+/// Contents of the [`crate::SYSVAR_RENT`] account.
+///
+/// Deserialize the account's data with [`Rent::borsh_de`].
+#[derive(Debug, Clone, Copy, PartialEq, BorshDeserialize, BorshSerialize)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[cfg_attr(
+    feature = "wasm",
+    derive(tsify_next::Tsify),
+    tsify(into_wasm_abi, from_wasm_abi, large_number_types_as_bigints)
+)]
+pub struct Rent {
+    pub lamports_per_byte_year: u64,
+
+    /// Number of years of rent an account must hold to be rent-exempt
+    pub exemption_threshold: f64,
+
+    /// Percentage of collected rent that is burned
+    pub burn_percent: u8,
+}
+
+impl Default for Rent {
+    #[inline]
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+impl Rent {
+    /// Size of the [`crate::SYSVAR_RENT`] account's data in bytes
+    pub const ACCOUNT_LEN: usize = 17;
+
+    /// Bytes of account metadata that are charged rent on top of account data
+    pub const ACCOUNT_STORAGE_OVERHEAD: u64 = 128;
+
+    /// Rent parameters of mainnet-beta
+    pub const DEFAULT: Self = Self {
+        lamports_per_byte_year: 3480,
+        exemption_threshold: 2.0,
+        burn_percent: 50,
+    };
+
+    /// Minimum lamports an account with `data_len` bytes of data
+    /// must hold to be rent-exempt
+    #[inline]
+    pub const fn minimum_balance(&self, data_len: usize) -> u64 {
+        let bytes = Self::ACCOUNT_STORAGE_OVERHEAD.saturating_add(data_len as u64);
+        (bytes.saturating_mul(self.lamports_per_byte_year) as f64 * self.exemption_threshold) as u64
+    }
+}
+
+impl Rent {
+    inherent_borsh_serde!();
+}
+
+/// Minimum lamports a stake account must hold to be rent-exempt,
+/// [`STAKE_ACCOUNT_RENT_EXEMPT_LAMPORTS`] if `rent` is `None`
+#[inline]
+pub const fn stake_account_rent_exempt_lamports(rent: Option<&Rent>) -> u64 {
+    match rent {
+        None => STAKE_ACCOUNT_RENT_EXEMPT_LAMPORTS,
+        Some(rent) => rent.minimum_balance(STAKE_ACCOUNT_LEN),
+    }
+}
@@ -0,0 +1,227 @@
+use sanctum_u64_ratio::Ratio;
+
+use crate::{
+    DepositSolQuote, DepositSolQuoteArgs, DepositStakeQuote, DepositStakeQuoteArgs, Lockup, Rent,
+    SplStakePoolError, StakeAccountLamports, StakePool, ValidatorStakeInfo, WithdrawSolQuote,
+    WithdrawSolQuoteArgs, WithdrawStakeQuote, WithdrawStakeQuoteArgs,
+    WithdrawStakeValidatorContext,
+};
+
+/// A request for any of the pool's four deposit/withdraw operations.
+///
+/// DepositStake deposits the entire stake account, so it is exact-in only.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TradeRequest<'a> {
+    /// Exact `lamports` in
+    DepositSolExactIn { lamports: u64 },
+
+    /// Exact `pool_tokens` out, or the smallest amount above it that can be minted,
+    /// see [`StakePool::quote_rev_deposit_sol_unchecked`]
+    DepositSolExactOut { pool_tokens: u64 },
+
+    /// Exact stake account in
+    DepositStake {
+        stake_account_lamports: StakeAccountLamports,
+
+        /// Validator the stake account is delegated to
+        validator: &'a ValidatorStakeInfo,
+
+        /// Set to `true` if the pool's stake deposit authority is not the default PDA,
+        /// in which case [`TradeContext::user`] must be the stake deposit authority
+        permissioned: bool,
+
+        /// Lockup of the stake account, which must match the pool's.
+        /// Set to None to skip the check
+        stake_lockup: Option<&'a Lockup>,
+    },
+
+    /// Exact `pool_tokens` in
+    WithdrawSolExactIn { pool_tokens: u64 },
+
+    /// Exact `lamports` out
+    WithdrawSolExactOut { lamports: u64 },
+
+    /// Exact `pool_tokens` in
+    WithdrawStakeExactIn {
+        pool_tokens: u64,
+
+        /// Validator whose stake account is withdrawn from, see [`WithdrawStakeValidatorContext`].
+        /// Set to None to skip its check, e.g. when withdrawing from the reserve
+        validator: Option<&'a ValidatorStakeInfo>,
+    },
+
+    /// Exact `lamports_staked` out
+    WithdrawStakeExactOut {
+        lamports_staked: u64,
+
+        /// Validator whose stake account is withdrawn from, see [`WithdrawStakeValidatorContext`].
+        /// Set to None to skip its check, e.g. when withdrawing from the reserve
+        validator: Option<&'a ValidatorStakeInfo>,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TradeContext<'a> {
+    /// Depositor or withdrawer
+    pub user: &'a [u8; 32],
+
+    pub current_epoch: u64,
+
+    /// Current lamports of the pool's reserve stake account,
+    /// only used for WithdrawSol
+    pub reserve_stake_lamports: u64,
+
+    /// Contents of the rent sysvar, only used for WithdrawSol and WithdrawStake from a validator.
+    /// Set to None to use mainnet-beta's rent parameters
+    pub rent: Option<&'a Rent>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "wasm",
+    derive(tsify_next::Tsify),
+    tsify(into_wasm_abi, from_wasm_abi, large_number_types_as_bigints)
+)]
+pub enum TradeQuote {
+    DepositSol(DepositSolQuote),
+    DepositStake(DepositStakeQuote),
+    WithdrawSol(WithdrawSolQuote),
+    WithdrawStake(WithdrawStakeQuote),
+}
+
+impl TradeQuote {
+    /// Lamports for deposits, pool tokens for withdrawals, before subtracting fees
+    #[inline]
+    pub fn in_amount(&self) -> u64 {
+        match self {
+            Self::DepositSol(q) => q.in_amount,
+            Self::DepositStake(q) => q.stake_account_lamports_in.total(),
+            Self::WithdrawSol(q) => q.in_amount,
+            Self::WithdrawStake(q) => q.tokens_in,
+        }
+    }
+
+    /// Pool tokens for deposits, lamports for withdrawals, after subtracting fees
+    #[inline]
+    pub fn out_amount(&self) -> u64 {
+        match self {
+            Self::DepositSol(q) => q.out_amount,
+            Self::DepositStake(q) => q.tokens_out,
+            Self::WithdrawSol(q) => q.out_amount,
+            Self::WithdrawStake(q) => q.lamports_staked,
+        }
+    }
+
+    /// Total fees levied, in terms of pool tokens
+    #[inline]
+    pub fn total_fee(&self) -> u64 {
+        match self {
+            Self::DepositSol(q) => q.total_fees(),
+            Self::DepositStake(q) => q.total_fees(),
+            Self::WithdrawSol(q) => q.manager_fee,
+            Self::WithdrawStake(q) => q.fee_amount,
+        }
+    }
+
+    /// `out_amount / in_amount`, after fees
+    #[inline]
+    pub fn effective_rate(&self) -> Ratio<u64, u64> {
+        Ratio {
+            n: self.out_amount(),
+            d: self.in_amount(),
+        }
+    }
+}
+
+impl StakePool {
+    /// Performs the checks needed to be serviceable along with calculation logic
+    /// for any of the pool's four deposit/withdraw operations
+    #[inline]
+    pub fn quote(
+        &self,
+        request: &TradeRequest,
+        TradeContext {
+            user,
+            current_epoch,
+            reserve_stake_lamports,
+            rent,
+        }: &TradeContext,
+    ) -> Result<TradeQuote, SplStakePoolError> {
+        let current_epoch = *current_epoch;
+        let deposit_sol_args = DepositSolQuoteArgs {
+            depositor: user,
+            current_epoch,
+        };
+        let withdraw_sol_args = WithdrawSolQuoteArgs {
+            withdrawer: user,
+            reserve_stake_lamports: *reserve_stake_lamports,
+            current_epoch,
+            rent: *rent,
+        };
+        let withdraw_stake_args = WithdrawStakeQuoteArgs { current_epoch };
+
+        match *request {
+            TradeRequest::DepositSolExactIn { lamports } => self
+                .quote_deposit_sol(lamports, &deposit_sol_args)
+                .map(TradeQuote::DepositSol),
+            TradeRequest::DepositSolExactOut { pool_tokens } => self
+                .quote_rev_deposit_sol(pool_tokens, &deposit_sol_args)
+                .map(TradeQuote::DepositSol),
+            TradeRequest::DepositStake {
+                stake_account_lamports,
+                validator,
+                permissioned,
+                stake_lockup,
+            } => {
+                let args = if permissioned {
+                    DepositStakeQuoteArgs::permissioned(validator, current_epoch, user)
+                } else {
+                    DepositStakeQuoteArgs::permissionless(validator, current_epoch)
+                };
+                let args = DepositStakeQuoteArgs {
+                    stake_lockup,
+                    ..args
+                };
+                self.quote_deposit_stake(stake_account_lamports, &args)
+                    .map(TradeQuote::DepositStake)
+            }
+            TradeRequest::WithdrawSolExactIn { pool_tokens } => self
+                .quote_withdraw_sol(pool_tokens, &withdraw_sol_args)
+                .map(TradeQuote::WithdrawSol),
+            TradeRequest::WithdrawSolExactOut { lamports } => self
+                .quote_rev_withdraw_sol(lamports, &withdraw_sol_args)
+                .map(TradeQuote::WithdrawSol),
+            TradeRequest::WithdrawStakeExactIn {
+                pool_tokens,
+                validator,
+            } => match validator {
+                Some(validator) => self.quote_withdraw_stake_from_validator(
+                    pool_tokens,
+                    withdraw_stake_args,
+                    WithdrawStakeValidatorContext {
+                        validator,
+                        rent: *rent,
+                    },
+                ),
+                None => self.quote_withdraw_stake(pool_tokens, withdraw_stake_args),
+            }
+            .map(TradeQuote::WithdrawStake),
+            TradeRequest::WithdrawStakeExactOut {
+                lamports_staked,
+                validator,
+            } => match validator {
+                Some(validator) => self.quote_rev_withdraw_stake_from_validator(
+                    lamports_staked,
+                    withdraw_stake_args,
+                    WithdrawStakeValidatorContext {
+                        validator,
+                        rent: *rent,
+                    },
+                ),
+                None => self.quote_rev_withdraw_stake(lamports_staked, withdraw_stake_args),
+            }
+            .map(TradeQuote::WithdrawStake),
+        }
+    }
+}
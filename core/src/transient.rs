@@ -0,0 +1,199 @@
+//! Transient stake account state tracking for
+//! IncreaseAdditionalValidatorStake and DecreaseAdditionalValidatorStake.
+//!
+//! Only one transient stake account may exist per validator at a time.
+//! An existing transient stake account can only be topped up in the same epoch
+//! it started activating (increase) or deactivating (decrease);
+//! otherwise it must first be merged into the validator stake account
+//! or reserve by UpdateValidatorListBalance.
+//!
+//! The checks below assume mainnet-beta's stake account rent-exempt minimum,
+//! [`STAKE_ACCOUNT_RENT_EXEMPT_LAMPORTS`].
+
+use crate::{
+    SplStakePoolError, StakeStatus, ValidatorStakeInfo, MIN_DECREASE_LAMPORTS,
+    MIN_INCREASE_LAMPORTS, MIN_VALIDATOR_STAKE_ACCOUNT_LAMPORTS,
+    STAKE_ACCOUNT_RENT_EXEMPT_LAMPORTS,
+};
+
+/// Discriminant of `StakeStateV2::Stake`
+const STAKE_STATE_STAKE: u32 = 2;
+
+/// Offset of `Delegation::activation_epoch` in a stake account's data
+const ACTIVATION_EPOCH_OFFSET: usize = 164;
+
+/// Offset of `Delegation::deactivation_epoch` in a stake account's data
+const DEACTIVATION_EPOCH_OFFSET: usize = 172;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "wasm",
+    derive(tsify_next::Tsify),
+    tsify(into_wasm_abi, from_wasm_abi)
+)]
+pub enum TransientStakeState {
+    /// Started activating this epoch,
+    /// can be topped up with IncreaseAdditionalValidatorStake
+    Activating,
+
+    /// Started deactivating this epoch,
+    /// can be topped up with DecreaseAdditionalValidatorStake
+    Deactivating,
+
+    /// Started activating or deactivating in a previous epoch,
+    /// must be merged by UpdateValidatorListBalance before another increase/decrease
+    AwaitingMerge,
+}
+
+impl TransientStakeState {
+    #[inline]
+    pub const fn from_delegation_epochs(
+        activation_epoch: u64,
+        deactivation_epoch: u64,
+        current_epoch: u64,
+    ) -> Self {
+        if activation_epoch == current_epoch && deactivation_epoch == u64::MAX {
+            Self::Activating
+        } else if deactivation_epoch == current_epoch {
+            Self::Deactivating
+        } else {
+            Self::AwaitingMerge
+        }
+    }
+
+    /// Returns `None` if `data` is not that of a delegated stake account
+    #[inline]
+    pub fn from_stake_account_data(data: &[u8], current_epoch: u64) -> Option<Self> {
+        let tag = u32::from_le_bytes(*data.first_chunk()?);
+        if tag != STAKE_STATE_STAKE {
+            return None;
+        }
+        let read_u64 = |offset: usize| {
+            data.get(offset..)
+                .and_then(|d| d.first_chunk())
+                .map(|b| u64::from_le_bytes(*b))
+        };
+        Some(Self::from_delegation_epochs(
+            read_u64(ACTIVATION_EPOCH_OFFSET)?,
+            read_u64(DEACTIVATION_EPOCH_OFFSET)?,
+            current_epoch,
+        ))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[cfg_attr(
+    feature = "wasm",
+    derive(tsify_next::Tsify),
+    tsify(into_wasm_abi, from_wasm_abi, large_number_types_as_bigints)
+)]
+pub struct AdditionalValidatorStakeCheckArgs {
+    /// Lamports to increase/decrease the validator's stake by
+    pub lamports: u64,
+
+    /// Current lamports of the pool's reserve stake account
+    pub reserve_stake_lamports: u64,
+
+    pub current_epoch: u64,
+
+    /// State of the validator's existing transient stake account, if any.
+    ///
+    /// Ignored if the validator has no transient stake.
+    pub transient_stake_state: Option<TransientStakeState>,
+}
+
+impl ValidatorStakeInfo {
+    /// Checks whether an IncreaseAdditionalValidatorStake instruction for this validator
+    /// would succeed this epoch.
+    ///
+    /// Returns the transient stake seed the instruction should use.
+    /// Returns [`SplStakePoolError::StakeLamportsNotEqualToMinimum`] if `args.lamports`
+    /// is below [`MIN_INCREASE_LAMPORTS`].
+    #[inline]
+    pub fn check_increase_additional_stake(
+        &self,
+        args: &AdditionalValidatorStakeCheckArgs,
+    ) -> Result<u64, SplStakePoolError> {
+        self.check_additional_stake_transient(args, TransientStakeState::Activating)?;
+        if args.lamports < MIN_INCREASE_LAMPORTS {
+            return Err(SplStakePoolError::StakeLamportsNotEqualToMinimum);
+        }
+        // reserve funds lamports + transient stake account rent and must remain rent-exempt
+        let required = args
+            .lamports
+            .checked_add(2 * STAKE_ACCOUNT_RENT_EXEMPT_LAMPORTS)
+            .ok_or(SplStakePoolError::CalculationFailure)?;
+        if args.reserve_stake_lamports < required {
+            return Err(SplStakePoolError::ReserveDepleted);
+        }
+        self.next_transient_seed()
+            .ok_or(SplStakePoolError::CalculationFailure)
+    }
+
+    /// Checks whether a DecreaseAdditionalValidatorStake instruction for this validator
+    /// would succeed this epoch.
+    ///
+    /// Returns the transient stake seed the instruction should use.
+    /// Returns [`SplStakePoolError::StakeLamportsNotEqualToMinimum`] if `args.lamports`
+    /// is below [`MIN_DECREASE_LAMPORTS`] and [`SplStakePoolError::InvalidState`] if the decrease
+    /// would bring the validator stake account below [`MIN_VALIDATOR_STAKE_ACCOUNT_LAMPORTS`].
+    #[inline]
+    pub fn check_decrease_additional_stake(
+        &self,
+        args: &AdditionalValidatorStakeCheckArgs,
+    ) -> Result<u64, SplStakePoolError> {
+        self.check_additional_stake_transient(args, TransientStakeState::Deactivating)?;
+        if args.lamports < MIN_DECREASE_LAMPORTS {
+            return Err(SplStakePoolError::StakeLamportsNotEqualToMinimum);
+        }
+        let remaining = self
+            .active_stake_lamports()
+            .checked_sub(args.lamports)
+            .ok_or(SplStakePoolError::InvalidState)?;
+        if remaining < MIN_VALIDATOR_STAKE_ACCOUNT_LAMPORTS {
+            return Err(SplStakePoolError::InvalidState);
+        }
+        // reserve funds transient stake account rent and must remain rent-exempt
+        if args.reserve_stake_lamports < 2 * STAKE_ACCOUNT_RENT_EXEMPT_LAMPORTS {
+            return Err(SplStakePoolError::ReserveDepleted);
+        }
+        self.next_transient_seed()
+            .ok_or(SplStakePoolError::CalculationFailure)
+    }
+
+    #[inline]
+    fn check_additional_stake_transient(
+        &self,
+        AdditionalValidatorStakeCheckArgs {
+            current_epoch,
+            transient_stake_state,
+            ..
+        }: &AdditionalValidatorStakeCheckArgs,
+        top_up_state: TransientStakeState,
+    ) -> Result<(), SplStakePoolError> {
+        if self.status() != StakeStatus::Active {
+            return Err(SplStakePoolError::ValidatorNotFound);
+        }
+        if !self.is_updated_for_epoch(*current_epoch) {
+            return Err(SplStakePoolError::StakeListAndPoolOutOfDate);
+        }
+        if self.transient_stake_lamports() == 0 {
+            return Ok(());
+        }
+        match transient_stake_state {
+            Some(s) if *s == top_up_state => Ok(()),
+            Some(TransientStakeState::Activating | TransientStakeState::Deactivating) => {
+                Err(SplStakePoolError::WrongStakeStake)
+            }
+            Some(TransientStakeState::AwaitingMerge) | None => {
+                Err(SplStakePoolError::TransientAccountInUse)
+            }
+        }
+    }
+}
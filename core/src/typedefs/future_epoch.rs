@@ -19,6 +19,31 @@ impl<T> Default for FutureEpoch<T> {
     }
 }
 
+impl<T: Copy> FutureEpoch<T> {
+    /// Returns the value that will be in effect after `updates` UpdateStakePoolBalance
+    /// instructions for new epochs, given the value currently in effect.
+    #[inline]
+    pub const fn resolve(&self, current: T, updates: u64) -> T {
+        match self {
+            Self::One(t) if updates >= 1 => *t,
+            Self::Two(t) if updates >= 2 => *t,
+            _ => current,
+        }
+    }
+
+    /// Returns the state of this `FutureEpoch` after `updates` UpdateStakePoolBalance
+    /// instructions for new epochs.
+    #[inline]
+    pub const fn advance(&self, updates: u64) -> Self {
+        match (self, updates) {
+            (Self::Two(t), 1) => Self::One(*t),
+            (Self::Two(t), 0) => Self::Two(*t),
+            (Self::One(t), 0) => Self::One(*t),
+            _ => Self::None,
+        }
+    }
+}
+
 impl<T: BorshSerialize> FutureEpoch<T> {
     inherent_borsh_ser!();
 }
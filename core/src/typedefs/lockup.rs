@@ -1,5 +1,13 @@
 use borsh::{BorshDeserialize, BorshSerialize};
 
+use crate::Clock;
+
+/// Offset of `Meta::lockup` in a stake account's data
+const STAKE_ACCOUNT_LOCKUP_OFFSET: usize = 76;
+
+/// Discriminants of `StakeStateV2::Initialized` and `StakeStateV2::Stake`
+const STAKE_STATES_WITH_META: [u32; 2] = [1, 2];
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, BorshDeserialize, BorshSerialize)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Lockup {
@@ -23,6 +31,27 @@ impl Lockup {
         epoch: 0,
         custodian: [0u8; 32],
     };
+
+    /// Whether this lockup prevents withdrawals at `clock`.
+    ///
+    /// A lockup is never in force for its custodian, pass `None` if the custodian is not signing.
+    #[inline]
+    pub fn is_in_force(&self, clock: &Clock, custodian: Option<&[u8; 32]>) -> bool {
+        if custodian.is_some_and(|c| *c == self.custodian) {
+            return false;
+        }
+        self.unix_timestamp > clock.unix_timestamp || self.epoch > clock.epoch
+    }
+
+    /// Returns `None` if `data` is not that of an initialized or delegated stake account
+    #[inline]
+    pub fn from_stake_account_data(data: &[u8]) -> Option<Self> {
+        let tag = u32::from_le_bytes(*data.first_chunk()?);
+        if !STAKE_STATES_WITH_META.contains(&tag) {
+            return None;
+        }
+        Self::borsh_de(data.get(STAKE_ACCOUNT_LOCKUP_OFFSET..)?).ok()
+    }
 }
 
 impl Lockup {
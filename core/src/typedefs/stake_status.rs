@@ -1,5 +1,7 @@
 use borsh::{BorshDeserialize, BorshSerialize};
 
+use crate::InvalidStakeStatusTransition;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, BorshDeserialize, BorshSerialize)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(
@@ -45,3 +47,155 @@ impl Default for StakeStatus {
         Self::Active
     }
 }
+
+/// Which of a validator's stake accounts exist
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[cfg_attr(
+    feature = "wasm",
+    derive(tsify_next::Tsify),
+    tsify(into_wasm_abi, from_wasm_abi)
+)]
+pub struct ValidatorStakeAccounts {
+    pub validator: bool,
+    pub transient: bool,
+}
+
+/// Program instructions that change a validator list entry's [`StakeStatus`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "wasm",
+    derive(tsify_next::Tsify),
+    tsify(into_wasm_abi, from_wasm_abi)
+)]
+pub enum StakeStatusEvent {
+    /// RemoveValidatorFromPool, which deactivates all of the validator's stake accounts
+    RemoveValidator,
+
+    /// UpdateValidatorListBalance, which merges fully deactivated stake accounts
+    /// of removed validators into the reserve
+    Update,
+
+    /// CleanupRemovedValidatorEntries, which deletes entries that are ready for removal
+    Cleanup,
+}
+
+impl StakeStatus {
+    /// Returns true if `accounts` are the stake accounts that can exist
+    /// for a validator list entry with this status
+    #[inline]
+    pub const fn is_consistent_with(&self, accounts: ValidatorStakeAccounts) -> bool {
+        let ValidatorStakeAccounts {
+            validator,
+            transient,
+        } = accounts;
+        match self {
+            Self::Active => validator,
+            Self::DeactivatingValidator => validator && !transient,
+            Self::DeactivatingTransient => !validator && transient,
+            Self::DeactivatingAll => validator && transient,
+            Self::ReadyForRemoval => !validator && !transient,
+        }
+    }
+
+    /// Status of the validator list entry after `event`, or `None` if the entry was deleted.
+    ///
+    /// `accounts` are the validator's stake accounts that exist
+    /// - for [`StakeStatusEvent::RemoveValidator`] and [`StakeStatusEvent::Cleanup`],
+    ///   at the time of the instruction
+    /// - for [`StakeStatusEvent::Update`], after the instruction has merged
+    ///   fully deactivated stake accounts into the reserve
+    ///
+    /// Transitions:
+    /// - `Active` -> `DeactivatingValidator` by RemoveValidatorFromPool
+    ///   if no transient stake account exists, else `DeactivatingAll`
+    /// - `DeactivatingAll` -> `DeactivatingValidator` / `DeactivatingTransient` by update
+    ///   once only the transient / validator stake account has been merged,
+    ///   or directly to `ReadyForRemoval` once both have
+    /// - `DeactivatingValidator` / `DeactivatingTransient` -> `ReadyForRemoval` by update
+    ///   once the remaining stake account has been merged
+    /// - `ReadyForRemoval` -> deleted by CleanupRemovedValidatorEntries
+    ///
+    /// Update and cleanup leave all other statuses unchanged.
+    ///
+    /// # Errors
+    /// - `self` is not consistent with `accounts` before removal or cleanup,
+    ///   see [`Self::is_consistent_with`]
+    /// - removing a validator that is not `Active`
+    /// - update resulting in stake accounts that could not exist for any status reachable from `self`
+    #[inline]
+    pub const fn transition(
+        &self,
+        event: StakeStatusEvent,
+        accounts: ValidatorStakeAccounts,
+    ) -> Result<Option<Self>, InvalidStakeStatusTransition> {
+        let ValidatorStakeAccounts {
+            validator,
+            transient,
+        } = accounts;
+        let res = match (event, self) {
+            (StakeStatusEvent::RemoveValidator, Self::Active) => match (validator, transient) {
+                (true, false) => Some(Some(Self::DeactivatingValidator)),
+                (true, true) => Some(Some(Self::DeactivatingAll)),
+                _ => None,
+            },
+            (StakeStatusEvent::RemoveValidator, _) => None,
+            (StakeStatusEvent::Update, Self::Active) => {
+                if validator {
+                    Some(Some(Self::Active))
+                } else {
+                    None
+                }
+            }
+            (StakeStatusEvent::Update, Self::DeactivatingAll) => match (validator, transient) {
+                (true, true) => Some(Some(Self::DeactivatingAll)),
+                (true, false) => Some(Some(Self::DeactivatingValidator)),
+                (false, true) => Some(Some(Self::DeactivatingTransient)),
+                (false, false) => Some(Some(Self::ReadyForRemoval)),
+            },
+            (StakeStatusEvent::Update, Self::DeactivatingValidator) => match (validator, transient)
+            {
+                (true, false) => Some(Some(Self::DeactivatingValidator)),
+                (false, false) => Some(Some(Self::ReadyForRemoval)),
+                _ => None,
+            },
+            (StakeStatusEvent::Update, Self::DeactivatingTransient) => match (validator, transient)
+            {
+                (false, true) => Some(Some(Self::DeactivatingTransient)),
+                (false, false) => Some(Some(Self::ReadyForRemoval)),
+                _ => None,
+            },
+            (StakeStatusEvent::Update, Self::ReadyForRemoval)
+            | (StakeStatusEvent::Cleanup, Self::ReadyForRemoval) => {
+                if !validator && !transient {
+                    Some(match event {
+                        StakeStatusEvent::Cleanup => None,
+                        _ => Some(Self::ReadyForRemoval),
+                    })
+                } else {
+                    None
+                }
+            }
+            (StakeStatusEvent::Cleanup, _) => {
+                if self.is_consistent_with(accounts) {
+                    Some(Some(*self))
+                } else {
+                    None
+                }
+            }
+        };
+        match res {
+            Some(next) => Ok(next),
+            None => Err(InvalidStakeStatusTransition {
+                from: *self,
+                event,
+                accounts,
+            }),
+        }
+    }
+}
@@ -3,6 +3,8 @@ use core::num::NonZeroU32;
 use super::StakeStatus;
 use borsh::{BorshDeserialize, BorshSerialize};
 
+use crate::{stake_account_rent_exempt_lamports, Rent, SplStakePoolError, MIN_ACTIVE_STAKE};
+
 // Non pub fields, values should be accessed by getters and setters.
 #[derive(Copy, Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -70,37 +72,49 @@ impl ValidatorStakeInfo {
     #[inline]
     pub fn status(&self) -> StakeStatus {
         // unwrap-safety: private fields means we enforce the invariant that
-        // StakeStatus is always valid at construction time
-        StakeStatus::borsh_de(core::slice::from_ref(&self.status)).unwrap()
+        // StakeStatus is always valid at construction time,
+        // ValidatorList::deserialize() validates all status bytes
+        self.try_status().unwrap()
+    }
+
+    /// Returns `None` if the status byte is not a valid [`StakeStatus`]
+    #[inline]
+    pub fn try_status(&self) -> Option<StakeStatus> {
+        StakeStatus::borsh_de(core::slice::from_ref(&self.status)).ok()
     }
 
     #[inline]
-    pub fn active_stake_lamports(&self) -> u64 {
+    pub const fn status_byte(&self) -> u8 {
+        self.status
+    }
+
+    #[inline]
+    pub const fn active_stake_lamports(&self) -> u64 {
         u64::from_le_bytes(self.active_stake_lamports)
     }
 
     #[inline]
-    pub fn transient_stake_lamports(&self) -> u64 {
+    pub const fn transient_stake_lamports(&self) -> u64 {
         u64::from_le_bytes(self.transient_stake_lamports)
     }
 
     #[inline]
-    pub fn last_update_epoch(&self) -> u64 {
+    pub const fn last_update_epoch(&self) -> u64 {
         u64::from_le_bytes(self.last_update_epoch)
     }
 
     #[inline]
-    pub fn transient_seed_suffix(&self) -> u64 {
+    pub const fn transient_seed_suffix(&self) -> u64 {
         u64::from_le_bytes(self.transient_seed_suffix)
     }
 
     #[inline]
-    pub fn validator_seed_suffix(&self) -> Option<NonZeroU32> {
+    pub const fn validator_seed_suffix(&self) -> Option<NonZeroU32> {
         NonZeroU32::new(u32::from_le_bytes(self.validator_seed_suffix))
     }
 
     #[inline]
-    pub fn vote_account_address(&self) -> &[u8; 32] {
+    pub const fn vote_account_address(&self) -> &[u8; 32] {
         &self.vote_account_address
     }
 }
@@ -142,3 +156,50 @@ impl ValidatorStakeInfo {
         self.vote_account_address = value;
     }
 }
+
+impl ValidatorStakeInfo {
+    /// Whether this entry has been updated by UpdateValidatorListBalance
+    /// for `current_epoch`
+    #[inline]
+    pub fn is_updated_for_epoch(&self, current_epoch: u64) -> bool {
+        self.last_update_epoch() >= current_epoch
+    }
+
+    /// Transient stake seed that an IncreaseAdditionalValidatorStake or
+    /// DecreaseAdditionalValidatorStake instruction for this validator should use.
+    ///
+    /// - If there is no transient stake, bump the seed up by one
+    ///   to avoid reusing a previously closed transient stake account
+    /// - Else, reuse the current seed to top up the existing transient stake account
+    ///
+    /// Returns `None` on overflow
+    #[inline]
+    pub fn next_transient_seed(&self) -> Option<u64> {
+        if self.transient_stake_lamports() == 0 {
+            self.transient_seed_suffix().checked_add(1)
+        } else {
+            Some(self.transient_seed_suffix())
+        }
+    }
+
+    /// Checks whether a WithdrawStake instruction splitting `lamports_staked`
+    /// off this validator's stake account would leave the account with the minimum
+    /// lamports required by the program, which is its rent-exempt reserve + [`MIN_ACTIVE_STAKE`].
+    ///
+    /// `rent` defaults to mainnet-beta's rent parameters if `None`
+    #[inline]
+    pub const fn check_withdraw_stake(
+        &self,
+        lamports_staked: u64,
+        rent: Option<&Rent>,
+    ) -> Result<(), SplStakePoolError> {
+        let min = match stake_account_rent_exempt_lamports(rent).checked_add(MIN_ACTIVE_STAKE) {
+            None => return Err(SplStakePoolError::CalculationFailure),
+            Some(x) => x,
+        };
+        match self.active_stake_lamports().checked_sub(lamports_staked) {
+            Some(remaining) if remaining >= min => Ok(()),
+            _ => Err(SplStakePoolError::StakeLamportsNotEqualToMinimum),
+        }
+    }
+}
@@ -1,6 +1,8 @@
 use borsh::{BorshDeserialize, BorshSerialize};
 
-use crate::{StakeStatus, ValidatorStakeInfo, STAKE_ACCOUNT_RENT_EXEMPT_LAMPORTS};
+use crate::{
+    stake_account_rent_exempt_lamports, Clock, Lockup, Rent, StakeStatus, ValidatorStakeInfo,
+};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct DepositSolQuoteArgs<'a> {
@@ -8,6 +10,16 @@ pub struct DepositSolQuoteArgs<'a> {
     pub current_epoch: u64,
 }
 
+impl<'a> DepositSolQuoteArgs<'a> {
+    #[inline]
+    pub const fn from_clock(depositor: &'a [u8; 32], clock: &Clock) -> Self {
+        Self {
+            depositor,
+            current_epoch: clock.epoch,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, BorshSerialize, BorshDeserialize, PartialEq, Eq)]
 #[cfg_attr(
     feature = "serde",
@@ -43,6 +55,10 @@ pub struct DepositStakeQuoteArgs<'a> {
     /// Set to None if pool is permissionless
     /// (stake_deposit_auth == default PDA)
     pub depositor: Option<&'a [u8; 32]>,
+
+    /// Lockup of the stake account to deposit, which must match the pool's.
+    /// Set to None to skip the check
+    pub stake_lockup: Option<&'a Lockup>,
 }
 
 impl<'a> DepositStakeQuoteArgs<'a> {
@@ -53,6 +69,7 @@ impl<'a> DepositStakeQuoteArgs<'a> {
             validator_vote: vsi.vote_account_address(),
             current_epoch,
             depositor: None,
+            stake_lockup: None,
         }
     }
 
@@ -67,6 +84,29 @@ impl<'a> DepositStakeQuoteArgs<'a> {
             validator_vote: vsi.vote_account_address(),
             current_epoch,
             depositor: Some(depositor),
+            stake_lockup: None,
+        }
+    }
+
+    #[inline]
+    pub fn permissionless_from_clock(vsi: &'a ValidatorStakeInfo, clock: &Clock) -> Self {
+        Self::permissionless(vsi, clock.epoch)
+    }
+
+    #[inline]
+    pub fn permissioned_from_clock(
+        vsi: &'a ValidatorStakeInfo,
+        clock: &Clock,
+        depositor: &'a [u8; 32],
+    ) -> Self {
+        Self::permissioned(vsi, clock.epoch, depositor)
+    }
+
+    #[inline]
+    pub const fn with_stake_lockup(self, stake_lockup: &'a Lockup) -> Self {
+        Self {
+            stake_lockup: Some(stake_lockup),
+            ..self
         }
     }
 }
@@ -107,6 +147,28 @@ pub struct WithdrawSolQuoteArgs<'a> {
     pub withdrawer: &'a [u8; 32],
     pub reserve_stake_lamports: u64,
     pub current_epoch: u64,
+
+    /// Contents of the rent sysvar.
+    /// Set to None to use mainnet-beta's rent parameters
+    pub rent: Option<&'a Rent>,
+}
+
+impl<'a> WithdrawSolQuoteArgs<'a> {
+    /// Uses mainnet-beta's rent parameters,
+    /// set [`Self::rent`] to override
+    #[inline]
+    pub const fn from_clock(
+        withdrawer: &'a [u8; 32],
+        reserve_stake_lamports: u64,
+        clock: &Clock,
+    ) -> Self {
+        Self {
+            withdrawer,
+            reserve_stake_lamports,
+            current_epoch: clock.epoch,
+            rent: None,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy, BorshSerialize, BorshDeserialize, PartialEq, Eq)]
@@ -146,6 +208,28 @@ pub struct WithdrawStakeQuoteArgs {
     pub current_epoch: u64,
 }
 
+impl WithdrawStakeQuoteArgs {
+    #[inline]
+    pub const fn from_clock(clock: &Clock) -> Self {
+        Self {
+            current_epoch: clock.epoch,
+        }
+    }
+}
+
+/// Validator stake account a stake withdrawal is made from, checked to be left
+/// with the minimum lamports required by the program.
+///
+/// Not needed when withdrawing from the reserve.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WithdrawStakeValidatorContext<'a> {
+    pub validator: &'a ValidatorStakeInfo,
+
+    /// Contents of the rent sysvar.
+    /// Set to None to use mainnet-beta's rent parameters
+    pub rent: Option<&'a Rent>,
+}
+
 #[derive(Debug, Clone, Copy, BorshSerialize, BorshDeserialize, PartialEq, Eq)]
 #[cfg_attr(
     feature = "serde",
@@ -187,14 +271,30 @@ impl StakeAccountLamports {
     }
 }
 
+/// Whether the reserve stake account remains rent-exempt after `lamports_out` are withdrawn from it,
+/// under mainnet-beta's rent parameters.
+///
+/// Use [`reserve_has_sufficient_lamports_with_rent`] for clusters with different rent parameters.
 #[inline]
 pub const fn reserve_has_sufficient_lamports(
     reserve_stake_lamports: u64,
     lamports_out: u64,
+) -> bool {
+    reserve_has_sufficient_lamports_with_rent(reserve_stake_lamports, lamports_out, None)
+}
+
+/// [`reserve_has_sufficient_lamports`] with the given rent parameters.
+///
+/// `rent` defaults to mainnet-beta's rent parameters if `None`
+#[inline]
+pub const fn reserve_has_sufficient_lamports_with_rent(
+    reserve_stake_lamports: u64,
+    lamports_out: u64,
+    rent: Option<&Rent>,
 ) -> bool {
     let rem = match reserve_stake_lamports.checked_sub(lamports_out) {
         None => return false,
         Some(x) => x,
     };
-    rem > STAKE_ACCOUNT_RENT_EXEMPT_LAMPORTS
+    rem > stake_account_rent_exempt_lamports(rent)
 }
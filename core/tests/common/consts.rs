@@ -1,2 +1,16 @@
+use sanctum_spl_stake_pool_core::Fee;
+
 pub const STAKE_POOL_DATA: &[u8] = include_bytes!("../../../test-fixtures/jupsol-stake-pool.json");
 pub const VALIDATOR_LIST_DATA: &[u8] = include_bytes!("../../../test-fixtures/validator-list.json");
+pub const PICOSOL_STAKE_POOL_DATA: &[u8] =
+    include_bytes!("../../../test-fixtures/picosol-stake-pool.json");
+/// Left uninitialized on purpose, the ts initialize test initializes it
+pub const BPSOL_STAKE_POOL_DATA: &[u8] =
+    include_bytes!("../../../test-fixtures/bpsol-stake-pool.json");
+pub const BPSOL_MINT_DATA: &[u8] = include_bytes!("../../../test-fixtures/bpsol-mint.json");
+
+/// A fee different from all of the jupSOL fixture's fees
+pub const NEW_FEE: Fee = Fee {
+    denominator: 100,
+    numerator: 10,
+};
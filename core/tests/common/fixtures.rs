@@ -0,0 +1,29 @@
+use data_encoding::BASE64;
+use sanctum_spl_stake_pool_core::StakePool;
+
+use super::consts::{STAKE_POOL_DATA, VALIDATOR_LIST_DATA};
+
+/// Decodes the account data of a `solana account --output json` fixture
+pub fn fixture_account_data(account_json: &[u8]) -> Vec<u8> {
+    let account_json: serde_json::Value = serde_json::from_slice(account_json).unwrap();
+    BASE64
+        .decode(
+            account_json["account"]["data"][0]
+                .as_str()
+                .unwrap()
+                .as_bytes(),
+        )
+        .unwrap()
+}
+
+/// The jupSOL stake pool fixture
+pub fn jupsol_stake_pool() -> StakePool {
+    StakePool::try_from_data(&fixture_account_data(STAKE_POOL_DATA)).unwrap()
+}
+
+/// Account data of the jupSOL validator list fixture.
+///
+/// Returns the data instead of a `ValidatorList` since `ValidatorList` borrows from it.
+pub fn jupsol_validator_list_data() -> Vec<u8> {
+    fixture_account_data(VALIDATOR_LIST_DATA)
+}
@@ -1,3 +1,4 @@
 pub mod consts;
+pub mod fixtures;
 pub mod proptest_utils;
 pub mod quote;
@@ -0,0 +1,113 @@
+use sanctum_spl_stake_pool_core::{
+    AccountType, InvalidAccountError, StakePool, ValidatorList,
+    SANCTUM_SPL_MULTI_STAKE_POOL_PROGRAM, SANCTUM_SPL_STAKE_POOL_PROGRAM,
+};
+
+use crate::common::{
+    consts::STAKE_POOL_DATA,
+    fixtures::{fixture_account_data, jupsol_validator_list_data},
+};
+
+/// Offset of the first validator's status byte:
+/// validator list header (9) + offset of `status` in `ValidatorStakeInfo` (40)
+const FIRST_VALIDATOR_STATUS_OFFSET: usize = 49;
+
+#[test]
+fn test_stake_pool_try_from_account() {
+    let mut data = fixture_account_data(STAKE_POOL_DATA);
+    assert!(StakePool::try_from_account(
+        &SANCTUM_SPL_MULTI_STAKE_POOL_PROGRAM,
+        &SANCTUM_SPL_MULTI_STAKE_POOL_PROGRAM,
+        &data
+    )
+    .is_ok());
+    assert_eq!(
+        StakePool::try_from_account(
+            &SANCTUM_SPL_STAKE_POOL_PROGRAM,
+            &SANCTUM_SPL_MULTI_STAKE_POOL_PROGRAM,
+            &data
+        ),
+        Err(InvalidAccountError::WrongOwner)
+    );
+    assert!(StakePool::try_from_known_program_account(
+        &SANCTUM_SPL_MULTI_STAKE_POOL_PROGRAM,
+        &data
+    )
+    .is_ok());
+    assert_eq!(
+        StakePool::try_from_known_program_account(&[1u8; 32], &data),
+        Err(InvalidAccountError::WrongOwner)
+    );
+
+    data[0] = 2;
+    assert_eq!(
+        StakePool::try_from_data(&data),
+        Err(InvalidAccountError::WrongAccountType(
+            AccountType::ValidatorList
+        ))
+    );
+
+    data[0] = 3;
+    assert!(matches!(
+        StakePool::try_from_data(&data),
+        Err(InvalidAccountError::Deserialize(_))
+    ));
+}
+
+#[test]
+fn test_validator_list_try_from_account() {
+    let mut data = jupsol_validator_list_data();
+    let list = ValidatorList::try_from_account(
+        &SANCTUM_SPL_MULTI_STAKE_POOL_PROGRAM,
+        &SANCTUM_SPL_MULTI_STAKE_POOL_PROGRAM,
+        &data,
+    )
+    .unwrap();
+    assert_eq!(list, ValidatorList::deserialize(&data).unwrap());
+    assert!(!list.validators.is_empty());
+    assert_eq!(
+        ValidatorList::try_from_account(
+            &SANCTUM_SPL_STAKE_POOL_PROGRAM,
+            &SANCTUM_SPL_MULTI_STAKE_POOL_PROGRAM,
+            &data
+        ),
+        Err(InvalidAccountError::WrongOwner)
+    );
+    assert_eq!(
+        ValidatorList::try_from_known_program_account(&SANCTUM_SPL_MULTI_STAKE_POOL_PROGRAM, &data),
+        Ok(list)
+    );
+    assert_eq!(
+        ValidatorList::try_from_known_program_account(&[1u8; 32], &data),
+        Err(InvalidAccountError::WrongOwner)
+    );
+
+    let mut wrong_type = data.clone();
+    wrong_type[0] = 1;
+    assert_eq!(
+        ValidatorList::try_from_data(&wrong_type),
+        Err(InvalidAccountError::WrongAccountType(
+            AccountType::StakePool
+        ))
+    );
+
+    let mut exceeds_max = data.clone();
+    exceeds_max[1..5].copy_from_slice(&0u32.to_le_bytes());
+    assert!(matches!(
+        ValidatorList::try_from_data(&exceeds_max),
+        Err(InvalidAccountError::NumValidatorsExceedsMax {
+            max_validators: 0,
+            ..
+        })
+    ));
+
+    data[FIRST_VALIDATOR_STATUS_OFFSET] = 0xff;
+    assert_eq!(
+        ValidatorList::try_from_data(&data),
+        Err(InvalidAccountError::WrongStakeStatus {
+            index: 0,
+            status: 0xff
+        })
+    );
+    assert!(ValidatorList::deserialize(&data).is_err());
+}
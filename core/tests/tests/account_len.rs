@@ -0,0 +1,75 @@
+use sanctum_spl_stake_pool_core::{Fee, FutureEpoch, Rent, StakePool, ValidatorList};
+
+use crate::common::{
+    consts::STAKE_POOL_DATA,
+    fixtures::{fixture_account_data, jupsol_validator_list_data},
+};
+
+#[test]
+fn test_account_lens_match_fixtures() {
+    assert_eq!(
+        fixture_account_data(STAKE_POOL_DATA).len(),
+        StakePool::ACCOUNT_LEN
+    );
+
+    let data = jupsol_validator_list_data();
+    let list = ValidatorList::try_from_data(&data).unwrap();
+    assert_eq!(
+        ValidatorList::account_len(list.header.max_validators),
+        Some(data.len())
+    );
+    assert_eq!(
+        ValidatorList::max_validators_for_account_len(data.len()),
+        list.header.max_validators
+    );
+}
+
+#[test]
+fn test_stake_pool_account_len_is_max_serialized_len() {
+    let fee = Fee {
+        denominator: 1,
+        numerator: 1,
+    };
+    let stake_pool = StakePool {
+        next_epoch_fee: FutureEpoch::Two(fee),
+        preferred_deposit_validator_vote_address: Some([1; 32]),
+        preferred_withdraw_validator_vote_address: Some([1; 32]),
+        next_stake_withdrawal_fee: FutureEpoch::One(fee),
+        sol_deposit_authority: Some([1; 32]),
+        sol_withdraw_authority: Some([1; 32]),
+        next_sol_withdrawal_fee: FutureEpoch::Two(fee),
+        ..Default::default()
+    };
+    let mut data = Vec::new();
+    stake_pool.borsh_ser(&mut data).unwrap();
+    assert_eq!(data.len(), StakePool::ACCOUNT_LEN);
+}
+
+#[test]
+fn test_account_len_inverse() {
+    assert_eq!(ValidatorList::account_len(0), Some(9));
+    assert_eq!(ValidatorList::account_len(1), Some(9 + 73));
+    assert_eq!(ValidatorList::max_validators_for_account_len(0), 0);
+    assert_eq!(ValidatorList::max_validators_for_account_len(9 + 72), 0);
+    assert_eq!(ValidatorList::max_validators_for_account_len(9 + 146), 2);
+}
+
+#[test]
+fn test_rent_exempt_lamports() {
+    assert_eq!(StakePool::rent_exempt_lamports(None), (128 + 611) * 6960);
+    assert_eq!(
+        ValidatorList::rent_exempt_lamports(1, Some(&Rent::DEFAULT)),
+        Some((128 + 9 + 73) * 6960)
+    );
+}
+
+#[test]
+fn test_account_len_overflow() {
+    // only overflows on targets with 32-bit usize
+    let expected = usize::try_from(9 + 73 * u64::from(u32::MAX)).ok();
+    assert_eq!(ValidatorList::account_len(u32::MAX), expected);
+    assert_eq!(
+        ValidatorList::rent_exempt_lamports(u32::MAX, None).is_some(),
+        expected.is_some()
+    );
+}
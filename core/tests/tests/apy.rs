@@ -0,0 +1,86 @@
+use sanctum_spl_stake_pool_core::{rate_change_from_snapshots, Fee, FutureEpoch, StakePool};
+
+use crate::common::{consts::NEW_FEE, fixtures::jupsol_stake_pool};
+
+fn pool(total_lamports: u64, pool_token_supply: u64) -> StakePool {
+    StakePool {
+        total_lamports,
+        pool_token_supply,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_epoch_rate_change_jupsol() {
+    let stake_pool = jupsol_stake_pool();
+    let change = stake_pool.epoch_rate_change().unwrap();
+    assert_eq!(change.epochs, 1);
+    assert!(
+        (change.rate_change - 0.000474324594359).abs() < 1e-12,
+        "{change:?}"
+    );
+}
+
+#[test]
+fn test_epoch_rate_change_no_last_epoch() {
+    assert_eq!(pool(1, 1).epoch_rate_change(), None);
+}
+
+#[test]
+fn test_epoch_fee_for_update() {
+    let mut stake_pool = StakePool {
+        epoch_fee: Fee {
+            denominator: 100,
+            numerator: 5,
+        },
+        ..Default::default()
+    };
+    let current = stake_pool.epoch_fee;
+    assert_eq!(stake_pool.epoch_fee_for_update(1), current);
+    assert_eq!(stake_pool.epoch_fee_for_update(2), current);
+
+    stake_pool.next_epoch_fee = FutureEpoch::One(NEW_FEE);
+    assert_eq!(stake_pool.epoch_fee_for_update(1), current);
+    assert_eq!(stake_pool.epoch_fee_for_update(2), NEW_FEE);
+
+    stake_pool.next_epoch_fee = FutureEpoch::Two(NEW_FEE);
+    assert_eq!(stake_pool.epoch_fee_for_update(2), current);
+    assert_eq!(stake_pool.epoch_fee_for_update(3), NEW_FEE);
+}
+
+#[test]
+fn test_projected_epoch_rate_change() {
+    let stake_pool = StakePool {
+        next_epoch_fee: FutureEpoch::One(NEW_FEE),
+        ..Default::default()
+    };
+    assert_eq!(
+        stake_pool.projected_epoch_rate_change(0.001, 1),
+        Some(0.001)
+    );
+    assert!((stake_pool.projected_epoch_rate_change(0.001, 2).unwrap() - 0.0009).abs() < 1e-15);
+
+    let invalid = StakePool {
+        epoch_fee: Fee {
+            denominator: 1,
+            numerator: 2,
+        },
+        ..Default::default()
+    };
+    assert_eq!(invalid.projected_epoch_rate_change(0.001, 1), None);
+}
+
+#[test]
+fn test_rate_change_from_snapshots() {
+    let snapshots = [
+        (12, pool(1_100, 1_000)),
+        (10, pool(1_000, 1_000)),
+        (11, pool(0, 0)),
+        (11, pool(2_100, 2_000)),
+    ];
+    let change = rate_change_from_snapshots(snapshots.iter().map(|(e, p)| (*e, p))).unwrap();
+    assert_eq!(change.epochs, 2);
+    assert!((change.rate_change - 0.1).abs() < 1e-12, "{change:?}");
+
+    assert_eq!(rate_change_from_snapshots([(10, &pool(0, 0))]), None);
+}
@@ -0,0 +1,157 @@
+use sanctum_spl_stake_pool_core::{
+    audit_pool_itr, Fee, FutureEpoch, PoolAuditFinding, PoolFeeField, PoolReferralFeeField,
+    StakePool, ValidatorList, ValidatorStakeAccountsLamports, STAKE_ACCOUNT_RENT_EXEMPT_LAMPORTS,
+};
+
+use crate::common::fixtures::{jupsol_stake_pool, jupsol_validator_list_data};
+
+const RESERVE_STAKE_LAMPORTS: u64 = 1_000_000_000;
+
+/// Fixture pool with `total_lamports` consistent with the fixture validator list
+/// and a reserve of [`RESERVE_STAKE_LAMPORTS`]
+fn consistent_stake_pool(validator_list: &ValidatorList) -> StakePool {
+    let mut stake_pool = jupsol_stake_pool();
+    stake_pool.total_lamports = validator_list
+        .validators
+        .iter()
+        .map(|v| v.active_stake_lamports() + v.transient_stake_lamports())
+        .sum::<u64>()
+        + RESERVE_STAKE_LAMPORTS
+        - STAKE_ACCOUNT_RENT_EXEMPT_LAMPORTS;
+    stake_pool
+}
+
+#[test]
+fn test_audit_consistent_pool() {
+    let validator_list_data = jupsol_validator_list_data();
+    let validator_list = ValidatorList::deserialize(&validator_list_data).unwrap();
+    let stake_pool = consistent_stake_pool(&validator_list);
+
+    let findings: Vec<_> = audit_pool_itr(
+        &stake_pool,
+        &validator_list,
+        RESERVE_STAKE_LAMPORTS,
+        stake_pool.pool_token_supply,
+        |_, vsi| {
+            Some(ValidatorStakeAccountsLamports {
+                validator: vsi.active_stake_lamports(),
+                transient: vsi.transient_stake_lamports(),
+            })
+        },
+    )
+    .collect();
+    assert_eq!(findings, []);
+}
+
+#[test]
+fn test_audit_pool_findings() {
+    let validator_list_data = jupsol_validator_list_data();
+    let validator_list = ValidatorList::deserialize(&validator_list_data).unwrap();
+    let mut stake_pool = consistent_stake_pool(&validator_list);
+    stake_pool.sol_referral_fee = 101;
+    stake_pool.sol_deposit_fee = Fee {
+        denominator: 0,
+        numerator: 1,
+    };
+    // 100% is allowed
+    stake_pool.sol_withdrawal_fee = Fee {
+        denominator: 1,
+        numerator: 1,
+    };
+    stake_pool.next_sol_withdrawal_fee = FutureEpoch::One(Fee {
+        denominator: 10,
+        numerator: 11,
+    });
+    stake_pool.last_update_epoch = 750;
+
+    let findings: Vec<_> = audit_pool_itr(
+        &stake_pool,
+        &validator_list,
+        RESERVE_STAKE_LAMPORTS + 1,
+        stake_pool.pool_token_supply - 1,
+        |i, _| {
+            (i == 2).then_some(ValidatorStakeAccountsLamports {
+                validator: 0,
+                transient: 0,
+            })
+        },
+    )
+    .collect();
+
+    let mut expected = vec![
+        PoolAuditFinding::TotalLamportsMismatch {
+            expected: stake_pool.total_lamports + 1,
+            total_lamports: stake_pool.total_lamports,
+        },
+        PoolAuditFinding::PoolTokenSupplyMismatch {
+            mint_supply: stake_pool.pool_token_supply - 1,
+            pool_token_supply: stake_pool.pool_token_supply,
+        },
+        PoolAuditFinding::ReferralFeeTooHigh {
+            field: PoolReferralFeeField::SolReferralFee,
+            fee_pct: 101,
+        },
+        PoolAuditFinding::FeeZeroDenominator {
+            field: PoolFeeField::SolDepositFee,
+            numerator: 1,
+        },
+        PoolAuditFinding::FeeTooHigh {
+            field: PoolFeeField::NextSolWithdrawalFee,
+            fee: Fee {
+                denominator: 10,
+                numerator: 11,
+            },
+        },
+    ];
+    expected.extend((0..3).map(|index| PoolAuditFinding::ValidatorAheadOfPool {
+        index,
+        last_update_epoch: 751,
+        pool_last_update_epoch: 750,
+    }));
+    // interleaved since findings are in validator list order
+    expected.extend([
+        PoolAuditFinding::StakeAccountLamportsMismatch {
+            index: 2,
+            recorded: ValidatorStakeAccountsLamports {
+                validator: validator_list.validators[2].active_stake_lamports(),
+                transient: 0,
+            },
+            actual: ValidatorStakeAccountsLamports {
+                validator: 0,
+                transient: 0,
+            },
+        },
+        PoolAuditFinding::InconsistentStakeStatus { index: 2 },
+    ]);
+    assert_eq!(findings, expected);
+}
+
+#[test]
+fn test_audit_duplicate_vote_account() {
+    let validator_list_data = jupsol_validator_list_data();
+    let validator_list = ValidatorList::deserialize(&validator_list_data).unwrap();
+    let mut validators = validator_list.validators.to_vec();
+    let first_vote = *validators[0].vote_account_address();
+    validators[2].set_vote_account_address(first_vote);
+    let validator_list = ValidatorList {
+        header: validator_list.header,
+        validators: &validators,
+    };
+    let stake_pool = consistent_stake_pool(&validator_list);
+
+    let findings: Vec<_> = audit_pool_itr(
+        &stake_pool,
+        &validator_list,
+        RESERVE_STAKE_LAMPORTS,
+        stake_pool.pool_token_supply,
+        |_, _| None,
+    )
+    .collect();
+    assert_eq!(
+        findings,
+        [PoolAuditFinding::DuplicateVoteAccount {
+            index: 2,
+            first_index: 0
+        }]
+    );
+}
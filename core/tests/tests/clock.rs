@@ -0,0 +1,119 @@
+use sanctum_spl_stake_pool_core::{
+    Clock, DepositSolQuoteArgs, EpochSchedule, WithdrawSolQuoteArgs, WithdrawStakeQuoteArgs,
+    DEFAULT_MS_PER_SLOT,
+};
+
+/// Warmup epoch schedule used by test validators and testnet
+const WARMUP_SCHEDULE: EpochSchedule = EpochSchedule {
+    slots_per_epoch: 8192,
+    leader_schedule_slot_offset: 8192,
+    warmup: true,
+    first_normal_epoch: 8,
+    first_normal_slot: 8160,
+};
+
+#[test]
+fn test_warmup_epoch_schedule() {
+    for (slot, expected) in [
+        (0, (0, 0)),
+        (31, (0, 31)),
+        (32, (1, 0)),
+        (95, (1, 63)),
+        (8159, (7, 4095)),
+        (8160, (8, 0)),
+        (8160 + 8192, (9, 0)),
+    ] {
+        assert_eq!(
+            WARMUP_SCHEDULE.epoch_and_slot_index(slot),
+            Some(expected),
+            "{slot}"
+        );
+    }
+    for (epoch, slots, first_slot) in [(0, 32, 0), (1, 64, 32), (8, 8192, 8160), (9, 8192, 16352)] {
+        assert_eq!(WARMUP_SCHEDULE.slots_in_epoch(epoch), slots);
+        assert_eq!(WARMUP_SCHEDULE.first_slot_in_epoch(epoch), first_slot);
+    }
+    assert_eq!(WARMUP_SCHEDULE.slots_until_next_epoch(95), Some(1));
+}
+
+#[test]
+fn test_default_epoch_schedule() {
+    let slot = 751 * 432_000 + 100;
+    let schedule = EpochSchedule::DEFAULT;
+    assert_eq!(schedule.epoch_and_slot_index(slot), Some((751, 100)));
+    assert_eq!(schedule.first_slot_in_epoch(752), slot + 431_900);
+
+    let clock = Clock {
+        slot,
+        epoch: 751,
+        ..Default::default()
+    };
+    assert_eq!(clock.slot_index(&schedule), Some(100));
+    assert_eq!(
+        clock.est_secs_until_next_epoch(&schedule, DEFAULT_MS_PER_SLOT),
+        Some(172_760)
+    );
+}
+
+#[test]
+fn test_zero_slots_per_epoch() {
+    let schedule = EpochSchedule {
+        slots_per_epoch: 0,
+        ..WARMUP_SCHEDULE
+    };
+    // warmup slots do not depend on slots_per_epoch
+    assert_eq!(schedule.epoch_and_slot_index(95), Some((1, 63)));
+    assert_eq!(schedule.epoch_and_slot_index(8160), None);
+    assert_eq!(schedule.epoch(8160), None);
+    assert_eq!(schedule.slots_until_next_epoch(8160), None);
+    let clock = Clock {
+        slot: 8160,
+        ..Default::default()
+    };
+    assert_eq!(clock.slot_index(&schedule), None);
+}
+
+#[test]
+fn test_sysvar_round_trip() {
+    let clock = Clock {
+        slot: 1,
+        epoch_start_timestamp: 2,
+        epoch: 3,
+        leader_schedule_epoch: 4,
+        unix_timestamp: 5,
+    };
+    let mut data = Vec::new();
+    clock.borsh_ser(&mut data).unwrap();
+    assert_eq!(data.len(), Clock::ACCOUNT_LEN);
+    assert_eq!(data[16..24], 3u64.to_le_bytes());
+    assert_eq!(Clock::borsh_de(data.as_slice()).unwrap(), clock);
+
+    let mut data = Vec::new();
+    WARMUP_SCHEDULE.borsh_ser(&mut data).unwrap();
+    assert_eq!(data.len(), EpochSchedule::ACCOUNT_LEN);
+    assert_eq!(
+        EpochSchedule::borsh_de(data.as_slice()).unwrap(),
+        WARMUP_SCHEDULE
+    );
+}
+
+#[test]
+fn test_quote_args_from_clock() {
+    let clock = Clock {
+        epoch: 751,
+        ..Default::default()
+    };
+    let user = [1u8; 32];
+    assert_eq!(
+        DepositSolQuoteArgs::from_clock(&user, &clock).current_epoch,
+        751
+    );
+    assert_eq!(
+        WithdrawSolQuoteArgs::from_clock(&user, 0, &clock).current_epoch,
+        751
+    );
+    assert_eq!(
+        WithdrawStakeQuoteArgs::from_clock(&clock).current_epoch,
+        751
+    );
+}
@@ -0,0 +1,61 @@
+use const_crypto::bs58::decode_pubkey;
+use sanctum_spl_stake_pool_core::{
+    known_stake_pool_by_addr, known_stake_pool_by_mint, KnownStakePool, StakePool,
+    StakePoolDeployment, BPSOL, JUPSOL, KNOWN_STAKE_POOLS, PICOSOL,
+    SANCTUM_SPL_MULTI_STAKE_POOL_PROGRAM, SPL_STAKE_POOL_PROGRAM,
+};
+
+use crate::common::{
+    consts::{BPSOL_MINT_DATA, BPSOL_STAKE_POOL_DATA, PICOSOL_STAKE_POOL_DATA, STAKE_POOL_DATA},
+    fixtures::fixture_account_data,
+};
+
+#[test]
+fn test_is_known_program() {
+    assert!(StakePoolDeployment::new(SPL_STAKE_POOL_PROGRAM, [1u8; 32]).is_known_program());
+    assert!(
+        StakePoolDeployment::new(SANCTUM_SPL_MULTI_STAKE_POOL_PROGRAM, [1u8; 32])
+            .is_known_program()
+    );
+    assert!(!StakePoolDeployment::new([1u8; 32], [1u8; 32]).is_known_program());
+}
+
+fn fixture_pubkey(account_json: &[u8], pointer: &str) -> [u8; 32] {
+    let json: serde_json::Value = serde_json::from_slice(account_json).unwrap();
+    decode_pubkey(json.pointer(pointer).unwrap().as_str().unwrap())
+}
+
+/// Checks `known`'s deployment against a `solana account --output json` fixture of its stake pool
+fn assert_known_deployment_matches_fixture(known: &KnownStakePool, account_json: &[u8]) {
+    assert_eq!(
+        fixture_pubkey(account_json, "/pubkey"),
+        known.deployment.stake_pool
+    );
+    assert_eq!(
+        fixture_pubkey(account_json, "/account/owner"),
+        known.deployment.program
+    );
+    assert_eq!(known_stake_pool_by_mint(&known.pool_mint), Some(known));
+    assert_eq!(
+        known_stake_pool_by_addr(&known.deployment.stake_pool),
+        Some(known)
+    );
+}
+
+/// Also checks `known`'s pool mint against the fixture's decoded stake pool data
+fn assert_known_pool_matches_fixture(known: &KnownStakePool, account_json: &[u8]) {
+    assert_known_deployment_matches_fixture(known, account_json);
+    let stake_pool = StakePool::try_from_data(&fixture_account_data(account_json)).unwrap();
+    assert_eq!(stake_pool.pool_mint, known.pool_mint);
+}
+
+#[test]
+fn test_known_pools_match_fixtures() {
+    assert_known_pool_matches_fixture(&JUPSOL, STAKE_POOL_DATA);
+    assert_known_pool_matches_fixture(&PICOSOL, PICOSOL_STAKE_POOL_DATA);
+    // bpSOL's stake pool fixture is uninitialized, so its data cannot be decoded
+    assert_known_deployment_matches_fixture(&BPSOL, BPSOL_STAKE_POOL_DATA);
+    assert_eq!(fixture_pubkey(BPSOL_MINT_DATA, "/pubkey"), BPSOL.pool_mint);
+    assert_eq!(KNOWN_STAKE_POOLS, [JUPSOL, PICOSOL, BPSOL]);
+    assert_eq!(known_stake_pool_by_mint(&[0u8; 32]), None);
+}
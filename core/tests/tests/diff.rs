@@ -0,0 +1,102 @@
+use sanctum_spl_stake_pool_core::{
+    stake_pool_diff_itr, validator_list_diff_itr, Fee, FutureEpoch, PoolFeeField,
+    PreferredValidatorField, StakePoolAuthorityField, StakePoolChange, StakeStatus, ValidatorList,
+    ValidatorListChange,
+};
+
+use crate::common::fixtures::{jupsol_stake_pool, jupsol_validator_list_data};
+
+#[test]
+fn test_stake_pool_diff() {
+    let old = jupsol_stake_pool();
+    assert_eq!(stake_pool_diff_itr(&old, &old).count(), 0);
+
+    let fee = Fee {
+        denominator: 100,
+        numerator: 1,
+    };
+    let mut new = old.clone();
+    new.manager = [1u8; 32];
+    new.next_epoch_fee = FutureEpoch::Two(fee);
+    new.total_lamports += 1;
+    new.preferred_withdraw_validator_vote_address = Some([2u8; 32]);
+
+    let changes: Vec<_> = stake_pool_diff_itr(&old, &new).collect();
+    assert_eq!(
+        changes,
+        [
+            StakePoolChange::Authority {
+                field: StakePoolAuthorityField::Manager,
+                old: Some(old.manager),
+                new: Some([1u8; 32]),
+            },
+            StakePoolChange::Fee {
+                field: PoolFeeField::NextEpochFee,
+                old: None,
+                new: Some(fee),
+            },
+            StakePoolChange::TotalLamports {
+                old: old.total_lamports,
+                new: old.total_lamports + 1,
+            },
+            StakePoolChange::PreferredValidator {
+                field: PreferredValidatorField::Withdraw,
+                old: old.preferred_withdraw_validator_vote_address,
+                new: Some([2u8; 32]),
+            },
+        ]
+    );
+
+    // scheduled fee countdown is not a change
+    let mut next = new.clone();
+    next.next_epoch_fee = FutureEpoch::One(fee);
+    assert_eq!(stake_pool_diff_itr(&new, &next).count(), 0);
+}
+
+#[test]
+fn test_validator_list_diff() {
+    let validator_list_data = jupsol_validator_list_data();
+    let old = ValidatorList::deserialize(&validator_list_data).unwrap();
+    assert_eq!(validator_list_diff_itr(&old, &old).count(), 0);
+
+    // remove validator 0 the way cleanup does (swap remove),
+    // remove validator 1 with a transient stake, add a new validator
+    let mut validators = old.validators.to_vec();
+    validators.swap_remove(0);
+    // validator 2 was swapped to index 0, validator 1 stays at index 1
+    validators[1].set_status(StakeStatus::DeactivatingAll);
+    validators[1].set_transient_stake_lamports(1);
+    let mut added = validators[0];
+    added.set_vote_account_address([1u8; 32]);
+    validators.push(added);
+    let new = ValidatorList {
+        header: old.header,
+        validators: &validators,
+    };
+
+    let changes: Vec<_> = validator_list_diff_itr(&old, &new).collect();
+    let o = &old.validators[1];
+    assert_eq!(
+        changes,
+        [
+            ValidatorListChange::Removed { old_index: 0 },
+            ValidatorListChange::StatusChanged {
+                old_index: 1,
+                new_index: 1,
+                old: StakeStatus::Active,
+                new: StakeStatus::DeactivatingAll,
+            },
+            ValidatorListChange::LamportsChanged {
+                old_index: 1,
+                new_index: 1,
+                old_active_stake_lamports: o.active_stake_lamports(),
+                new_active_stake_lamports: o.active_stake_lamports(),
+                old_transient_stake_lamports: 0,
+                new_transient_stake_lamports: 1,
+            },
+            ValidatorListChange::Added { new_index: 2 },
+        ]
+    );
+    assert_eq!(changes[2].transient_stake_lamports_delta(), Some(1));
+    assert_eq!(changes[2].active_stake_lamports_delta(), Some(0));
+}
@@ -0,0 +1,74 @@
+use sanctum_spl_stake_pool_core::{Fee, FutureEpoch, StakePool, WithdrawSolQuoteArgs};
+
+use crate::common::{consts::NEW_FEE, fixtures::jupsol_stake_pool};
+
+#[test]
+fn test_effective_fees_at_no_scheduled_changes() {
+    let stake_pool = jupsol_stake_pool();
+    let epoch = stake_pool.last_update_epoch;
+    for e in [epoch, epoch + 1, epoch + 2, epoch + 100] {
+        assert_eq!(stake_pool.effective_fees_at(e), stake_pool.fees());
+    }
+}
+
+#[test]
+fn test_effective_fees_at_scheduled_changes() {
+    let stake_pool = StakePool {
+        next_epoch_fee: FutureEpoch::One(NEW_FEE),
+        next_sol_withdrawal_fee: FutureEpoch::Two(NEW_FEE),
+        ..jupsol_stake_pool()
+    };
+    let epoch = stake_pool.last_update_epoch;
+
+    let fees = stake_pool.effective_fees_at(epoch);
+    assert_eq!(fees, stake_pool.fees());
+
+    let fees = stake_pool.effective_fees_at(epoch + 1);
+    assert_eq!(fees.epoch_fee, NEW_FEE);
+    assert_eq!(fees.sol_withdrawal_fee, stake_pool.sol_withdrawal_fee);
+
+    let fees = stake_pool.effective_fees_at(epoch + 2);
+    assert_eq!(fees.epoch_fee, NEW_FEE);
+    assert_eq!(fees.sol_withdrawal_fee, NEW_FEE);
+    assert_eq!(fees.sol_deposit_fee, stake_pool.sol_deposit_fee);
+}
+
+#[test]
+fn test_projected_to_epoch() {
+    let stake_pool = StakePool {
+        next_sol_withdrawal_fee: FutureEpoch::Two(NEW_FEE),
+        ..jupsol_stake_pool()
+    };
+    let epoch = stake_pool.last_update_epoch;
+
+    assert_eq!(stake_pool.projected_to_epoch(epoch), stake_pool);
+
+    let projected = stake_pool.projected_to_epoch(epoch + 1);
+    assert_eq!(projected.last_update_epoch, epoch + 1);
+    assert_eq!(projected.sol_withdrawal_fee, stake_pool.sol_withdrawal_fee);
+    assert_eq!(projected.next_sol_withdrawal_fee, FutureEpoch::One(NEW_FEE));
+    assert_eq!(
+        projected.last_epoch_pool_token_supply,
+        stake_pool.pool_token_supply
+    );
+    assert_eq!(
+        projected.projected_to_epoch(epoch + 2),
+        stake_pool.projected_to_epoch(epoch + 2)
+    );
+
+    let projected = stake_pool.projected_to_epoch(epoch + 2);
+    assert_eq!(projected.sol_withdrawal_fee, NEW_FEE);
+    assert_eq!(projected.next_sol_withdrawal_fee, FutureEpoch::None);
+
+    // pool is stale at the future epoch, but the projection is not
+    let pool_tokens = 1_000_000_000;
+    let args = WithdrawSolQuoteArgs {
+        withdrawer: &[0u8; 32],
+        reserve_stake_lamports: u64::MAX,
+        current_epoch: epoch + 2,
+        rent: None,
+    };
+    assert!(stake_pool.quote_withdraw_sol(pool_tokens, &args).is_err());
+    let quote = projected.quote_withdraw_sol(pool_tokens, &args).unwrap();
+    assert_eq!(quote.manager_fee, pool_tokens / 10);
+}
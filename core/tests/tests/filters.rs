@@ -0,0 +1,63 @@
+use sanctum_spl_stake_pool_core::{
+    stake_pool_prefix_matches, stake_pools_managed_by_filters, stake_pools_with_mint_filters,
+    validator_list_prefix_matches, MemcmpFilter, StakePool, ValidatorList,
+    STAKE_POOL_FIXED_PREFIX_LEN, VALIDATOR_LIST_NUM_VALIDATORS_OFFSET,
+};
+
+use crate::common::{
+    consts::STAKE_POOL_DATA,
+    fixtures::{fixture_account_data, jupsol_validator_list_data},
+};
+
+#[test]
+fn test_stake_pool_filters_match_fixture() {
+    let data = fixture_account_data(STAKE_POOL_DATA);
+    let pool = StakePool::try_from_data(&data).unwrap();
+
+    assert!(stake_pool_prefix_matches(
+        &data,
+        &stake_pools_with_mint_filters(&pool.pool_mint)
+    ));
+    assert!(stake_pool_prefix_matches(
+        &data,
+        &stake_pools_managed_by_filters(&pool.manager)
+    ));
+    assert!(stake_pool_prefix_matches(
+        &data,
+        &[
+            MemcmpFilter::stake_pool_staker(&pool.staker),
+            MemcmpFilter::stake_pool_validator_list(&pool.validator_list),
+            MemcmpFilter::stake_pool_reserve_stake(&pool.reserve_stake),
+            MemcmpFilter::stake_pool_manager_fee_account(&pool.manager_fee_account),
+            MemcmpFilter::stake_pool_token_program_id(&pool.token_program_id),
+            MemcmpFilter::stake_pool_stake_deposit_authority(&pool.stake_deposit_authority),
+        ]
+    ));
+    assert!(!stake_pool_prefix_matches(
+        &data,
+        &stake_pools_with_mint_filters(&[0u8; 32])
+    ));
+    assert!(!stake_pool_prefix_matches(
+        &data[..STAKE_POOL_FIXED_PREFIX_LEN - 1],
+        &[]
+    ));
+}
+
+#[test]
+fn test_validator_list_filters_match_fixture() {
+    let data = jupsol_validator_list_data();
+    let list = ValidatorList::try_from_data(&data).unwrap();
+    let num_validators = (list.validators.len() as u32).to_le_bytes();
+
+    assert!(validator_list_prefix_matches(
+        &data,
+        &[MemcmpFilter::new(
+            VALIDATOR_LIST_NUM_VALIDATORS_OFFSET,
+            &num_validators
+        )]
+    ));
+    assert!(!validator_list_prefix_matches(
+        &fixture_account_data(STAKE_POOL_DATA),
+        &[]
+    ));
+}
@@ -1,8 +1,17 @@
+use std::num::NonZeroUsize;
+
 use const_crypto::bs58;
 use data_encoding::BASE64;
+
 use sanctum_spl_stake_pool_core::{
-    StakePool, UpdateStakePoolBalanceIxData, UpdateStakePoolBalanceIxKeysOwned,
+    update_validator_list_balance_chunks_itr, update_validator_list_balance_ix_count,
+    CreateAccountIxData, DepositSolIxFullKeysOwned, DepositSolIxPrefixKeysOwned,
+    DepositStakeIxFullKeysOwned, DepositStakeIxKeysOwned, Lockup, StakeAuthorize,
+    StakeAuthorizeIxData, StakeDeactivateIxData, StakeInitializeIxData, StakePool,
+    StakeWithdrawIxData, UpdateStakePoolBalanceIxData, UpdateStakePoolBalanceIxKeysOwned,
     UpdateValidatorListBalanceIxData, UpdateValidatorListBalanceIxPrefixKeysOwned, ValidatorList,
+    DEPOSIT_SOL_IX_PREFIX_ACCS_LEN, STAKE_ACCOUNT_LEN, STAKE_ACCOUNT_RENT_EXEMPT_LAMPORTS,
+    STAKE_PROGRAM,
 };
 
 use crate::common::consts::{STAKE_POOL_DATA, VALIDATOR_LIST_DATA};
@@ -79,3 +88,116 @@ fn test_update_validator_list_balance_ix() {
     assert_eq!(validator_seeds.len(), 3);
     assert_eq!(transient_seeds.len(), 3);
 }
+
+#[test]
+fn test_stake_program_ix_data() {
+    let pk = [7u8; 32];
+
+    let authorize = StakeAuthorizeIxData::new(&pk, StakeAuthorize::Withdrawer).to_buf();
+    assert_eq!(authorize[..4], [1, 0, 0, 0]);
+    assert_eq!(authorize[4..36], pk);
+    assert_eq!(authorize[36..], [1, 0, 0, 0]);
+
+    let initialize = StakeInitializeIxData::new(
+        &pk,
+        &[8u8; 32],
+        &Lockup {
+            unix_timestamp: -1,
+            epoch: 2,
+            custodian: [9u8; 32],
+        },
+    )
+    .to_buf();
+    assert_eq!(initialize[..4], [0, 0, 0, 0]);
+    assert_eq!(initialize[36..68], [8u8; 32]);
+    assert_eq!(initialize[68..76], [0xff; 8]);
+    assert_eq!(initialize[76..84], 2u64.to_le_bytes());
+    assert_eq!(initialize[84..], [9u8; 32]);
+
+    assert_eq!(
+        StakeWithdrawIxData::new(1).to_buf(),
+        [4, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0]
+    );
+    assert_eq!(StakeDeactivateIxData::new().to_buf(), [5, 0, 0, 0]);
+
+    let create = CreateAccountIxData::new(
+        STAKE_ACCOUNT_RENT_EXEMPT_LAMPORTS,
+        STAKE_ACCOUNT_LEN as u64,
+        &STAKE_PROGRAM,
+    )
+    .to_buf();
+    assert_eq!(create[..4], [0, 0, 0, 0]);
+    assert_eq!(
+        create[4..12],
+        STAKE_ACCOUNT_RENT_EXEMPT_LAMPORTS.to_le_bytes()
+    );
+    assert_eq!(create[12..20], 200u64.to_le_bytes());
+    assert_eq!(create[20..], STAKE_PROGRAM);
+}
+
+#[test]
+fn test_update_validator_list_balance_chunks() {
+    let max = NonZeroUsize::new(5).unwrap();
+    assert_eq!(
+        update_validator_list_balance_chunks_itr(12, max).collect::<Vec<_>>(),
+        [(0, 5), (5, 5), (10, 2)]
+    );
+    assert_eq!(
+        update_validator_list_balance_chunks_itr(10, max).collect::<Vec<_>>(),
+        [(0, 5), (5, 5)]
+    );
+    assert_eq!(update_validator_list_balance_chunks_itr(0, max).count(), 0);
+    for n in 0..50 {
+        assert_eq!(
+            update_validator_list_balance_chunks_itr(n, max).count(),
+            update_validator_list_balance_ix_count(n, max)
+        );
+    }
+}
+
+#[test]
+fn test_full_keys_permissioned_suffix() {
+    let account_json: serde_json::Value = serde_json::from_slice(STAKE_POOL_DATA).unwrap();
+    let account_data = BASE64
+        .decode(
+            account_json["account"]["data"][0]
+                .as_str()
+                .unwrap()
+                .as_bytes(),
+        )
+        .unwrap();
+    let mut stake_pool = StakePool::borsh_de(&mut &account_data[..]).unwrap();
+    stake_pool.sol_deposit_authority = None;
+
+    let prefix = DepositSolIxPrefixKeysOwned::default().with_keys_from_stake_pool(&stake_pool);
+    let permissionless =
+        DepositSolIxFullKeysOwned::from_prefix_and_stake_pool(prefix.clone(), &stake_pool);
+    assert_eq!(
+        permissionless.keys_signer_writer().count(),
+        DEPOSIT_SOL_IX_PREFIX_ACCS_LEN
+    );
+
+    let auth = [7u8; 32];
+    stake_pool.sol_deposit_authority = Some(auth);
+    let permissioned = DepositSolIxFullKeysOwned::from_prefix_and_stake_pool(prefix, &stake_pool);
+    assert_eq!(
+        permissioned.keys_signer_writer().last(),
+        Some((&auth, true, false))
+    );
+
+    let deposit_auth_pda = [8u8; 32];
+    stake_pool.stake_deposit_authority = deposit_auth_pda;
+    let keys = DepositStakeIxKeysOwned::default();
+    let permissionless =
+        DepositStakeIxFullKeysOwned::from_keys_and_stake_pool(keys, &stake_pool, &deposit_auth_pda);
+    assert!(permissionless.keys_signer_writer().all(|(_, s, _)| !s));
+
+    stake_pool.stake_deposit_authority = auth;
+    let permissioned =
+        DepositStakeIxFullKeysOwned::from_keys_and_stake_pool(keys, &stake_pool, &deposit_auth_pda);
+    let signers: Vec<_> = permissioned
+        .keys_signer_writer()
+        .filter_map(|(k, s, _)| s.then_some(*k))
+        .collect();
+    assert_eq!(signers, [auth]);
+}
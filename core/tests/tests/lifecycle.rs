@@ -0,0 +1,140 @@
+use sanctum_spl_stake_pool_core::{
+    validator_adds_plan_itr, validator_lifecycle_plan_itr, StakeStatus, ValidatorAddStatus,
+    ValidatorAddStep, ValidatorLifecycleAction, ValidatorList, ADD_VALIDATOR_LAMPORTS,
+    MIN_VALIDATOR_STAKE_ACCOUNT_LAMPORTS, STAKE_ACCOUNT_RENT_EXEMPT_LAMPORTS,
+};
+
+use crate::common::fixtures::jupsol_validator_list_data;
+
+#[test]
+fn test_lifecycle_plan_keep_all() {
+    let validator_list_data = jupsol_validator_list_data();
+    let validator_list = ValidatorList::deserialize(&validator_list_data).unwrap();
+
+    assert!(
+        validator_lifecycle_plan_itr(&validator_list, 751, |_| true).all(|step| {
+            step.action == ValidatorLifecycleAction::Keep
+                && step.next_status == Some(StakeStatus::Active)
+        })
+    );
+}
+
+#[test]
+fn test_lifecycle_plan_stale_validators_await_update() {
+    let validator_list_data = jupsol_validator_list_data();
+    let validator_list = ValidatorList::deserialize(&validator_list_data).unwrap();
+
+    assert!(
+        validator_lifecycle_plan_itr(&validator_list, 752, |_| false)
+            .all(|step| step.action == ValidatorLifecycleAction::AwaitUpdate)
+    );
+}
+
+#[test]
+fn test_lifecycle_plan_decrease_before_removal() {
+    let validator_list_data = jupsol_validator_list_data();
+    let validator_list = ValidatorList::deserialize(&validator_list_data).unwrap();
+    let first = &validator_list.validators[0];
+
+    let step = validator_lifecycle_plan_itr(&validator_list, 751, |vote| {
+        vote != first.vote_account_address()
+    })
+    .next()
+    .unwrap();
+    assert_eq!(step.validator_index, 0);
+    assert_eq!(step.action, ValidatorLifecycleAction::Decrease);
+    assert_eq!(
+        step.lamports,
+        first.active_stake_lamports() - MIN_VALIDATOR_STAKE_ACCOUNT_LAMPORTS
+    );
+    assert_eq!(step.next_status, Some(StakeStatus::Active));
+    assert_eq!(
+        step.reserve_lamports_cost(),
+        STAKE_ACCOUNT_RENT_EXEMPT_LAMPORTS
+    );
+}
+
+#[test]
+fn test_adds_plan() {
+    let validator_list_data = jupsol_validator_list_data();
+    let validator_list = ValidatorList::deserialize(&validator_list_data).unwrap();
+    let existing = *validator_list.validators[0].vote_account_address();
+    let new_votes = [existing, [1u8; 32], [2u8; 32]];
+
+    // reserve can only fund a single add while staying rent-exempt
+    let reserve = STAKE_ACCOUNT_RENT_EXEMPT_LAMPORTS + ADD_VALIDATOR_LAMPORTS;
+    let steps: Vec<_> =
+        validator_adds_plan_itr(&validator_list, reserve, new_votes.iter()).collect();
+    assert_eq!(
+        steps,
+        [
+            ValidatorAddStep {
+                add_index: 0,
+                status: ValidatorAddStatus::AlreadyAdded,
+            },
+            ValidatorAddStep {
+                add_index: 1,
+                status: ValidatorAddStatus::Fundable,
+            },
+            ValidatorAddStep {
+                add_index: 2,
+                status: ValidatorAddStatus::InsufficientReserve,
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_adds_plan_repeats() {
+    let validator_list_data = jupsol_validator_list_data();
+    let validator_list = ValidatorList::deserialize(&validator_list_data).unwrap();
+    let new_votes = [[1u8; 32], [1u8; 32], [2u8; 32], [2u8; 32]];
+
+    let reserve = STAKE_ACCOUNT_RENT_EXEMPT_LAMPORTS + ADD_VALIDATOR_LAMPORTS;
+    let statuses: Vec<_> = validator_adds_plan_itr(&validator_list, reserve, new_votes.iter())
+        .map(|step| step.status)
+        .collect();
+    assert_eq!(
+        statuses,
+        [
+            ValidatorAddStatus::Fundable,
+            ValidatorAddStatus::AlreadyAdded,
+            ValidatorAddStatus::InsufficientReserve,
+            ValidatorAddStatus::InsufficientReserve,
+        ]
+    );
+}
+
+#[test]
+fn test_adds_plan_ready_for_removal() {
+    let validator_list_data = jupsol_validator_list_data();
+    let validator_list = ValidatorList::deserialize(&validator_list_data).unwrap();
+    let mut validators = validator_list.validators.to_vec();
+    validators[0].set_status(StakeStatus::ReadyForRemoval);
+    validators[1].set_status(StakeStatus::DeactivatingAll);
+    let mut header = validator_list.header;
+    // only the ReadyForRemoval entry's slot is free
+    header.max_validators = validators.len().try_into().unwrap();
+    let validator_list = ValidatorList {
+        header,
+        validators: &validators,
+    };
+    let new_votes = [
+        *validators[0].vote_account_address(),
+        *validators[1].vote_account_address(),
+        [1u8; 32],
+    ];
+
+    let reserve = STAKE_ACCOUNT_RENT_EXEMPT_LAMPORTS + 2 * ADD_VALIDATOR_LAMPORTS;
+    let statuses: Vec<_> = validator_adds_plan_itr(&validator_list, reserve, new_votes.iter())
+        .map(|step| step.status)
+        .collect();
+    assert_eq!(
+        statuses,
+        [
+            ValidatorAddStatus::Fundable,
+            ValidatorAddStatus::AwaitingCleanup,
+            ValidatorAddStatus::ValidatorListFull,
+        ]
+    );
+}
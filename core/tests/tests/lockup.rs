@@ -0,0 +1,112 @@
+use sanctum_spl_stake_pool_core::{
+    Clock, DepositStakeQuoteArgs, Lockup, SplStakePoolError, StakeAccountLamports, StakePool,
+    ValidatorList, STAKE_ACCOUNT_LEN,
+};
+
+use crate::common::fixtures::{jupsol_stake_pool, jupsol_validator_list_data};
+
+const CUSTODIAN: [u8; 32] = [7u8; 32];
+
+const LOCKUP: Lockup = Lockup {
+    unix_timestamp: 1_000,
+    epoch: 800,
+    custodian: CUSTODIAN,
+};
+
+fn clock(epoch: u64, unix_timestamp: i64) -> Clock {
+    Clock {
+        epoch,
+        unix_timestamp,
+        ..Default::default()
+    }
+}
+
+fn stake_account_data(tag: u32, lockup: &Lockup) -> Vec<u8> {
+    let mut data = vec![0u8; STAKE_ACCOUNT_LEN];
+    data[..4].copy_from_slice(&tag.to_le_bytes());
+    let mut lockup_bytes = Vec::new();
+    lockup.borsh_ser(&mut lockup_bytes).unwrap();
+    data[76..76 + lockup_bytes.len()].copy_from_slice(&lockup_bytes);
+    data
+}
+
+#[test]
+fn test_is_in_force() {
+    for (clock, custodian, expected) in [
+        (clock(799, 1_000), None, true),
+        (clock(800, 999), None, true),
+        (clock(800, 1_000), None, false),
+        (clock(799, 999), Some(&CUSTODIAN), false),
+        (clock(799, 999), Some(&[8u8; 32]), true),
+    ] {
+        assert_eq!(
+            LOCKUP.is_in_force(&clock, custodian),
+            expected,
+            "{clock:?} {custodian:?}"
+        );
+    }
+    assert!(!Lockup::DEFAULT.is_in_force(&clock(0, 0), None));
+}
+
+#[test]
+fn test_from_stake_account_data() {
+    for tag in [1, 2] {
+        assert_eq!(
+            Lockup::from_stake_account_data(&stake_account_data(tag, &LOCKUP)),
+            Some(LOCKUP)
+        );
+    }
+    for tag in [0, 3] {
+        assert_eq!(
+            Lockup::from_stake_account_data(&stake_account_data(tag, &LOCKUP)),
+            None
+        );
+    }
+    assert_eq!(Lockup::from_stake_account_data(&[1, 0, 0, 0]), None);
+}
+
+#[test]
+fn test_deposit_stake_lockup() {
+    let stake_pool = jupsol_stake_pool();
+    let validator_list_data = jupsol_validator_list_data();
+    let validator_list = ValidatorList::deserialize(&validator_list_data).unwrap();
+    let args = DepositStakeQuoteArgs::permissionless(
+        &validator_list.validators[0],
+        stake_pool.last_update_epoch,
+    );
+    let stake_account_lamports = StakeAccountLamports {
+        staked: 1_000_000_000,
+        unstaked: 2_282_880,
+    };
+
+    assert!(stake_pool
+        .quote_deposit_stake(
+            stake_account_lamports,
+            &args.with_stake_lockup(&Lockup::DEFAULT)
+        )
+        .is_ok());
+    assert_eq!(
+        stake_pool.quote_deposit_stake(stake_account_lamports, &args.with_stake_lockup(&LOCKUP)),
+        Err(SplStakePoolError::StakeLockupMismatch)
+    );
+}
+
+#[test]
+fn test_withdrawn_stake_lockup() {
+    let stake_pool = StakePool {
+        lockup: LOCKUP,
+        ..Default::default()
+    };
+    assert_eq!(
+        stake_pool.check_withdrawn_stake_lockup(&clock(799, 0), None),
+        Err(SplStakePoolError::WithdrawnStakeLockupInForce)
+    );
+    assert_eq!(
+        stake_pool.check_withdrawn_stake_lockup(&clock(799, 0), Some(&CUSTODIAN)),
+        Ok(())
+    );
+    assert_eq!(
+        stake_pool.check_withdrawn_stake_lockup(&clock(800, 1_000), None),
+        Ok(())
+    );
+}
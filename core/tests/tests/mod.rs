@@ -1,3 +1,21 @@
+mod account;
+mod account_len;
+mod apy;
+mod audit;
+mod clock;
+mod deployment;
+mod diff;
+mod fee_schedule;
+mod filters;
 mod instruction;
+mod lifecycle;
+mod lockup;
 mod quote;
+mod rebalance;
+mod rent;
+mod report;
 mod serde;
+mod solana_compat;
+mod stake_status;
+mod transient;
+mod update_status;
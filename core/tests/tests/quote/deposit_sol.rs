@@ -0,0 +1,134 @@
+use proptest::prelude::*;
+use sanctum_spl_stake_pool_core::{DepositSolQuote, Fee, StakePool};
+use sanctum_u64_ratio::Ratio;
+
+use crate::common::{
+    proptest_utils::{ratio_gte_one, ratio_lte_one},
+    quote::{NewPoolQuoteU64sBuilder, PoolQuoteU64Ds},
+};
+
+fn quote_rev_deposit_sol_round_trip_x_gte_1(
+    sol_deposit_fee: Fee,
+    sol_referral_fee: u8,
+    pool: PoolQuoteU64Ds,
+    tokens: u64,
+) {
+    let sp = StakePool {
+        total_lamports: *pool.total_lamports(),
+        pool_token_supply: *pool.pool_token_supply(),
+        sol_deposit_fee,
+        sol_referral_fee,
+        ..Default::default()
+    };
+    if let Some(
+        rev @ DepositSolQuote {
+            in_amount: lamports,
+            out_amount,
+            ..
+        },
+    ) = sp.quote_rev_deposit_sol_unchecked(tokens)
+    {
+        assert_eq!(out_amount, tokens);
+        // since x-rate >= 1, every pool token amount is reachable,
+        // so depositing the quoted lamports should give exactly the requested tokens
+        let quote = sp.quote_deposit_sol_unchecked(lamports).unwrap();
+        assert_eq!(quote, rev);
+        // and depositing 1 less lamport should give less tokens
+        if let Some(less) = lamports.checked_sub(1) {
+            let less_out = sp.quote_deposit_sol_unchecked(less).unwrap().out_amount;
+            assert!(less_out < tokens, "{less_out}, {tokens}");
+        }
+    }
+}
+
+proptest! {
+    #[test]
+    fn quote_rev_deposit_sol_round_trip_x_gte_1_pt(
+        (numerator, denominator) in (0..=u64::MAX).prop_flat_map(|d| (0..=d, Just(d))),
+        sol_referral_fee in 0..=100u8,
+        Ratio {
+            n: total_lamports,
+            d: pool_token_supply,
+        } in ratio_gte_one(),
+        tokens: u64,
+    ) {
+        quote_rev_deposit_sol_round_trip_x_gte_1(
+            Fee { numerator, denominator },
+            sol_referral_fee,
+            NewPoolQuoteU64sBuilder::start()
+                .with_total_lamports(total_lamports)
+                .with_pool_token_supply(pool_token_supply)
+                .build(),
+            tokens,
+        );
+    }
+}
+
+fn quote_rev_deposit_sol_round_trip_x_lte_1(
+    sol_deposit_fee: Fee,
+    sol_referral_fee: u8,
+    pool: PoolQuoteU64Ds,
+    tokens: u64,
+) {
+    let sp = StakePool {
+        total_lamports: *pool.total_lamports(),
+        pool_token_supply: *pool.pool_token_supply(),
+        sol_deposit_fee,
+        sol_referral_fee,
+        ..Default::default()
+    };
+    if let Some(
+        rev @ DepositSolQuote {
+            in_amount: lamports,
+            out_amount,
+            ..
+        },
+    ) = sp.quote_rev_deposit_sol_unchecked(tokens)
+    {
+        // since x-rate <= 1, not every pool token amount is reachable,
+        // so the quote is of the smallest deposit that gives at least the requested tokens
+        assert!(out_amount >= tokens, "{out_amount}, {tokens}");
+        let quote = sp.quote_deposit_sol_unchecked(lamports).unwrap();
+        assert_eq!(quote, rev);
+        if let Some(less) = lamports.checked_sub(1) {
+            let less_out = sp.quote_deposit_sol_unchecked(less).unwrap().out_amount;
+            assert!(less_out < tokens, "{less_out}, {tokens}");
+        }
+    }
+}
+
+proptest! {
+    #[test]
+    fn quote_rev_deposit_sol_round_trip_x_lte_1_pt(
+        (numerator, denominator) in (0..=u64::MAX).prop_flat_map(|d| (0..=d, Just(d))),
+        sol_referral_fee in 0..=100u8,
+        Ratio {
+            n: total_lamports,
+            d: pool_token_supply,
+        } in ratio_lte_one(),
+        tokens: u64,
+    ) {
+        quote_rev_deposit_sol_round_trip_x_lte_1(
+            Fee { numerator, denominator },
+            sol_referral_fee,
+            NewPoolQuoteU64sBuilder::start()
+                .with_total_lamports(total_lamports)
+                .with_pool_token_supply(pool_token_supply)
+                .build(),
+            tokens,
+        );
+    }
+}
+
+#[test]
+fn quote_rev_deposit_sol_unreachable_tokens() {
+    // 3 pool tokens per lamport, only multiples of 3 can be minted
+    let sp = StakePool {
+        total_lamports: 1_000,
+        pool_token_supply: 3_000,
+        ..Default::default()
+    };
+    let quote = sp.quote_rev_deposit_sol_unchecked(4).unwrap();
+    assert_eq!(quote.in_amount, 2);
+    assert_eq!(quote.out_amount, 6);
+}
@@ -1,2 +1,4 @@
+mod deposit_sol;
+mod trade;
 mod withdraw_sol;
 mod withdraw_stake;
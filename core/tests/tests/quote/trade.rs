@@ -0,0 +1,189 @@
+use sanctum_spl_stake_pool_core::{
+    DepositSolQuoteArgs, DepositStakeQuoteArgs, SplStakePoolError, StakeAccountLamports,
+    TradeContext, TradeQuote, TradeRequest, ValidatorList, ValidatorStakeInfo,
+    WithdrawSolQuoteArgs, WithdrawStakeQuoteArgs, WithdrawStakeValidatorContext,
+    MIN_VALIDATOR_STAKE_ACCOUNT_LAMPORTS,
+};
+use sanctum_u64_ratio::Ratio;
+
+use crate::common::fixtures::{jupsol_stake_pool, jupsol_validator_list_data};
+
+const USER: [u8; 32] = [1u8; 32];
+
+const CTX: TradeContext = TradeContext {
+    user: &USER,
+    current_epoch: 751,
+    reserve_stake_lamports: 1_000_000_000_000,
+    rent: None,
+};
+
+#[test]
+fn test_quote_matches_individual_quotes() {
+    let stake_pool = jupsol_stake_pool();
+    let validator_list_data = jupsol_validator_list_data();
+    let validator_list = ValidatorList::deserialize(&validator_list_data).unwrap();
+    let validator = &validator_list.validators[0];
+    let amt = 1_000_000_000;
+    let stake_account_lamports = StakeAccountLamports {
+        staked: amt,
+        unstaked: 2_282_880,
+    };
+    let deposit_sol_args = DepositSolQuoteArgs {
+        depositor: &USER,
+        current_epoch: CTX.current_epoch,
+    };
+    let withdraw_sol_args = WithdrawSolQuoteArgs {
+        withdrawer: &USER,
+        reserve_stake_lamports: CTX.reserve_stake_lamports,
+        current_epoch: CTX.current_epoch,
+        rent: CTX.rent,
+    };
+    let withdraw_stake_args = WithdrawStakeQuoteArgs {
+        current_epoch: CTX.current_epoch,
+    };
+    let validator_ctx = WithdrawStakeValidatorContext {
+        validator,
+        rent: CTX.rent,
+    };
+
+    for (request, expected) in [
+        (
+            TradeRequest::DepositSolExactIn { lamports: amt },
+            TradeQuote::DepositSol(
+                stake_pool
+                    .quote_deposit_sol(amt, &deposit_sol_args)
+                    .unwrap(),
+            ),
+        ),
+        (
+            TradeRequest::DepositSolExactOut { pool_tokens: amt },
+            TradeQuote::DepositSol(
+                stake_pool
+                    .quote_rev_deposit_sol(amt, &deposit_sol_args)
+                    .unwrap(),
+            ),
+        ),
+        (
+            TradeRequest::DepositStake {
+                stake_account_lamports,
+                validator,
+                permissioned: false,
+                stake_lockup: None,
+            },
+            TradeQuote::DepositStake(
+                stake_pool
+                    .quote_deposit_stake(
+                        stake_account_lamports,
+                        &DepositStakeQuoteArgs::permissionless(validator, CTX.current_epoch),
+                    )
+                    .unwrap(),
+            ),
+        ),
+        (
+            TradeRequest::WithdrawSolExactIn { pool_tokens: amt },
+            TradeQuote::WithdrawSol(
+                stake_pool
+                    .quote_withdraw_sol(amt, &withdraw_sol_args)
+                    .unwrap(),
+            ),
+        ),
+        (
+            TradeRequest::WithdrawSolExactOut { lamports: amt },
+            TradeQuote::WithdrawSol(
+                stake_pool
+                    .quote_rev_withdraw_sol(amt, &withdraw_sol_args)
+                    .unwrap(),
+            ),
+        ),
+        (
+            TradeRequest::WithdrawStakeExactIn {
+                pool_tokens: amt,
+                validator: Some(validator),
+            },
+            TradeQuote::WithdrawStake(
+                stake_pool
+                    .quote_withdraw_stake_from_validator(amt, withdraw_stake_args, validator_ctx)
+                    .unwrap(),
+            ),
+        ),
+        (
+            TradeRequest::WithdrawStakeExactOut {
+                lamports_staked: amt,
+                validator: Some(validator),
+            },
+            TradeQuote::WithdrawStake(
+                stake_pool
+                    .quote_rev_withdraw_stake_from_validator(
+                        amt,
+                        withdraw_stake_args,
+                        validator_ctx,
+                    )
+                    .unwrap(),
+            ),
+        ),
+    ] {
+        let quote = stake_pool.quote(&request, &CTX).unwrap();
+        assert_eq!(quote, expected);
+        assert_eq!(
+            quote.effective_rate(),
+            Ratio {
+                n: quote.out_amount(),
+                d: quote.in_amount(),
+            }
+        );
+    }
+}
+
+#[test]
+fn test_quote_accessors() {
+    let stake_pool = jupsol_stake_pool();
+    let amt = 1_000_000_000;
+
+    let quote = stake_pool
+        .quote(&TradeRequest::WithdrawSolExactIn { pool_tokens: amt }, &CTX)
+        .unwrap();
+    let TradeQuote::WithdrawSol(inner) = quote else {
+        panic!("{quote:?}");
+    };
+    assert_eq!(quote.in_amount(), amt);
+    assert_eq!(quote.out_amount(), inner.out_amount);
+    assert_eq!(quote.total_fee(), inner.manager_fee);
+}
+
+#[test]
+fn test_quote_checks() {
+    let stake_pool = jupsol_stake_pool();
+    let stale = TradeContext {
+        current_epoch: 752,
+        ..CTX
+    };
+    assert_eq!(
+        stake_pool.quote(&TradeRequest::DepositSolExactIn { lamports: 1 }, &stale),
+        Err(SplStakePoolError::StakeListAndPoolOutOfDate)
+    );
+
+    let depleted = TradeContext {
+        reserve_stake_lamports: 0,
+        ..CTX
+    };
+    assert_eq!(
+        stake_pool.quote(
+            &TradeRequest::WithdrawSolExactOut { lamports: 1 },
+            &depleted
+        ),
+        Err(SplStakePoolError::SolWithdrawalTooLarge)
+    );
+
+    let mut validator = ValidatorStakeInfo::DEFAULT;
+    validator.set_active_stake_lamports(MIN_VALIDATOR_STAKE_ACCOUNT_LAMPORTS);
+    assert_eq!(
+        stake_pool.quote(
+            &TradeRequest::WithdrawStakeExactOut {
+                lamports_staked: 1,
+                validator: Some(&validator),
+            },
+            &CTX
+        ),
+        Err(SplStakePoolError::StakeLamportsNotEqualToMinimum)
+    );
+}
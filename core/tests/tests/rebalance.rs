@@ -0,0 +1,132 @@
+use sanctum_spl_stake_pool_core::{
+    rebalance_plan_itr, RebalanceDirection, RebalanceStep, StakeStatus, TransientStakeState,
+    ValidatorList, MIN_VALIDATOR_STAKE_ACCOUNT_LAMPORTS, STAKE_ACCOUNT_RENT_EXEMPT_LAMPORTS,
+};
+
+use crate::common::fixtures::jupsol_validator_list_data;
+
+#[test]
+fn test_rebalance_plan_equal_weights() {
+    let validator_list_data = jupsol_validator_list_data();
+    let validator_list = ValidatorList::deserialize(&validator_list_data).unwrap();
+    let reserve = 4 * STAKE_ACCOUNT_RENT_EXEMPT_LAMPORTS;
+
+    let steps: Vec<_> =
+        rebalance_plan_itr(&validator_list, reserve, 751, |_| 1, |_, _| None).collect();
+    assert_eq!(
+        steps,
+        [
+            RebalanceStep {
+                validator_index: 0,
+                direction: RebalanceDirection::Decrease,
+                lamports: 1568885018326499,
+                transient_stake_seed: 1,
+            },
+            // reserve can only fund a single increase after
+            // paying for the decrease's transient stake account rent
+            RebalanceStep {
+                validator_index: 1,
+                direction: RebalanceDirection::Increase,
+                lamports: STAKE_ACCOUNT_RENT_EXEMPT_LAMPORTS,
+                transient_stake_seed: 1,
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_rebalance_plan_skips_stale_validators() {
+    let validator_list_data = jupsol_validator_list_data();
+    let validator_list = ValidatorList::deserialize(&validator_list_data).unwrap();
+
+    assert_eq!(
+        rebalance_plan_itr(&validator_list, u64::MAX, 752, |_| 1, |_, _| None).count(),
+        0
+    );
+}
+
+#[test]
+fn test_rebalance_plan_zero_weight_decreases_to_min() {
+    let validator_list_data = jupsol_validator_list_data();
+    let validator_list = ValidatorList::deserialize(&validator_list_data).unwrap();
+    let first = *validator_list.validators[0].vote_account_address();
+
+    let steps: Vec<_> = rebalance_plan_itr(
+        &validator_list,
+        2 * STAKE_ACCOUNT_RENT_EXEMPT_LAMPORTS,
+        751,
+        |vote| u64::from(*vote == first),
+        |_, _| None,
+    )
+    .collect();
+    assert_eq!(steps.len(), 1);
+    assert_eq!(steps[0].validator_index, 1);
+    assert_eq!(steps[0].direction, RebalanceDirection::Decrease);
+    assert_eq!(
+        steps[0].lamports,
+        validator_list.validators[1].active_stake_lamports() - MIN_VALIDATOR_STAKE_ACCOUNT_LAMPORTS
+    );
+}
+
+#[test]
+fn test_rebalance_plan_tops_up_transient_stake_in_same_direction() {
+    let validator_list_data = jupsol_validator_list_data();
+    let validator_list = ValidatorList::deserialize(&validator_list_data).unwrap();
+    let mut validators = validator_list.validators.to_vec();
+    validators[1].set_transient_stake_lamports(MIN_VALIDATOR_STAKE_ACCOUNT_LAMPORTS);
+    validators[1].set_transient_seed_suffix(1);
+    let validator_list = ValidatorList {
+        header: validator_list.header,
+        validators: &validators,
+    };
+
+    // large reserve so that every validator is below its target
+    let step_of_1 = |state: Option<TransientStakeState>| {
+        rebalance_plan_itr(&validator_list, u64::MAX, 751, |_| 1, |_, _| state)
+            .find(|step| step.validator_index == 1)
+    };
+
+    let step = step_of_1(Some(TransientStakeState::Activating)).unwrap();
+    assert_eq!(step.direction, RebalanceDirection::Increase);
+    // reuses the existing transient stake account
+    assert_eq!(step.transient_stake_seed, 1);
+
+    for state in [
+        Some(TransientStakeState::Deactivating),
+        Some(TransientStakeState::AwaitingMerge),
+        None,
+    ] {
+        assert_eq!(step_of_1(state), None);
+    }
+}
+
+#[test]
+fn test_rebalance_plan_excludes_non_active_stake_from_total() {
+    let validator_list_data = jupsol_validator_list_data();
+    let validator_list = ValidatorList::deserialize(&validator_list_data).unwrap();
+    let mut validators = validator_list.validators.to_vec();
+    validators[2].set_status(StakeStatus::DeactivatingValidator);
+    let validator_list = ValidatorList {
+        header: validator_list.header,
+        validators: &validators,
+    };
+    let [v0, v1] = [0, 1].map(|i| validators[i].active_stake_lamports());
+
+    let steps: Vec<_> = rebalance_plan_itr(
+        &validator_list,
+        2 * STAKE_ACCOUNT_RENT_EXEMPT_LAMPORTS,
+        751,
+        |_| 1,
+        |_, _| None,
+    )
+    .collect();
+    assert_eq!(
+        steps,
+        [RebalanceStep {
+            validator_index: 0,
+            direction: RebalanceDirection::Decrease,
+            lamports: v0 - (v0 + v1 + STAKE_ACCOUNT_RENT_EXEMPT_LAMPORTS) / 2,
+            transient_stake_seed: 1,
+        }]
+    );
+}
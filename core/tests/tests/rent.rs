@@ -0,0 +1,68 @@
+use sanctum_spl_stake_pool_core::{
+    reserve_has_sufficient_lamports, reserve_has_sufficient_lamports_with_rent, Rent,
+    SplStakePoolError, ValidatorStakeInfo, MIN_ACTIVE_STAKE, STAKE_ACCOUNT_LEN,
+    STAKE_ACCOUNT_RENT_EXEMPT_LAMPORTS,
+};
+
+/// Rent with double mainnet-beta's lamports per byte-year
+const EXPENSIVE_RENT: Rent = Rent {
+    lamports_per_byte_year: 6960,
+    ..Rent::DEFAULT
+};
+
+#[test]
+fn test_default_rent_stake_account_minimum_balance() {
+    assert_eq!(
+        Rent::DEFAULT.minimum_balance(STAKE_ACCOUNT_LEN),
+        STAKE_ACCOUNT_RENT_EXEMPT_LAMPORTS
+    );
+    assert_eq!(Rent::DEFAULT.minimum_balance(0), 890_880);
+}
+
+#[test]
+fn test_rent_sysvar_round_trip() {
+    let mut data = Vec::new();
+    Rent::DEFAULT.borsh_ser(&mut data).unwrap();
+    assert_eq!(data.len(), Rent::ACCOUNT_LEN);
+    assert_eq!(Rent::borsh_de(data.as_slice()).unwrap(), Rent::DEFAULT);
+}
+
+#[test]
+fn test_reserve_has_sufficient_lamports_rent() {
+    let reserve = 3 * STAKE_ACCOUNT_RENT_EXEMPT_LAMPORTS;
+    let out = STAKE_ACCOUNT_RENT_EXEMPT_LAMPORTS;
+    assert!(reserve_has_sufficient_lamports(reserve, out));
+    assert!(reserve_has_sufficient_lamports_with_rent(
+        reserve, out, None
+    ));
+    assert!(reserve_has_sufficient_lamports_with_rent(
+        reserve,
+        out,
+        Some(&Rent::DEFAULT)
+    ));
+    assert!(!reserve_has_sufficient_lamports_with_rent(
+        reserve,
+        out,
+        Some(&EXPENSIVE_RENT)
+    ));
+}
+
+#[test]
+fn test_check_withdraw_stake() {
+    let mut vsi = ValidatorStakeInfo::DEFAULT;
+    vsi.set_active_stake_lamports(1_000_000_000);
+    let max = 1_000_000_000 - STAKE_ACCOUNT_RENT_EXEMPT_LAMPORTS - MIN_ACTIVE_STAKE;
+
+    assert_eq!(vsi.check_withdraw_stake(max, None), Ok(()));
+    assert_eq!(vsi.check_withdraw_stake(max, Some(&Rent::DEFAULT)), Ok(()));
+    for (lamports, rent) in [
+        (max + 1, None),
+        (max, Some(&EXPENSIVE_RENT)),
+        (u64::MAX, None),
+    ] {
+        assert_eq!(
+            vsi.check_withdraw_stake(lamports, rent),
+            Err(SplStakePoolError::StakeLamportsNotEqualToMinimum)
+        );
+    }
+}
@@ -0,0 +1,73 @@
+use sanctum_spl_stake_pool_core::{
+    validators_near_min_stake_itr, PoolHealthReport, ValidatorList, ValidatorStatusCounts,
+    MIN_VALIDATOR_STAKE_ACCOUNT_LAMPORTS, STAKE_ACCOUNT_RENT_EXEMPT_LAMPORTS,
+};
+
+use crate::common::fixtures::{jupsol_stake_pool, jupsol_validator_list_data};
+
+#[test]
+fn test_pool_health_report() {
+    let stake_pool = jupsol_stake_pool();
+    let validator_list_data = jupsol_validator_list_data();
+    let validator_list = ValidatorList::deserialize(&validator_list_data).unwrap();
+    let stakes: Vec<u64> = validator_list
+        .validators
+        .iter()
+        .map(|v| v.active_stake_lamports())
+        .collect();
+    let total_stake: u64 = stakes.iter().sum();
+    let reserve_lamports = 1_000_000_000;
+
+    let report = PoolHealthReport::new(
+        &stake_pool,
+        &validator_list,
+        reserve_lamports + STAKE_ACCOUNT_RENT_EXEMPT_LAMPORTS,
+        2,
+        0,
+    );
+
+    assert_eq!(report.total_lamports, stake_pool.total_lamports);
+    assert_eq!(report.reserve_lamports, reserve_lamports);
+    assert_eq!(report.total_active_stake_lamports, total_stake);
+    assert_eq!(report.total_transient_stake_lamports, 0);
+    assert_eq!(report.top_n, 2);
+    // fixture validators are in descending order of stake
+    assert!(
+        (report.top_n_share - (stakes[0] + stakes[1]) as f64 / total_stake as f64).abs() < 1e-12
+    );
+    let hhi: f64 = stakes
+        .iter()
+        .map(|s| (*s as f64 / total_stake as f64).powi(2))
+        .sum();
+    assert!((report.hhi - hhi).abs() < 1e-12);
+    assert!((1.0 / 3.0..=1.0).contains(&report.hhi));
+    assert_eq!(
+        report.status_counts,
+        ValidatorStatusCounts {
+            active: 3,
+            ..Default::default()
+        }
+    );
+    assert_eq!(report.validators_near_min_stake, 0);
+    assert_eq!(report.withdraw_sol_liquidity_lamports, reserve_lamports);
+    assert_eq!(
+        report.withdraw_stake_liquidity_lamports,
+        total_stake - 3 * MIN_VALIDATOR_STAKE_ACCOUNT_LAMPORTS
+    );
+}
+
+#[test]
+fn test_validators_near_min_stake() {
+    let validator_list_data = jupsol_validator_list_data();
+    let validator_list = ValidatorList::deserialize(&validator_list_data).unwrap();
+    let smallest = validator_list.validators[2].active_stake_lamports();
+
+    assert_eq!(
+        validators_near_min_stake_itr(
+            &validator_list,
+            smallest - MIN_VALIDATOR_STAKE_ACCOUNT_LAMPORTS
+        )
+        .collect::<Vec<_>>(),
+        [2]
+    );
+}
@@ -0,0 +1,49 @@
+use sanctum_spl_stake_pool_core::{
+    DepositSolIxData, DepositSolIxFullKeysOwned, DepositSolIxPrefixKeysOwned,
+    DepositSolIxSuffixKeysOwned, DEPOSIT_SOL_IX_PREFIX_ACCS_LEN, DEPOSIT_SOL_IX_PREFIX_IS_SIGNER,
+    DEPOSIT_SOL_IX_PREFIX_IS_WRITER, SPL_STAKE_POOL_PROGRAM,
+};
+
+#[test]
+fn deposit_sol_to_instruction_matches_flags() {
+    let keys = DepositSolIxPrefixKeysOwned::new(core::array::from_fn(|i| [i as u8; 32]));
+    let data = DepositSolIxData::new(1_000_000_000).to_buf();
+    let ix = keys
+        .as_borrowed()
+        .to_instruction(&SPL_STAKE_POOL_PROGRAM, &data);
+
+    assert_eq!(ix.program_id.to_bytes(), SPL_STAKE_POOL_PROGRAM);
+    assert_eq!(ix.data, data);
+    assert_eq!(ix.accounts.len(), DEPOSIT_SOL_IX_PREFIX_ACCS_LEN);
+    for (i, meta) in ix.accounts.iter().enumerate() {
+        assert_eq!(meta.pubkey.to_bytes(), [i as u8; 32]);
+        assert_eq!(meta.is_signer, DEPOSIT_SOL_IX_PREFIX_IS_SIGNER.0[i]);
+        assert_eq!(meta.is_writable, DEPOSIT_SOL_IX_PREFIX_IS_WRITER.0[i]);
+    }
+}
+
+#[test]
+fn deposit_sol_full_keys_to_instruction_includes_suffix() {
+    let sol_deposit_auth = [255u8; 32];
+    let keys = DepositSolIxFullKeysOwned {
+        prefix: DepositSolIxPrefixKeysOwned::new(core::array::from_fn(|i| [i as u8; 32])),
+        suffix: Some(
+            DepositSolIxSuffixKeysOwned::default().with_sol_deposit_auth(sol_deposit_auth),
+        ),
+    };
+    let data = DepositSolIxData::new(1_000_000_000).to_buf();
+    let ix = keys.to_instruction(&SPL_STAKE_POOL_PROGRAM, &data);
+
+    assert_eq!(ix.program_id.to_bytes(), SPL_STAKE_POOL_PROGRAM);
+    assert_eq!(ix.data, data);
+    assert_eq!(ix.accounts.len(), DEPOSIT_SOL_IX_PREFIX_ACCS_LEN + 1);
+    for (meta, (key, is_signer, is_writable)) in ix.accounts.iter().zip(keys.keys_signer_writer()) {
+        assert_eq!(meta.pubkey.to_bytes(), *key);
+        assert_eq!(meta.is_signer, is_signer);
+        assert_eq!(meta.is_writable, is_writable);
+    }
+    let auth_meta = ix.accounts.last().unwrap();
+    assert_eq!(auth_meta.pubkey.to_bytes(), sol_deposit_auth);
+    assert!(auth_meta.is_signer);
+    assert!(!auth_meta.is_writable);
+}
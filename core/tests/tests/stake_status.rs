@@ -0,0 +1,106 @@
+use sanctum_spl_stake_pool_core::{
+    InvalidStakeStatusTransition, StakeStatus, StakeStatusEvent, ValidatorStakeAccounts,
+};
+
+const NONE: ValidatorStakeAccounts = ValidatorStakeAccounts {
+    validator: false,
+    transient: false,
+};
+
+const VALIDATOR: ValidatorStakeAccounts = ValidatorStakeAccounts {
+    validator: true,
+    transient: false,
+};
+
+const TRANSIENT: ValidatorStakeAccounts = ValidatorStakeAccounts {
+    validator: false,
+    transient: true,
+};
+
+const BOTH: ValidatorStakeAccounts = ValidatorStakeAccounts {
+    validator: true,
+    transient: true,
+};
+
+#[test]
+fn test_remove_with_transient_deactivates_all_then_collapses() {
+    let mut status = StakeStatus::Active;
+    for (event, accounts, expected) in [
+        (
+            StakeStatusEvent::RemoveValidator,
+            BOTH,
+            StakeStatus::DeactivatingAll,
+        ),
+        (StakeStatusEvent::Update, BOTH, StakeStatus::DeactivatingAll),
+        (
+            StakeStatusEvent::Update,
+            TRANSIENT,
+            StakeStatus::DeactivatingTransient,
+        ),
+        (StakeStatusEvent::Update, NONE, StakeStatus::ReadyForRemoval),
+    ] {
+        status = status.transition(event, accounts).unwrap().unwrap();
+        assert_eq!(status, expected);
+        assert!(status.is_consistent_with(accounts));
+    }
+    assert_eq!(status.transition(StakeStatusEvent::Cleanup, NONE), Ok(None));
+}
+
+#[test]
+fn test_remove_without_transient() {
+    assert_eq!(
+        StakeStatus::Active.transition(StakeStatusEvent::RemoveValidator, VALIDATOR),
+        Ok(Some(StakeStatus::DeactivatingValidator))
+    );
+    assert_eq!(
+        StakeStatus::DeactivatingValidator.transition(StakeStatusEvent::Update, NONE),
+        Ok(Some(StakeStatus::ReadyForRemoval))
+    );
+}
+
+#[test]
+fn test_invalid_transitions() {
+    for (from, event, accounts) in [
+        // only active validators can be removed
+        (
+            StakeStatus::DeactivatingValidator,
+            StakeStatusEvent::RemoveValidator,
+            VALIDATOR,
+        ),
+        // active validator must have a validator stake account
+        (StakeStatus::Active, StakeStatusEvent::Update, TRANSIENT),
+        // transient stake account cannot reappear during removal
+        (
+            StakeStatus::DeactivatingValidator,
+            StakeStatusEvent::Update,
+            BOTH,
+        ),
+        // cannot clean up an entry whose stake accounts still exist
+        (
+            StakeStatus::ReadyForRemoval,
+            StakeStatusEvent::Cleanup,
+            VALIDATOR,
+        ),
+    ] {
+        assert_eq!(
+            from.transition(event, accounts),
+            Err(InvalidStakeStatusTransition {
+                from,
+                event,
+                accounts,
+            })
+        );
+    }
+}
+
+#[test]
+fn test_update_and_cleanup_leave_active_unchanged() {
+    for accounts in [VALIDATOR, BOTH] {
+        for event in [StakeStatusEvent::Update, StakeStatusEvent::Cleanup] {
+            assert_eq!(
+                StakeStatus::Active.transition(event, accounts),
+                Ok(Some(StakeStatus::Active))
+            );
+        }
+    }
+}
@@ -0,0 +1,119 @@
+use sanctum_spl_stake_pool_core::{
+    AdditionalValidatorStakeCheckArgs, SplStakePoolError, StakeStatus, TransientStakeState,
+    ValidatorStakeInfo, MIN_ACTIVE_STAKE, STAKE_ACCOUNT_RENT_EXEMPT_LAMPORTS,
+};
+
+const EPOCH: u64 = 100;
+
+fn vsi(transient_stake_lamports: u64) -> ValidatorStakeInfo {
+    let mut vsi = ValidatorStakeInfo::DEFAULT;
+    vsi.set_active_stake_lamports(1_000_000_000_000);
+    vsi.set_transient_stake_lamports(transient_stake_lamports);
+    vsi.set_last_update_epoch(EPOCH);
+    vsi.set_transient_seed_suffix(5);
+    vsi
+}
+
+fn args(transient_stake_state: Option<TransientStakeState>) -> AdditionalValidatorStakeCheckArgs {
+    AdditionalValidatorStakeCheckArgs {
+        lamports: MIN_ACTIVE_STAKE + STAKE_ACCOUNT_RENT_EXEMPT_LAMPORTS,
+        reserve_stake_lamports: 1_000_000_000_000,
+        current_epoch: EPOCH,
+        transient_stake_state,
+    }
+}
+
+#[test]
+fn test_transient_stake_state_from_delegation_epochs() {
+    for (activation, deactivation, expected) in [
+        (EPOCH, u64::MAX, TransientStakeState::Activating),
+        (EPOCH - 1, EPOCH, TransientStakeState::Deactivating),
+        (EPOCH, EPOCH, TransientStakeState::Deactivating),
+        (EPOCH - 1, u64::MAX, TransientStakeState::AwaitingMerge),
+        (EPOCH - 2, EPOCH - 1, TransientStakeState::AwaitingMerge),
+    ] {
+        assert_eq!(
+            TransientStakeState::from_delegation_epochs(activation, deactivation, EPOCH),
+            expected
+        );
+    }
+}
+
+#[test]
+fn test_additional_stake_new_transient_bumps_seed() {
+    let vsi = vsi(0);
+    assert_eq!(vsi.check_increase_additional_stake(&args(None)), Ok(6));
+    assert_eq!(vsi.check_decrease_additional_stake(&args(None)), Ok(6));
+}
+
+#[test]
+fn test_additional_stake_top_up_reuses_seed() {
+    let vsi = vsi(MIN_ACTIVE_STAKE);
+    assert_eq!(
+        vsi.check_increase_additional_stake(&args(Some(TransientStakeState::Activating))),
+        Ok(5)
+    );
+    assert_eq!(
+        vsi.check_decrease_additional_stake(&args(Some(TransientStakeState::Deactivating))),
+        Ok(5)
+    );
+}
+
+#[test]
+fn test_additional_stake_rejections() {
+    let vsi_with_transient = vsi(MIN_ACTIVE_STAKE);
+    assert_eq!(
+        vsi_with_transient
+            .check_increase_additional_stake(&args(Some(TransientStakeState::Deactivating))),
+        Err(SplStakePoolError::WrongStakeStake)
+    );
+    assert_eq!(
+        vsi_with_transient
+            .check_decrease_additional_stake(&args(Some(TransientStakeState::AwaitingMerge))),
+        Err(SplStakePoolError::TransientAccountInUse)
+    );
+
+    let vsi = vsi(0);
+    let stale = AdditionalValidatorStakeCheckArgs {
+        current_epoch: EPOCH + 1,
+        ..args(None)
+    };
+    assert_eq!(
+        vsi.check_increase_additional_stake(&stale),
+        Err(SplStakePoolError::StakeListAndPoolOutOfDate)
+    );
+
+    let small = AdditionalValidatorStakeCheckArgs {
+        lamports: MIN_ACTIVE_STAKE - 1,
+        ..args(None)
+    };
+    assert_eq!(
+        vsi.check_increase_additional_stake(&small),
+        Err(SplStakePoolError::StakeLamportsNotEqualToMinimum)
+    );
+
+    let depleted = AdditionalValidatorStakeCheckArgs {
+        reserve_stake_lamports: 2 * STAKE_ACCOUNT_RENT_EXEMPT_LAMPORTS,
+        ..args(None)
+    };
+    assert_eq!(
+        vsi.check_increase_additional_stake(&depleted),
+        Err(SplStakePoolError::ReserveDepleted)
+    );
+
+    let too_much = AdditionalValidatorStakeCheckArgs {
+        lamports: vsi.active_stake_lamports(),
+        ..args(None)
+    };
+    assert_eq!(
+        vsi.check_decrease_additional_stake(&too_much),
+        Err(SplStakePoolError::InvalidState)
+    );
+
+    let mut removing = vsi;
+    removing.set_status(StakeStatus::DeactivatingValidator);
+    assert_eq!(
+        removing.check_decrease_additional_stake(&args(None)),
+        Err(SplStakePoolError::ValidatorNotFound)
+    );
+}
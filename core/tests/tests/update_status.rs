@@ -0,0 +1,41 @@
+use std::num::NonZeroUsize;
+
+use sanctum_spl_stake_pool_core::{UpdateStatus, ValidatorList};
+
+use crate::common::fixtures::{jupsol_stake_pool, jupsol_validator_list_data};
+
+#[test]
+fn test_update_status() {
+    let stake_pool = jupsol_stake_pool();
+    let validator_list_data = jupsol_validator_list_data();
+    let validator_list = ValidatorList::deserialize(&validator_list_data).unwrap();
+    let max = NonZeroUsize::new(2).unwrap();
+
+    // fixtures were fetched after the pool was updated for epoch 751
+    let updated = UpdateStatus::new(&stake_pool, &validator_list, 751, max);
+    assert!(!updated.is_update_required());
+    assert_eq!(updated, UpdateStatus::default());
+    assert_eq!(validator_list.stale_validator_indices_itr(751).count(), 0);
+
+    let stale = UpdateStatus::new(&stake_pool, &validator_list, 752, max);
+    assert_eq!(
+        stale,
+        UpdateStatus {
+            stake_pool_stale: true,
+            stale_validators: 3,
+            cleanup_required: false,
+            update_validator_list_balance_ixs: 2,
+            ixs: 3,
+            txs: 3,
+        }
+    );
+    // no entry is ready for removal, so no CleanupRemovedValidatorEntries
+    assert!(stale.is_update_stake_pool_balance_required());
+    assert!(!updated.is_update_stake_pool_balance_required());
+    assert_eq!(
+        validator_list
+            .stale_validator_indices_itr(752)
+            .collect::<Vec<_>>(),
+        [0, 1, 2]
+    );
+}
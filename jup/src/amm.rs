@@ -0,0 +1,123 @@
+//! Types mirroring the shape of jupiter-amm-interface's `Amm` trait,
+//! using plain `[u8; 32]` pubkeys so that this crate does not depend on solana crates.
+
+use std::{collections::HashMap, error::Error, fmt::Display};
+
+use sanctum_spl_stake_pool_core::SplStakePoolError;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Account {
+    pub lamports: u64,
+    pub owner: [u8; 32],
+    pub data: Vec<u8>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct KeyedAccount {
+    pub key: [u8; 32],
+    pub account: Account,
+}
+
+pub type AccountMap = HashMap<[u8; 32], Account>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct QuoteParams {
+    /// Exact amount of `input_mint` in
+    pub amount: u64,
+    pub input_mint: [u8; 32],
+    pub output_mint: [u8; 32],
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Quote {
+    pub in_amount: u64,
+    pub out_amount: u64,
+    pub fee_amount: u64,
+    pub fee_mint: [u8; 32],
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SwapParams {
+    /// Exact amount of `source_mint` in
+    pub in_amount: u64,
+    pub source_mint: [u8; 32],
+    pub destination_mint: [u8; 32],
+    pub source_token_account: [u8; 32],
+    pub destination_token_account: [u8; 32],
+
+    /// Signer that owns `source_token_account`.
+    ///
+    /// Also the system account that SOL is transferred from/to
+    pub token_transfer_authority: [u8; 32],
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AccountMeta {
+    pub pubkey: [u8; 32],
+    pub is_signer: bool,
+    pub is_writable: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SwapAndAccountMetas {
+    pub program_id: [u8; 32],
+    pub data: Vec<u8>,
+    pub account_metas: Vec<AccountMeta>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AmmError {
+    /// Account required for the operation was not in the `AccountMap`
+    MissingAccount([u8; 32]),
+
+    /// Account data could not be deserialized
+    InvalidAccountData([u8; 32]),
+
+    /// Mint pair is not serviced by this AMM
+    UnsupportedMints,
+
+    StakePool(SplStakePoolError),
+}
+
+impl Display for AmmError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::StakePool(e) => write!(f, "{e}"),
+            _ => write!(f, "{self:?}"),
+        }
+    }
+}
+
+impl Error for AmmError {}
+
+impl From<SplStakePoolError> for AmmError {
+    #[inline]
+    fn from(value: SplStakePoolError) -> Self {
+        Self::StakePool(value)
+    }
+}
+
+pub trait Amm: Sized {
+    fn from_keyed_account(keyed_account: &KeyedAccount) -> Result<Self, AmmError>;
+
+    fn label(&self) -> &'static str;
+
+    fn program_id(&self) -> [u8; 32];
+
+    /// Address that uniquely identifies this AMM
+    fn key(&self) -> [u8; 32];
+
+    fn get_reserve_mints(&self) -> Vec<[u8; 32]>;
+
+    /// Accounts that must be in the `AccountMap` passed to [`Amm::update`]
+    fn get_accounts_to_update(&self) -> Vec<[u8; 32]>;
+
+    fn update(&mut self, account_map: &AccountMap) -> Result<(), AmmError>;
+
+    fn quote(&self, quote_params: &QuoteParams) -> Result<Quote, AmmError>;
+
+    fn get_swap_and_account_metas(
+        &self,
+        swap_params: &SwapParams,
+    ) -> Result<SwapAndAccountMetas, AmmError>;
+}
@@ -0,0 +1,5 @@
+use const_crypto::bs58;
+
+/// Wrapped SOL mint, used to represent SOL in [`crate::QuoteParams`] and [`crate::SwapParams`]
+pub const NATIVE_MINT: [u8; 32] =
+    bs58::decode_pubkey("So11111111111111111111111111111111111111112");
@@ -0,0 +1,10 @@
+//! Adapter exposing SPL stake pools in the shape of jupiter-amm-interface's `Amm` trait,
+//! for routers that integrate pools as SOL <-> LST swaps.
+
+mod amm;
+mod consts;
+mod stake_pool;
+
+pub use amm::*;
+pub use consts::*;
+pub use stake_pool::*;
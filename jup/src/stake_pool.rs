@@ -0,0 +1,282 @@
+use sanctum_spl_stake_pool_core::{
+    withdraw_auth_seeds, AccountType, Clock, DepositSolIxData, DepositSolIxPrefixKeysOwned,
+    DepositSolQuoteArgs, Rent, SplStakePoolError, StakePool, ValidatorList, ValidatorListHeader,
+    ValidatorStakeInfo, WithdrawSolIxData, WithdrawSolIxPrefixKeysOwned, WithdrawSolQuoteArgs,
+    DEPOSIT_SOL_IX_PREFIX_IS_SIGNER, DEPOSIT_SOL_IX_PREFIX_IS_WRITER, SYSVAR_CLOCK, SYSVAR_RENT,
+    WITHDRAW_SOL_IX_PREFIX_IS_SIGNER, WITHDRAW_SOL_IX_PREFIX_IS_WRITER,
+};
+
+use crate::{
+    Account, AccountMap, AccountMeta, Amm, AmmError, KeyedAccount, Quote, QuoteParams,
+    SwapAndAccountMetas, SwapParams, NATIVE_MINT,
+};
+
+/// Pubkey passed to the quote checks in place of the user,
+/// since the user is not known at quote time.
+///
+/// This means pools with a SOL deposit or withdraw authority are not serviceable.
+const UNKNOWN_USER: [u8; 32] = [0u8; 32];
+
+const PDA_MARKER: &[u8; 21] = b"ProgramDerivedAddress";
+
+/// Swaps between SOL and an SPL stake pool's LST:
+///
+/// - SOL -> LST via DepositSol
+/// - LST -> SOL via WithdrawSol
+///
+/// The SOL side of the swap is the native lamports of
+/// [`SwapParams::token_transfer_authority`], not a wrapped SOL token account.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SplStakePoolAmm {
+    program_id: [u8; 32],
+    key: [u8; 32],
+    withdraw_auth: [u8; 32],
+    stake_pool: StakePool,
+    validator_list_header: ValidatorListHeader,
+    validators: Vec<ValidatorStakeInfo>,
+    reserve_stake_lamports: u64,
+    current_epoch: u64,
+    rent: Rent,
+}
+
+impl SplStakePoolAmm {
+    #[inline]
+    pub fn stake_pool(&self) -> &StakePool {
+        &self.stake_pool
+    }
+
+    #[inline]
+    pub fn validator_list(&self) -> ValidatorList<'_> {
+        ValidatorList {
+            header: self.validator_list_header,
+            validators: &self.validators,
+        }
+    }
+
+    #[inline]
+    pub const fn withdraw_auth(&self) -> &[u8; 32] {
+        &self.withdraw_auth
+    }
+
+    /// Whether the pool and all its validators have been updated for the current epoch,
+    /// which is required for any deposit or withdrawal to succeed
+    #[inline]
+    pub fn is_active(&self) -> bool {
+        self.stake_pool.is_updated_for_epoch(self.current_epoch)
+            && self
+                .validator_list()
+                .stale_validator_indices_itr(self.current_epoch)
+                .next()
+                .is_none()
+    }
+
+    fn deposit_sol_ix(&self, swap_params: &SwapParams) -> Result<SwapAndAccountMetas, AmmError> {
+        if self.stake_pool.sol_deposit_authority.is_some() {
+            return Err(SplStakePoolError::InvalidSolDepositAuthority.into());
+        }
+        let keys = DepositSolIxPrefixKeysOwned::default()
+            .with_keys_from_stake_pool(&self.stake_pool)
+            .with_consts()
+            .with_stake_pool(self.key)
+            .with_withdraw_auth(self.withdraw_auth)
+            .with_from_user_lamports(swap_params.token_transfer_authority)
+            .with_dest_user_pool(swap_params.destination_token_account)
+            // no referrer, so referral fees go back to the user
+            .with_referrer_fee(swap_params.destination_token_account);
+        Ok(SwapAndAccountMetas {
+            program_id: self.program_id,
+            data: DepositSolIxData::new(swap_params.in_amount).to_buf().into(),
+            account_metas: to_account_metas(
+                &keys.0,
+                &DEPOSIT_SOL_IX_PREFIX_IS_SIGNER.0,
+                &DEPOSIT_SOL_IX_PREFIX_IS_WRITER.0,
+            ),
+        })
+    }
+
+    fn withdraw_sol_ix(&self, swap_params: &SwapParams) -> Result<SwapAndAccountMetas, AmmError> {
+        if self.stake_pool.sol_withdraw_authority.is_some() {
+            return Err(SplStakePoolError::InvalidSolWithdrawAuthority.into());
+        }
+        let keys = WithdrawSolIxPrefixKeysOwned::default()
+            .with_keys_from_stake_pool(&self.stake_pool)
+            .with_consts()
+            .with_stake_pool(self.key)
+            .with_withdraw_auth(self.withdraw_auth)
+            .with_user_transfer_auth(swap_params.token_transfer_authority)
+            .with_pool_tokens_from(swap_params.source_token_account)
+            .with_lamports_to(swap_params.token_transfer_authority);
+        Ok(SwapAndAccountMetas {
+            program_id: self.program_id,
+            data: WithdrawSolIxData::new(swap_params.in_amount)
+                .to_buf()
+                .into(),
+            account_metas: to_account_metas(
+                &keys.0,
+                &WITHDRAW_SOL_IX_PREFIX_IS_SIGNER.0,
+                &WITHDRAW_SOL_IX_PREFIX_IS_WRITER.0,
+            ),
+        })
+    }
+}
+
+impl Amm for SplStakePoolAmm {
+    fn from_keyed_account(KeyedAccount { key, account }: &KeyedAccount) -> Result<Self, AmmError> {
+        let stake_pool = deser_stake_pool(key, account)?;
+        let withdraw_auth =
+            create_withdraw_auth(&account.owner, key, stake_pool.stake_withdraw_bump_seed);
+        Ok(Self {
+            program_id: account.owner,
+            key: *key,
+            withdraw_auth,
+            stake_pool,
+            validator_list_header: ValidatorListHeader::default(),
+            validators: Vec::new(),
+            reserve_stake_lamports: 0,
+            current_epoch: 0,
+            rent: Rent::DEFAULT,
+        })
+    }
+
+    fn label(&self) -> &'static str {
+        "SPL Stake Pool"
+    }
+
+    fn program_id(&self) -> [u8; 32] {
+        self.program_id
+    }
+
+    fn key(&self) -> [u8; 32] {
+        self.key
+    }
+
+    fn get_reserve_mints(&self) -> Vec<[u8; 32]> {
+        vec![NATIVE_MINT, self.stake_pool.pool_mint]
+    }
+
+    fn get_accounts_to_update(&self) -> Vec<[u8; 32]> {
+        vec![
+            self.key,
+            self.stake_pool.validator_list,
+            self.stake_pool.reserve_stake,
+            SYSVAR_CLOCK,
+            SYSVAR_RENT,
+        ]
+    }
+
+    fn update(&mut self, account_map: &AccountMap) -> Result<(), AmmError> {
+        let get = |pk: &[u8; 32]| account_map.get(pk).ok_or(AmmError::MissingAccount(*pk));
+
+        let stake_pool = deser_stake_pool(&self.key, get(&self.key)?)?;
+
+        let validator_list_addr = stake_pool.validator_list;
+        let validator_list = ValidatorList::deserialize(&get(&validator_list_addr)?.data)
+            .map_err(|_e| AmmError::InvalidAccountData(validator_list_addr))?;
+
+        let reserve_stake_lamports = get(&stake_pool.reserve_stake)?.lamports;
+
+        let clock = Clock::borsh_de(get(&SYSVAR_CLOCK)?.data.as_slice())
+            .map_err(|_e| AmmError::InvalidAccountData(SYSVAR_CLOCK))?;
+
+        let rent = Rent::borsh_de(get(&SYSVAR_RENT)?.data.as_slice())
+            .map_err(|_e| AmmError::InvalidAccountData(SYSVAR_RENT))?;
+
+        self.validator_list_header = validator_list.header;
+        self.validators = validator_list.validators.to_vec();
+        self.stake_pool = stake_pool;
+        self.reserve_stake_lamports = reserve_stake_lamports;
+        self.current_epoch = clock.epoch;
+        self.rent = rent;
+        Ok(())
+    }
+
+    fn quote(
+        &self,
+        QuoteParams {
+            amount,
+            input_mint,
+            output_mint,
+        }: &QuoteParams,
+    ) -> Result<Quote, AmmError> {
+        let pool_mint = self.stake_pool.pool_mint;
+        if *input_mint == NATIVE_MINT && *output_mint == pool_mint {
+            let quote = self.stake_pool.quote_deposit_sol(
+                *amount,
+                &DepositSolQuoteArgs {
+                    depositor: &UNKNOWN_USER,
+                    current_epoch: self.current_epoch,
+                },
+            )?;
+            // the referral fee is minted to the user's destination token account too,
+            // see `Self::deposit_sol_ix`, so only the manager fee is lost to fees
+            Ok(Quote {
+                in_amount: quote.in_amount,
+                out_amount: quote.out_amount + quote.referral_fee,
+                fee_amount: quote.manager_fee,
+                fee_mint: pool_mint,
+            })
+        } else if *input_mint == pool_mint && *output_mint == NATIVE_MINT {
+            let quote = self.stake_pool.quote_withdraw_sol(
+                *amount,
+                &WithdrawSolQuoteArgs {
+                    withdrawer: &UNKNOWN_USER,
+                    reserve_stake_lamports: self.reserve_stake_lamports,
+                    current_epoch: self.current_epoch,
+                    rent: Some(&self.rent),
+                },
+            )?;
+            Ok(Quote {
+                in_amount: quote.in_amount,
+                out_amount: quote.out_amount,
+                fee_amount: quote.manager_fee,
+                fee_mint: pool_mint,
+            })
+        } else {
+            Err(AmmError::UnsupportedMints)
+        }
+    }
+
+    fn get_swap_and_account_metas(
+        &self,
+        swap_params: &SwapParams,
+    ) -> Result<SwapAndAccountMetas, AmmError> {
+        let pool_mint = self.stake_pool.pool_mint;
+        match (swap_params.source_mint, swap_params.destination_mint) {
+            (NATIVE_MINT, dst) if dst == pool_mint => self.deposit_sol_ix(swap_params),
+            (src, NATIVE_MINT) if src == pool_mint => self.withdraw_sol_ix(swap_params),
+            _ => Err(AmmError::UnsupportedMints),
+        }
+    }
+}
+
+fn deser_stake_pool(key: &[u8; 32], account: &Account) -> Result<StakePool, AmmError> {
+    match StakePool::borsh_de(account.data.as_slice()) {
+        Ok(pool) if pool.account_type == AccountType::StakePool => Ok(pool),
+        _ => Err(AmmError::InvalidAccountData(*key)),
+    }
+}
+
+/// The bump seed stored in the stake pool has already been validated by the program,
+/// so there is no need to check that the resulting address is off-curve
+fn create_withdraw_auth(program_id: &[u8; 32], stake_pool: &[u8; 32], bump: u8) -> [u8; 32] {
+    let (pool, seed) = withdraw_auth_seeds(stake_pool);
+    let mut hasher = hmac_sha256::Hash::new();
+    hasher.update(pool);
+    hasher.update(seed);
+    hasher.update([bump]);
+    hasher.update(program_id);
+    hasher.update(PDA_MARKER);
+    hasher.finalize()
+}
+
+fn to_account_metas(keys: &[[u8; 32]], is_signer: &[bool], is_writer: &[bool]) -> Vec<AccountMeta> {
+    keys.iter()
+        .zip(is_signer)
+        .zip(is_writer)
+        .map(|((pubkey, is_signer), is_writable)| AccountMeta {
+            pubkey: *pubkey,
+            is_signer: *is_signer,
+            is_writable: *is_writable,
+        })
+        .collect()
+}
@@ -0,0 +1,280 @@
+use const_crypto::bs58;
+use data_encoding::BASE64;
+use sanctum_spl_stake_pool_core::{
+    Clock, Fee, Rent, SplStakePoolError, StakePool, WithdrawSolQuoteArgs, SYSTEM_PROGRAM,
+    SYSVAR_CLOCK, SYSVAR_RENT,
+};
+use sanctum_spl_stake_pool_jup::{
+    Account, AccountMap, Amm, AmmError, KeyedAccount, QuoteParams, SplStakePoolAmm, SwapParams,
+    NATIVE_MINT,
+};
+
+const PICOSOL_STAKE_POOL: &[u8] = include_bytes!("../../test-fixtures/picosol-stake-pool.json");
+const PICOSOL_VALIDATOR_LIST: &[u8] =
+    include_bytes!("../../test-fixtures/picosol-validator-list.json");
+const PICOSOL_RESERVE: &[u8] = include_bytes!("../../test-fixtures/picosol-reserve-account.json");
+const BPSOL_STAKE_POOL: &[u8] = include_bytes!("../../test-fixtures/bpsol-stake-pool.json");
+
+const PICOSOL_WITHDRAW_AUTH: [u8; 32] =
+    bs58::decode_pubkey("4At8nQXanWgRvjbrVXmxMBBdfz39txWVm4SiXEoP1kGh");
+
+const USER: [u8; 32] = [1u8; 32];
+const USER_PICOSOL: [u8; 32] = [2u8; 32];
+
+/// Parses a `solana account --output json` fixture
+fn fixture_keyed_account(account_json: &[u8]) -> KeyedAccount {
+    let json: serde_json::Value = serde_json::from_slice(account_json).unwrap();
+    let account = &json["account"];
+    KeyedAccount {
+        key: bs58::decode_pubkey(json["pubkey"].as_str().unwrap()),
+        account: Account {
+            lamports: account["lamports"].as_u64().unwrap(),
+            owner: bs58::decode_pubkey(account["owner"].as_str().unwrap()),
+            data: BASE64
+                .decode(account["data"][0].as_str().unwrap().as_bytes())
+                .unwrap(),
+        },
+    }
+}
+
+fn clock_account(epoch: u64) -> Account {
+    let mut data = Vec::new();
+    Clock {
+        epoch,
+        ..Default::default()
+    }
+    .borsh_ser(&mut data)
+    .unwrap();
+    Account {
+        data,
+        ..Default::default()
+    }
+}
+
+fn rent_account() -> Account {
+    let mut data = Vec::new();
+    Rent::DEFAULT.borsh_ser(&mut data).unwrap();
+    Account {
+        data,
+        ..Default::default()
+    }
+}
+
+fn picosol_amm(epoch: u64) -> SplStakePoolAmm {
+    picosol_amm_with(epoch, |_| {})
+}
+
+/// picoSOL AMM with the stake pool fixture modified by `modify`
+fn picosol_amm_with(epoch: u64, modify: impl FnOnce(&mut StakePool)) -> SplStakePoolAmm {
+    let mut pool = fixture_keyed_account(PICOSOL_STAKE_POOL);
+    let mut stake_pool = StakePool::borsh_de(pool.account.data.as_slice()).unwrap();
+    modify(&mut stake_pool);
+    let mut data = Vec::new();
+    stake_pool.borsh_ser(&mut data).unwrap();
+    pool.account.data[..data.len()].copy_from_slice(&data);
+
+    let mut amm = SplStakePoolAmm::from_keyed_account(&pool).unwrap();
+    let account_map: AccountMap = [
+        pool,
+        fixture_keyed_account(PICOSOL_VALIDATOR_LIST),
+        fixture_keyed_account(PICOSOL_RESERVE),
+    ]
+    .into_iter()
+    .map(|KeyedAccount { key, account }| (key, account))
+    .chain([
+        (SYSVAR_CLOCK, clock_account(epoch)),
+        (SYSVAR_RENT, rent_account()),
+    ])
+    .collect();
+    assert_eq!(amm.get_accounts_to_update().len(), account_map.len());
+    amm.update(&account_map).unwrap();
+    amm
+}
+
+#[test]
+fn test_picosol_accounts() {
+    let amm = picosol_amm(1);
+    let pool = amm.stake_pool();
+    assert!(amm.is_active());
+    assert_eq!(*amm.withdraw_auth(), PICOSOL_WITHDRAW_AUTH);
+    assert_eq!(
+        amm.get_accounts_to_update(),
+        [
+            amm.key(),
+            pool.validator_list,
+            pool.reserve_stake,
+            SYSVAR_CLOCK,
+            SYSVAR_RENT
+        ]
+    );
+    assert_eq!(amm.get_reserve_mints(), [NATIVE_MINT, pool.pool_mint]);
+    assert_eq!(amm.validator_list().validators.len(), 1);
+}
+
+#[test]
+fn test_picosol_deposit_sol() {
+    let amm = picosol_amm_with(1, |pool| {
+        pool.sol_deposit_fee = Fee {
+            denominator: 1_000,
+            numerator: 3,
+        };
+        pool.sol_referral_fee = 50;
+    });
+    let pool = amm.stake_pool();
+    let amount = 1_000_000_000;
+
+    let quote = amm
+        .quote(&QuoteParams {
+            amount,
+            input_mint: NATIVE_MINT,
+            output_mint: pool.pool_mint,
+        })
+        .unwrap();
+
+    // amounts the program mints, with the user's destination token account as the referrer
+    let minted =
+        u128::from(amount) * u128::from(pool.pool_token_supply) / u128::from(pool.total_lamports);
+    let deposit_fee = (minted * 3).div_ceil(1_000);
+    let referral_fee = deposit_fee * 50 / 100;
+    let minted_to_user = u64::try_from(minted - deposit_fee + referral_fee).unwrap();
+    let minted_to_manager = u64::try_from(deposit_fee - referral_fee).unwrap();
+    assert!(referral_fee > 0);
+
+    assert_eq!(quote.in_amount, amount);
+    assert_eq!(quote.out_amount, minted_to_user);
+    assert_eq!(quote.fee_amount, minted_to_manager);
+    assert_eq!(quote.fee_mint, pool.pool_mint);
+
+    let swap = amm
+        .get_swap_and_account_metas(&SwapParams {
+            in_amount: amount,
+            source_mint: NATIVE_MINT,
+            destination_mint: pool.pool_mint,
+            source_token_account: USER,
+            destination_token_account: USER_PICOSOL,
+            token_transfer_authority: USER,
+        })
+        .unwrap();
+    assert_eq!(swap.program_id, amm.program_id());
+    assert_eq!(swap.data[1..], amount.to_le_bytes());
+    let keys: Vec<_> = swap.account_metas.iter().map(|m| m.pubkey).collect();
+    assert_eq!(
+        keys,
+        [
+            amm.key(),
+            PICOSOL_WITHDRAW_AUTH,
+            pool.reserve_stake,
+            USER,
+            USER_PICOSOL,
+            pool.manager_fee_account,
+            USER_PICOSOL,
+            pool.pool_mint,
+            SYSTEM_PROGRAM,
+            pool.token_program_id,
+        ]
+    );
+    let signers: Vec<_> = swap
+        .account_metas
+        .iter()
+        .filter(|m| m.is_signer)
+        .map(|m| m.pubkey)
+        .collect();
+    assert_eq!(signers, [USER]);
+}
+
+#[test]
+fn test_picosol_withdraw_sol() {
+    let amm = picosol_amm(1);
+    let pool = amm.stake_pool();
+    let amount = 1_000_000;
+
+    let quote = amm
+        .quote(&QuoteParams {
+            amount,
+            input_mint: pool.pool_mint,
+            output_mint: NATIVE_MINT,
+        })
+        .unwrap();
+    let expected = pool
+        .quote_withdraw_sol(
+            amount,
+            &WithdrawSolQuoteArgs {
+                withdrawer: &USER,
+                reserve_stake_lamports: fixture_keyed_account(PICOSOL_RESERVE).account.lamports,
+                current_epoch: 1,
+                rent: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(quote.in_amount, amount);
+    assert_eq!(quote.out_amount, expected.out_amount);
+    assert_eq!(quote.fee_amount, expected.manager_fee);
+
+    let swap = amm
+        .get_swap_and_account_metas(&SwapParams {
+            in_amount: amount,
+            source_mint: pool.pool_mint,
+            destination_mint: NATIVE_MINT,
+            source_token_account: USER_PICOSOL,
+            destination_token_account: USER,
+            token_transfer_authority: USER,
+        })
+        .unwrap();
+    assert_eq!(swap.data[1..], amount.to_le_bytes());
+    assert_eq!(swap.account_metas[1].pubkey, PICOSOL_WITHDRAW_AUTH);
+    assert_eq!(swap.account_metas[3].pubkey, USER_PICOSOL);
+    assert_eq!(swap.account_metas[5].pubkey, USER);
+}
+
+#[test]
+fn test_picosol_withdraw_sol_exceeds_reserve() {
+    let amm = picosol_amm(1);
+    let res = amm.quote(&QuoteParams {
+        amount: amm.stake_pool().pool_token_supply,
+        input_mint: amm.stake_pool().pool_mint,
+        output_mint: NATIVE_MINT,
+    });
+    assert_eq!(
+        res,
+        Err(AmmError::StakePool(
+            SplStakePoolError::SolWithdrawalTooLarge
+        ))
+    );
+}
+
+#[test]
+fn test_picosol_out_of_date() {
+    let amm = picosol_amm(2);
+    assert!(!amm.is_active());
+    let res = amm.quote(&QuoteParams {
+        amount: 1_000_000_000,
+        input_mint: NATIVE_MINT,
+        output_mint: amm.stake_pool().pool_mint,
+    });
+    assert_eq!(
+        res,
+        Err(AmmError::StakePool(
+            SplStakePoolError::StakeListAndPoolOutOfDate
+        ))
+    );
+}
+
+#[test]
+fn test_unsupported_mints() {
+    let amm = picosol_amm(1);
+    let res = amm.quote(&QuoteParams {
+        amount: 1,
+        input_mint: NATIVE_MINT,
+        output_mint: NATIVE_MINT,
+    });
+    assert_eq!(res, Err(AmmError::UnsupportedMints));
+}
+
+#[test]
+fn test_bpsol_uninitialized_pool() {
+    let pool = fixture_keyed_account(BPSOL_STAKE_POOL);
+    assert_eq!(
+        SplStakePoolAmm::from_keyed_account(&pool),
+        Err(AmmError::InvalidAccountData(pool.key))
+    );
+}
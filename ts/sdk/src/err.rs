@@ -11,3 +11,23 @@ pub fn no_valid_pda() -> JsError {
 pub fn validator_idx_oob() -> JsError {
     JsError::new(intern("validator index out of bounds"))
 }
+
+pub fn not_delegated_stake_account() -> JsError {
+    JsError::new(intern("not a delegated stake account"))
+}
+
+pub fn not_stake_account_with_lockup() -> JsError {
+    JsError::new(intern("not an initialized or delegated stake account"))
+}
+
+pub fn invalid_base58() -> JsError {
+    JsError::new(intern("invalid base58"))
+}
+
+pub fn invalid_epoch_schedule() -> JsError {
+    JsError::new(intern("invalid epoch schedule"))
+}
+
+pub fn invalid_stake_status_transition() -> JsError {
+    JsError::new(intern("invalid stake status transition"))
+}
@@ -0,0 +1,91 @@
+use serde::{Deserialize, Serialize};
+use tsify_next::Tsify;
+use wasm_bindgen::prelude::*;
+
+use sanctum_spl_stake_pool_core as stake_pool_sdk;
+
+use crate::{err::invalid_base58, B58PK};
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Tsify)]
+#[tsify(into_wasm_abi, from_wasm_abi, large_number_types_as_bigints)]
+#[serde(rename_all = "camelCase")]
+pub struct MemcmpFilterParams {
+    pub offset: u64,
+
+    /// base58-encoded bytes
+    pub bytes: String,
+
+    #[tsify(type = "\"base58\"")]
+    pub encoding: String,
+}
+
+/// Same shape as `@solana/kit`'s `GetProgramAccountsMemcmpFilter`
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Tsify)]
+#[tsify(into_wasm_abi, from_wasm_abi, large_number_types_as_bigints)]
+#[serde(rename_all = "camelCase")]
+pub struct MemcmpFilter {
+    pub memcmp: MemcmpFilterParams,
+}
+
+impl MemcmpFilter {
+    pub(crate) fn from_core(
+        stake_pool_sdk::MemcmpFilter { offset, bytes }: &stake_pool_sdk::MemcmpFilter,
+    ) -> Self {
+        Self {
+            memcmp: MemcmpFilterParams {
+                offset: *offset as u64,
+                bytes: bs58::encode(bytes).into_string(),
+                encoding: "base58".to_owned(),
+            },
+        }
+    }
+}
+
+/// getProgramAccounts filters for all stake pools whose LST is `poolMint`
+#[wasm_bindgen(js_name = stakePoolsWithMintFilters)]
+pub fn stake_pools_with_mint_filters(pool_mint: B58PK) -> Vec<MemcmpFilter> {
+    stake_pool_sdk::stake_pools_with_mint_filters(&pool_mint.0)
+        .iter()
+        .map(MemcmpFilter::from_core)
+        .collect()
+}
+
+/// getProgramAccounts filters for all stake pools whose manager is `manager`
+#[wasm_bindgen(js_name = stakePoolsManagedByFilters)]
+pub fn stake_pools_managed_by_filters(manager: B58PK) -> Vec<MemcmpFilter> {
+    stake_pool_sdk::stake_pools_managed_by_filters(&manager.0)
+        .iter()
+        .map(MemcmpFilter::from_core)
+        .collect()
+}
+
+/// getProgramAccounts filters for all stake pools whose staker is `staker`
+#[wasm_bindgen(js_name = stakePoolsStakedByFilters)]
+pub fn stake_pools_staked_by_filters(staker: B58PK) -> Vec<MemcmpFilter> {
+    stake_pool_sdk::stake_pools_staked_by_filters(&staker.0)
+        .iter()
+        .map(MemcmpFilter::from_core)
+        .collect()
+}
+
+/// Cheaply checks whether `data` is a stake pool account matching `filters`
+/// by only reading its fixed-position prefix, before a full `deserStakePool()`
+///
+/// @throws if `filters` contains a filter whose `bytes` is not valid base58
+#[wasm_bindgen(js_name = stakePoolPrefixMatches)]
+pub fn stake_pool_prefix_matches(data: &[u8], filters: Vec<MemcmpFilter>) -> Result<bool, JsError> {
+    let decoded = filters
+        .into_iter()
+        .map(|MemcmpFilter { memcmp }| {
+            bs58::decode(&memcmp.bytes)
+                .into_vec()
+                .map(|bytes| (memcmp.offset as usize, bytes))
+                .map_err(|_| invalid_base58())
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    let filters: Vec<_> = decoded
+        .iter()
+        .map(|(offset, bytes)| stake_pool_sdk::MemcmpFilter::new(*offset, bytes))
+        .collect();
+    Ok(stake_pool_sdk::stake_pool_prefix_matches(data, &filters))
+}
@@ -11,7 +11,7 @@ use crate::{
     find_validator_stake_account_pda_internal, find_withdraw_auth_pda_internal,
     utils::{keys_signer_writer_to_account_metas, AccountMeta},
     AdditionalValidatorStakeIxArgs, AdditionalValidatorStakeIxUserAddrs, Instruction,
-    StakePoolHandle,
+    StakePoolDeployment, StakePoolHandle,
 };
 
 /// @throws if
@@ -20,9 +20,11 @@ use crate::{
 #[wasm_bindgen(js_name = increaseAdditionalValidatorStakeIxFromStakePool)]
 pub fn increase_additional_validator_stake_ix_from_stake_pool(
     AdditionalValidatorStakeIxUserAddrs {
-        program,
+        deployment: StakePoolDeployment {
+            program,
+            stake_pool,
+        },
         vote_account,
-        stake_pool,
     }: AdditionalValidatorStakeIxUserAddrs,
     stake_pool_handle: &StakePoolHandle,
     args: AdditionalValidatorStakeIxArgs,
@@ -1,13 +1,15 @@
 use serde::{Deserialize, Serialize};
 use tsify_next::Tsify;
 
-use crate::B58PK;
+use crate::{StakePoolDeployment, B58PK};
 
 mod decrease;
 mod increase;
+mod rebalance;
 
 pub use decrease::*;
 pub use increase::*;
+pub use rebalance::*;
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Tsify)]
 #[tsify(into_wasm_abi, from_wasm_abi, large_number_types_as_bigints)]
@@ -22,7 +24,8 @@ pub struct AdditionalValidatorStakeIxArgs {
 #[tsify(into_wasm_abi, from_wasm_abi, large_number_types_as_bigints)]
 #[serde(rename_all = "camelCase")]
 pub struct AdditionalValidatorStakeIxUserAddrs {
-    pub program: B58PK,
+    #[serde(flatten)]
+    pub deployment: StakePoolDeployment,
+
     pub vote_account: B58PK,
-    pub stake_pool: B58PK,
 }
@@ -0,0 +1,138 @@
+use std::collections::HashMap;
+
+use sanctum_spl_stake_pool_core::{
+    rebalance_plan_itr, RebalanceDirection, RebalanceStep, TransientStakeState,
+};
+use serde::{Deserialize, Serialize};
+use tsify_next::{declare, Tsify};
+use wasm_bindgen::{prelude::wasm_bindgen, JsError};
+
+use crate::{
+    decrease_additional_validator_stake_ix_from_stake_pool,
+    increase_additional_validator_stake_ix_from_stake_pool, AdditionalValidatorStakeIxArgs,
+    AdditionalValidatorStakeIxUserAddrs, Instructions, StakePoolDeployment, StakePoolHandle,
+    ValidatorListHandle, B58PK,
+};
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Tsify)]
+#[tsify(into_wasm_abi, from_wasm_abi, large_number_types_as_bigints)]
+#[serde(rename_all = "camelCase")]
+pub struct TargetWeight {
+    pub vote_account: B58PK,
+    pub weight: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Tsify)]
+#[tsify(into_wasm_abi, from_wasm_abi, large_number_types_as_bigints)]
+#[serde(rename_all = "camelCase")]
+pub struct RebalanceArgs {
+    /// Lamports of the reserve stake account that may be used for increases
+    pub reserve_stake_lamports: u64,
+    pub current_epoch: u64,
+
+    /// Validators not in this list have a target weight of 0
+    pub target_weights: Vec<TargetWeight>,
+
+    /// State of each validator's transient stake account, in validator list order,
+    /// see `transientStakeState()`.
+    /// Validators with transient stake but without an entry, or with a `null` entry, are skipped.
+    #[tsify(optional)]
+    pub transient_stake_states: Option<Vec<Option<TransientStakeState>>>,
+}
+
+#[declare]
+pub type RebalanceUserAddrs = StakePoolDeployment;
+
+/// Returns the IncreaseAdditionalValidatorStake and DecreaseAdditionalValidatorStake
+/// instructions required to move the pool's stake towards `args.targetWeights`.
+/// See `rebalancePlan()` for details.
+///
+/// @throws if
+/// - invalid pubkey was provided
+/// - PDAs can't be found
+#[wasm_bindgen(js_name = rebalanceIxsFromStakePool)]
+pub fn rebalance_ixs_from_stake_pool(
+    deployment: RebalanceUserAddrs,
+    stake_pool_handle: &StakePoolHandle,
+    validator_list_handle: &ValidatorListHandle,
+    args: RebalanceArgs,
+) -> Result<Instructions, JsError> {
+    let validator_list = validator_list_handle.0.as_borrowed();
+    let ixs: Result<Box<[_]>, JsError> = rebalance_plan(validator_list_handle, args)
+        .into_iter()
+        .map(
+            |RebalanceStep {
+                 validator_index,
+                 direction,
+                 lamports,
+                 transient_stake_seed,
+             }| {
+                let vsi = &validator_list.validators[validator_index];
+                let addrs = AdditionalValidatorStakeIxUserAddrs {
+                    deployment: deployment.clone(),
+                    vote_account: B58PK::new(*vsi.vote_account_address()),
+                };
+                let args = AdditionalValidatorStakeIxArgs {
+                    lamports,
+                    transient_stake_seed,
+                    validator_stake_seed: vsi.validator_seed_suffix().map(|s| s.get()),
+                };
+                match direction {
+                    RebalanceDirection::Increase => {
+                        increase_additional_validator_stake_ix_from_stake_pool(
+                            addrs,
+                            stake_pool_handle,
+                            args,
+                        )
+                    }
+                    RebalanceDirection::Decrease => {
+                        decrease_additional_validator_stake_ix_from_stake_pool(
+                            addrs,
+                            stake_pool_handle,
+                            args,
+                        )
+                    }
+                }
+            },
+        )
+        .collect();
+    Ok(Instructions(ixs?))
+}
+
+/// Plans at most one increase or decrease per validator that moves the validator's
+/// stake towards its share of the pool's total active stake according to `args.targetWeights`.
+///
+/// - only active validators that have been updated for `args.currentEpoch` are considered
+/// - validators with transient stake are only topped up in the same direction
+///   as their transient stake account's, if its state is provided
+/// - increases are funded from the reserve, keeping it rent-exempt
+/// - decreases never bring a validator's stake below the minimum required by the program
+#[wasm_bindgen(js_name = rebalancePlan)]
+pub fn rebalance_plan(
+    validator_list_handle: &ValidatorListHandle,
+    RebalanceArgs {
+        reserve_stake_lamports,
+        current_epoch,
+        target_weights,
+        transient_stake_states,
+    }: RebalanceArgs,
+) -> Vec<RebalanceStep> {
+    let target_weights: HashMap<[u8; 32], u64> = target_weights
+        .into_iter()
+        .map(
+            |TargetWeight {
+                 vote_account,
+                 weight,
+             }| (vote_account.0, weight),
+        )
+        .collect();
+    let transient_stake_states = transient_stake_states.unwrap_or_default();
+    rebalance_plan_itr(
+        &validator_list_handle.0.as_borrowed(),
+        reserve_stake_lamports,
+        current_epoch,
+        |vote| target_weights.get(vote).copied().unwrap_or(0),
+        |i, _| transient_stake_states.get(i).copied().flatten(),
+    )
+    .collect()
+}
@@ -0,0 +1,114 @@
+use sanctum_spl_stake_pool_core::{
+    self as stake_pool_sdk, ASSOCIATED_TOKEN_PROGRAM, CREATE_ATA_IDEMPOTENT_IX_IS_SIGNER,
+    CREATE_ATA_IDEMPOTENT_IX_IS_WRITER,
+};
+use serde::{Deserialize, Serialize};
+use serde_bytes::ByteBuf;
+use tsify_next::Tsify;
+use wasm_bindgen::{prelude::wasm_bindgen, JsError};
+
+use crate::{
+    err::no_valid_pda,
+    find_ata_internal, find_pool_token_ata_internal,
+    utils::{keys_signer_writer_to_account_metas, AccountMeta},
+    StakePoolHandle, B58PK,
+};
+
+use super::Instruction;
+
+#[wasm_bindgen]
+#[derive(Default)]
+pub struct CreateAtaIdempotentIxKeysHandle(stake_pool_sdk::CreateAtaIdempotentIxKeysOwned);
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Tsify)]
+#[tsify(into_wasm_abi, from_wasm_abi, large_number_types_as_bigints)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateAtaIdempotentIxUserAddrs {
+    pub funding: B58PK,
+    pub wallet: B58PK,
+    pub mint: B58PK,
+    pub token_program: B58PK,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Tsify)]
+#[tsify(into_wasm_abi, from_wasm_abi, large_number_types_as_bigints)]
+#[serde(rename_all = "camelCase")]
+pub struct CreatePoolTokenAtaIdempotentIxUserAddrs {
+    pub funding: B58PK,
+    pub wallet: B58PK,
+}
+
+/// @throws if
+/// - invalid pubkey was provided
+/// - PDAs can't be found
+#[wasm_bindgen(js_name = createAtaIdempotentIx)]
+pub fn create_ata_idempotent_ix(
+    CreateAtaIdempotentIxUserAddrs {
+        funding,
+        wallet,
+        mint,
+        token_program,
+    }: CreateAtaIdempotentIxUserAddrs,
+) -> Result<Instruction, JsError> {
+    let ata = find_ata_internal(&wallet.0, &token_program.0, &mint.0)
+        .ok_or_else(no_valid_pda)?
+        .0;
+
+    let accounts = stake_pool_sdk::CreateAtaIdempotentIxKeysOwned::default()
+        .with_funding(funding.0)
+        .with_ata(ata)
+        .with_wallet(wallet.0)
+        .with_mint(mint.0)
+        .with_token_program(token_program.0)
+        .with_consts();
+
+    Ok(create_ata_idempotent_ix_internal(accounts))
+}
+
+/// Creates `wallet`'s associated token account for the pool's LST
+/// if it does not yet exist, using the pool's token program.
+///
+/// @throws if
+/// - invalid pubkey was provided
+/// - PDAs can't be found
+#[wasm_bindgen(js_name = createPoolTokenAtaIdempotentIxFromStakePool)]
+pub fn create_pool_token_ata_idempotent_ix_from_stake_pool(
+    CreatePoolTokenAtaIdempotentIxUserAddrs {
+        funding,
+        wallet,
+    }: CreatePoolTokenAtaIdempotentIxUserAddrs,
+    stake_pool_handle: &StakePoolHandle,
+) -> Result<Instruction, JsError> {
+    let ata = find_pool_token_ata_internal(&stake_pool_handle.0, &wallet.0)
+        .ok_or_else(no_valid_pda)?
+        .0;
+
+    let accounts = stake_pool_sdk::CreateAtaIdempotentIxKeysOwned::default()
+        .with_keys_from_stake_pool(&stake_pool_handle.0)
+        .with_funding(funding.0)
+        .with_ata(ata)
+        .with_wallet(wallet.0)
+        .with_consts();
+
+    Ok(create_ata_idempotent_ix_internal(accounts))
+}
+
+pub(crate) fn create_ata_idempotent_ix_internal(
+    accounts: stake_pool_sdk::CreateAtaIdempotentIxKeysOwned,
+) -> Instruction {
+    Instruction {
+        data: ByteBuf::from(stake_pool_sdk::CreateAtaIdempotentIxData::new().to_buf()),
+        accounts: Box::new(CreateAtaIdempotentIxKeysHandle(accounts).to_account_metas()),
+        program_address: B58PK::new(ASSOCIATED_TOKEN_PROGRAM),
+    }
+}
+
+impl CreateAtaIdempotentIxKeysHandle {
+    fn to_account_metas(&self) -> [AccountMeta; stake_pool_sdk::CREATE_ATA_IDEMPOTENT_IX_ACCS_LEN] {
+        keys_signer_writer_to_account_metas(
+            &self.0.as_borrowed().0,
+            &CREATE_ATA_IDEMPOTENT_IX_IS_SIGNER.0,
+            &CREATE_ATA_IDEMPOTENT_IX_IS_WRITER.0,
+        )
+    }
+}
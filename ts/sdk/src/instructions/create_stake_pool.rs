@@ -0,0 +1,238 @@
+use sanctum_spl_stake_pool_core::{
+    self as stake_pool_sdk, Fee, Rent, StakePool, ValidatorList, MINT_ACCOUNT_LEN,
+    STAKE_ACCOUNT_LEN, TOKEN_INITIALIZE_MINT_IX_IS_SIGNER, TOKEN_INITIALIZE_MINT_IX_IS_WRITER,
+};
+use serde::{Deserialize, Serialize};
+use serde_bytes::ByteBuf;
+use tsify_next::Tsify;
+use wasm_bindgen::{prelude::wasm_bindgen, JsError};
+
+use crate::{
+    err::{arithmetic_overflow_err, no_valid_pda},
+    find_ata_internal, find_withdraw_auth_pda_internal,
+    utils::keys_signer_writer_to_account_metas,
+    B58PK,
+};
+
+use super::{
+    create_account_ix_internal, create_ata_idempotent_ix_internal, initialize_ix,
+    stake_initialize_ix_internal, InitializeIxArgs, InitializeIxUserAddrs, Instruction,
+    Instructions, StakePoolDeployment,
+};
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Tsify)]
+#[tsify(into_wasm_abi, from_wasm_abi, large_number_types_as_bigints)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateStakePoolIxsUserAddrs {
+    /// `stakePool` should be the pubkey of a newly generated keypair
+    #[serde(flatten)]
+    pub deployment: StakePoolDeployment,
+
+    /// Pays for the rent of all created accounts
+    pub payer: B58PK,
+
+    pub manager: B58PK,
+    pub staker: B58PK,
+
+    /// Pubkey of a newly generated keypair
+    pub validator_list: B58PK,
+
+    /// Pubkey of a newly generated keypair
+    pub reserve: B58PK,
+
+    /// Pubkey of a newly generated keypair
+    pub pool_mint: B58PK,
+
+    pub pool_token_program: B58PK,
+
+    #[tsify(optional)]
+    pub deposit_authority: Option<B58PK>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Tsify)]
+#[tsify(into_wasm_abi, from_wasm_abi, large_number_types_as_bigints)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateStakePoolIxsArgs {
+    pub fee: Fee,
+    pub withdrawal_fee: Fee,
+    pub deposit_fee: Fee,
+    pub referral_fee: u8,
+    pub max_validators: u32,
+
+    pub pool_mint_decimals: u8,
+
+    /// Lamports to fund the reserve stake account with
+    /// on top of its rent-exempt minimum.
+    ///
+    /// Initialize mints these lamports 1:1 as pool tokens to the manager's fee account
+    pub reserve_lamports: u64,
+
+    /// Defaults to mainnet-beta's rent parameters if not provided
+    #[tsify(optional)]
+    pub rent: Option<Rent>,
+}
+
+/// Returns the ordered instructions to create and initialize a new stake pool:
+///
+/// 1. System program CreateAccount for the stake pool, owned by `program`
+/// 2. System program CreateAccount for the validator list, owned by `program`
+/// 3. System program CreateAccount for the reserve stake
+/// 4. Stake program Initialize for the reserve stake,
+///    with the pool's withdraw authority as staker and withdrawer
+/// 5. System program CreateAccount for the pool mint, owned by `poolTokenProgram`
+/// 6. Token program InitializeMint2 for the pool mint,
+///    with the pool's withdraw authority as mint authority
+/// 7. Associated token program CreateIdempotent for `manager`'s pool token account,
+///    which is used as the manager fee account
+/// 8. Initialize
+///
+/// `payer`, `stakePool`, `validatorList`, `reserve`, `poolMint` and `manager` must sign.
+///
+/// These instructions usually do not fit into a single transaction;
+/// instructions 1-4 and 5-8 can be sent as 2 separate transactions in order.
+///
+/// @throws if
+/// - invalid pubkey was provided
+/// - PDAs can't be found
+/// - validator list account size for `maxValidators` overflows
+#[wasm_bindgen(js_name = createStakePoolIxs)]
+pub fn create_stake_pool_ixs(
+    CreateStakePoolIxsUserAddrs {
+        deployment,
+        payer,
+        manager,
+        staker,
+        validator_list,
+        reserve,
+        pool_mint,
+        pool_token_program,
+        deposit_authority,
+    }: CreateStakePoolIxsUserAddrs,
+    CreateStakePoolIxsArgs {
+        fee,
+        withdrawal_fee,
+        deposit_fee,
+        referral_fee,
+        max_validators,
+        pool_mint_decimals,
+        reserve_lamports,
+        rent,
+    }: CreateStakePoolIxsArgs,
+) -> Result<Instructions, JsError> {
+    let rent = rent.unwrap_or(Rent::DEFAULT);
+    let StakePoolDeployment {
+        program,
+        stake_pool,
+    } = &deployment;
+
+    let withdraw_auth = find_withdraw_auth_pda_internal(&program.0, &stake_pool.0)
+        .ok_or_else(no_valid_pda)?
+        .0;
+    let manager_fee = find_ata_internal(&manager.0, &pool_token_program.0, &pool_mint.0)
+        .ok_or_else(no_valid_pda)?
+        .0;
+
+    let create_stake_pool = create_account_ix_internal(
+        &payer.0,
+        &stake_pool.0,
+        StakePool::rent_exempt_lamports(Some(&rent)),
+        StakePool::ACCOUNT_LEN,
+        &program.0,
+    );
+    let validator_list_len =
+        ValidatorList::account_len(max_validators).ok_or_else(arithmetic_overflow_err)?;
+    let create_validator_list = create_account_ix_internal(
+        &payer.0,
+        &validator_list.0,
+        rent.minimum_balance(validator_list_len),
+        validator_list_len,
+        &program.0,
+    );
+    let create_reserve = create_account_ix_internal(
+        &payer.0,
+        &reserve.0,
+        rent.minimum_balance(STAKE_ACCOUNT_LEN)
+            .saturating_add(reserve_lamports),
+        STAKE_ACCOUNT_LEN,
+        &stake_pool_sdk::STAKE_PROGRAM,
+    );
+    let initialize_reserve = stake_initialize_ix_internal(
+        &reserve.0,
+        &withdraw_auth,
+        &withdraw_auth,
+        &stake_pool_sdk::Lockup::default(),
+    );
+    let create_mint = create_account_ix_internal(
+        &payer.0,
+        &pool_mint.0,
+        rent.minimum_balance(MINT_ACCOUNT_LEN),
+        MINT_ACCOUNT_LEN,
+        &pool_token_program.0,
+    );
+    let initialize_mint = token_initialize_mint_ix_internal(
+        &pool_token_program.0,
+        &pool_mint.0,
+        pool_mint_decimals,
+        &withdraw_auth,
+    );
+    let create_manager_fee = create_ata_idempotent_ix_internal(
+        stake_pool_sdk::CreateAtaIdempotentIxKeysOwned::default()
+            .with_funding(payer.0)
+            .with_ata(manager_fee)
+            .with_wallet(manager.0)
+            .with_mint(pool_mint.0)
+            .with_token_program(pool_token_program.0)
+            .with_consts(),
+    );
+    let initialize = initialize_ix(
+        InitializeIxUserAddrs {
+            deployment,
+            manager,
+            manager_fee: B58PK::new(manager_fee),
+            staker,
+            validator_list,
+            reserve,
+            pool_mint,
+            pool_token_program,
+            deposit_authority,
+        },
+        InitializeIxArgs {
+            fee,
+            withdrawal_fee,
+            deposit_fee,
+            referral_fee,
+            max_validators,
+        },
+    )?;
+
+    Ok(Instructions(Box::new([
+        create_stake_pool,
+        create_validator_list,
+        create_reserve,
+        initialize_reserve,
+        create_mint,
+        initialize_mint,
+        create_manager_fee,
+        initialize,
+    ])))
+}
+
+fn token_initialize_mint_ix_internal(
+    token_program: &[u8; 32],
+    mint: &[u8; 32],
+    decimals: u8,
+    mint_authority: &[u8; 32],
+) -> Instruction {
+    let accounts = stake_pool_sdk::TokenInitializeMintIxKeysOwned::default().with_mint(*mint);
+    let data = stake_pool_sdk::TokenInitializeMintIxData::new(decimals, mint_authority);
+
+    Instruction {
+        data: ByteBuf::from(data.to_buf()),
+        accounts: Box::new(keys_signer_writer_to_account_metas(
+            &accounts.as_borrowed().0,
+            &TOKEN_INITIALIZE_MINT_IX_IS_SIGNER.0,
+            &TOKEN_INITIALIZE_MINT_IX_IS_WRITER.0,
+        )),
+        program_address: B58PK::new(*token_program),
+    }
+}
@@ -8,11 +8,11 @@ use sanctum_spl_stake_pool_core as stake_pool_sdk;
 use crate::{
     err::no_valid_pda,
     find_withdraw_auth_pda_internal,
-    utils::{keys_signer_writer_to_account_metas, AccountMeta, Role},
+    utils::{keys_signer_writer_iter_to_account_metas, AccountMeta},
     StakePoolHandle, B58PK,
 };
 
-use super::Instruction;
+use super::{Instruction, StakePoolDeployment};
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Tsify)]
 #[tsify(into_wasm_abi, from_wasm_abi, large_number_types_as_bigints)]
@@ -29,8 +29,9 @@ pub struct DepositSolIxPrefixKeysHandle(stake_pool_sdk::DepositSolIxPrefixKeysOw
 #[tsify(into_wasm_abi, from_wasm_abi, large_number_types_as_bigints)]
 #[serde(rename_all = "camelCase")]
 pub struct DepositSolIxUserAddrs {
-    pub program: B58PK,
-    pub stake_pool: B58PK,
+    #[serde(flatten)]
+    pub deployment: StakePoolDeployment,
+
     pub referrer_fee: B58PK,
     pub from_user_lamports: B58PK,
     pub dest_user_pool: B58PK,
@@ -43,8 +44,10 @@ pub struct DepositSolIxUserAddrs {
 #[wasm_bindgen(js_name = depositSolIxFromStakePool)]
 pub fn deposit_sol_ix_from_stake_pool(
     DepositSolIxUserAddrs {
-        program,
-        stake_pool,
+        deployment: StakePoolDeployment {
+            program,
+            stake_pool,
+        },
         referrer_fee,
         from_user_lamports,
         dest_user_pool,
@@ -66,32 +69,26 @@ pub fn deposit_sol_ix_from_stake_pool(
             .with_dest_user_pool(dest_user_pool.0)
             .with_consts(),
     )
-    .to_account_metas();
+    .to_account_metas(&stake_pool_handle.0);
 
     let data = stake_pool_sdk::DepositSolIxData::new(args.deposit_lamports);
 
     Ok(Instruction {
         data: ByteBuf::from(data.to_buf()),
-        accounts: accounts
-            .into_iter()
-            .chain(
-                stake_pool_handle
-                    .0
-                    .sol_deposit_authority
-                    .into_iter()
-                    .map(|auth| AccountMeta::new(auth, Role::ReadonlySigner)),
-            )
-            .collect(),
+        accounts,
         program_address: program,
     })
 }
 
 impl DepositSolIxPrefixKeysHandle {
-    fn to_account_metas(&self) -> [AccountMeta; stake_pool_sdk::DEPOSIT_SOL_IX_PREFIX_ACCS_LEN] {
-        keys_signer_writer_to_account_metas(
-            &self.0.as_borrowed().0,
-            &stake_pool_sdk::DEPOSIT_SOL_IX_PREFIX_IS_SIGNER.0,
-            &stake_pool_sdk::DEPOSIT_SOL_IX_PREFIX_IS_WRITER.0,
+    /// Appends the suffix accounts required by `stake_pool`, if any
+    fn to_account_metas(&self, stake_pool: &stake_pool_sdk::StakePool) -> Box<[AccountMeta]> {
+        keys_signer_writer_iter_to_account_metas(
+            stake_pool_sdk::DepositSolIxFullKeysOwned::from_prefix_and_stake_pool(
+                self.0.clone(),
+                stake_pool,
+            )
+            .keys_signer_writer(),
         )
     }
 }
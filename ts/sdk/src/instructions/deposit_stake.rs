@@ -1,8 +1,6 @@
 use std::num::NonZeroU32;
 
-use sanctum_spl_stake_pool_core::{
-    self as stake_pool_sdk, DEPOSIT_STAKE_IX_IS_SIGNER, DEPOSIT_STAKE_IX_IS_WRITER,
-};
+use sanctum_spl_stake_pool_core::{self as stake_pool_sdk, StakeAuthorize};
 use serde::{Deserialize, Serialize};
 use serde_bytes::ByteBuf;
 use tsify_next::Tsify;
@@ -10,12 +8,13 @@ use wasm_bindgen::{prelude::wasm_bindgen, JsError};
 
 use crate::{
     err::no_valid_pda,
-    find_validator_stake_account_pda_internal, find_withdraw_auth_pda_internal,
-    utils::{keys_signer_writer_to_account_metas, AccountMeta},
+    find_deposit_auth_pda_internal, find_validator_stake_account_pda_internal,
+    find_withdraw_auth_pda_internal,
+    utils::{keys_signer_writer_iter_to_account_metas, AccountMeta},
     StakePoolHandle, B58PK,
 };
 
-use super::Instruction;
+use super::{stake_authorize_ix_internal, Instruction, Instructions, StakePoolDeployment};
 
 #[wasm_bindgen]
 #[derive(Default)]
@@ -25,8 +24,9 @@ pub struct DepositStakeIxKeysHandle(stake_pool_sdk::DepositStakeIxKeysOwned);
 #[tsify(into_wasm_abi, from_wasm_abi, large_number_types_as_bigints)]
 #[serde(rename_all = "camelCase")]
 pub struct DepositStakeIxUserAddrs {
-    pub program: B58PK,
-    pub stake_pool: B58PK,
+    #[serde(flatten)]
+    pub deployment: StakePoolDeployment,
+
     pub deposit_stake: B58PK,
     pub validator_vote: B58PK,
     pub pool_tokens_to: B58PK,
@@ -40,8 +40,10 @@ pub struct DepositStakeIxUserAddrs {
 #[wasm_bindgen(js_name = depositStakeIxFromStakePool)]
 pub fn deposit_stake_ix_from_stake_pool(
     DepositStakeIxUserAddrs {
-        program,
-        stake_pool,
+        deployment: StakePoolDeployment {
+            program,
+            stake_pool,
+        },
         deposit_stake,
         validator_vote,
         pool_tokens_to,
@@ -62,11 +64,14 @@ pub fn deposit_stake_ix_from_stake_pool(
     .ok_or_else(no_valid_pda)?
     .0;
 
+    let deposit_auth_pda = find_deposit_auth_pda_internal(&program.0, &stake_pool.0)
+        .ok_or_else(no_valid_pda)?
+        .0;
+
     let accounts = stake_pool_sdk::DepositStakeIxKeysOwned::default()
         .with_keys_from_stake_pool(&stake_pool_handle.0)
         .with_consts()
         .with_stake_pool(stake_pool.0)
-        .with_deposit_auth(stake_pool_handle.0.stake_deposit_authority)
         .with_withdraw_auth(withdraw_auth)
         .with_deposit_stake(deposit_stake.0)
         .with_validator_stake(validator_stake)
@@ -75,17 +80,96 @@ pub fn deposit_stake_ix_from_stake_pool(
 
     Ok(Instruction {
         data: ByteBuf::from(stake_pool_sdk::DepositStakeIxData::new().to_buf()),
-        accounts: Box::new(DepositStakeIxKeysHandle(accounts).to_account_metas()),
+        accounts: DepositStakeIxKeysHandle(accounts)
+            .to_account_metas(&stake_pool_handle.0, &deposit_auth_pda),
         program_address: program,
     })
 }
 
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Tsify)]
+#[tsify(into_wasm_abi, from_wasm_abi, large_number_types_as_bigints)]
+#[serde(rename_all = "camelCase")]
+pub struct DepositStakeIxsUserAddrs {
+    #[serde(flatten)]
+    pub deployment: StakePoolDeployment,
+
+    pub deposit_stake: B58PK,
+
+    /// Current staker and withdrawer of `deposit_stake`
+    pub deposit_stake_auth: B58PK,
+
+    pub validator_vote: B58PK,
+    pub pool_tokens_to: B58PK,
+    pub referral_pool_tokens: B58PK,
+}
+
+/// Returns the ordered instructions to deposit a stake account
+/// whose staker and withdrawer is `deposit_stake_auth`:
+///
+/// 1. Stake program Authorize to set the pool's stake deposit authority as staker
+/// 2. Stake program Authorize to set the pool's stake deposit authority as withdrawer
+/// 3. DepositStake
+///
+/// @throws if
+/// - invalid pubkey was provided
+/// - PDAs can't be found
+#[wasm_bindgen(js_name = depositStakeIxsFromStakePool)]
+pub fn deposit_stake_ixs_from_stake_pool(
+    DepositStakeIxsUserAddrs {
+        deployment,
+        deposit_stake,
+        deposit_stake_auth,
+        validator_vote,
+        pool_tokens_to,
+        referral_pool_tokens,
+    }: DepositStakeIxsUserAddrs,
+    stake_pool_handle: &StakePoolHandle,
+    validator_stake_seed: Option<u32>,
+) -> Result<Instructions, JsError> {
+    let [authorize_staker, authorize_withdrawer] =
+        [StakeAuthorize::Staker, StakeAuthorize::Withdrawer].map(|stake_authorize| {
+            stake_authorize_ix_internal(
+                &deposit_stake.0,
+                &deposit_stake_auth.0,
+                None,
+                &stake_pool_handle.0.stake_deposit_authority,
+                stake_authorize,
+            )
+        });
+    let deposit = deposit_stake_ix_from_stake_pool(
+        DepositStakeIxUserAddrs {
+            deployment,
+            deposit_stake,
+            validator_vote,
+            pool_tokens_to,
+            referral_pool_tokens,
+        },
+        stake_pool_handle,
+        validator_stake_seed,
+    )?;
+
+    Ok(Instructions(Box::new([
+        authorize_staker,
+        authorize_withdrawer,
+        deposit,
+    ])))
+}
+
 impl DepositStakeIxKeysHandle {
-    fn to_account_metas(&self) -> [AccountMeta; stake_pool_sdk::DEPOSIT_STAKE_IX_ACCS_LEN] {
-        keys_signer_writer_to_account_metas(
-            &self.0.as_borrowed().0,
-            &DEPOSIT_STAKE_IX_IS_SIGNER.0,
-            &DEPOSIT_STAKE_IX_IS_WRITER.0,
+    /// Sets the pool's stake deposit authority as a signer
+    /// if it is not `deposit_auth_pda`
+    fn to_account_metas(
+        &self,
+        stake_pool: &stake_pool_sdk::StakePool,
+        deposit_auth_pda: &[u8; 32],
+    ) -> Box<[AccountMeta]> {
+        keys_signer_writer_iter_to_account_metas(
+            stake_pool_sdk::DepositStakeIxFullKeysOwned::from_keys_and_stake_pool(
+                self.0,
+                stake_pool,
+                deposit_auth_pda,
+            )
+            .keys_signer_writer(),
         )
     }
 }
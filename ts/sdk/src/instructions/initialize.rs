@@ -13,7 +13,7 @@ use crate::{
     B58PK,
 };
 
-use super::Instruction;
+use super::{Instruction, StakePoolDeployment};
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Tsify)]
 #[tsify(into_wasm_abi, from_wasm_abi)]
@@ -34,8 +34,9 @@ pub struct InitializeIxKeysHandle(stake_pool_sdk::InitializeIxPrefixKeysOwned);
 #[tsify(into_wasm_abi, from_wasm_abi, large_number_types_as_bigints)]
 #[serde(rename_all = "camelCase")]
 pub struct InitializeIxUserAddrs {
-    pub program: B58PK,
-    pub stake_pool: B58PK,
+    #[serde(flatten)]
+    pub deployment: StakePoolDeployment,
+
     pub manager: B58PK,
     pub manager_fee: B58PK,
     pub staker: B58PK,
@@ -55,8 +56,10 @@ pub struct InitializeIxUserAddrs {
 #[wasm_bindgen(js_name = initializeIx)]
 pub fn initialize_ix(
     InitializeIxUserAddrs {
-        program,
-        stake_pool,
+        deployment: StakePoolDeployment {
+            program,
+            stake_pool,
+        },
         manager,
         manager_fee,
         staker,
@@ -1,14 +1,18 @@
 use serde::{Deserialize, Serialize};
 use serde_bytes::ByteBuf;
-use tsify_next::Tsify;
+use tsify_next::{declare, Tsify};
 
 use crate::{utils::AccountMeta, B58PK};
 
 mod additional_validator_stake;
 mod cleanup_removed_validator_entries;
+mod create_ata_idempotent;
+mod create_stake_pool;
 mod deposit_sol;
 mod deposit_stake;
 mod initialize;
+mod stake_program;
+mod update_stake_pool;
 mod update_stake_pool_balance;
 mod update_validator_list_balance;
 mod withdraw_sol;
@@ -16,9 +20,13 @@ mod withdraw_stake;
 
 pub use additional_validator_stake::*;
 pub use cleanup_removed_validator_entries::*;
+pub use create_ata_idempotent::*;
+pub use create_stake_pool::*;
 pub use deposit_sol::*;
 pub use deposit_stake::*;
 pub use initialize::*;
+pub use stake_program::*;
+pub use update_stake_pool::*;
 pub use update_stake_pool_balance::*;
 pub use update_validator_list_balance::*;
 pub use withdraw_sol::*;
@@ -33,11 +41,23 @@ pub struct Instruction {
     pub program_address: B58PK,
 }
 
-/// This user addrs struct is common across multiple instructions
+/// An ordered list of instructions
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Tsify)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub struct Instructions(pub Box<[Instruction]>);
+
+/// A stake pool and the stake pool program it belongs to.
+///
+/// Included in the user addrs of every stake pool instruction.
+/// See `knownStakePools()` for well-known deployments.
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Tsify)]
 #[tsify(into_wasm_abi, from_wasm_abi, large_number_types_as_bigints)]
 #[serde(rename_all = "camelCase")]
-pub struct ProgramAndStakePoolUserAddrs {
+pub struct StakePoolDeployment {
     pub program: B58PK,
     pub stake_pool: B58PK,
 }
+
+/// This user addrs struct is common across multiple instructions
+#[declare]
+pub type ProgramAndStakePoolUserAddrs = StakePoolDeployment;
@@ -0,0 +1,420 @@
+use sanctum_spl_stake_pool_core::{
+    self as stake_pool_sdk, StakeAuthorize, STAKE_ACCOUNT_LEN, STAKE_PROGRAM, SYSTEM_PROGRAM,
+};
+use serde::{Deserialize, Serialize};
+use serde_bytes::ByteBuf;
+use tsify_next::Tsify;
+use wasm_bindgen::{prelude::wasm_bindgen, JsError};
+
+use crate::{
+    utils::{keys_signer_writer_to_account_metas, AccountMeta, Role},
+    Lockup, B58PK,
+};
+
+use super::Instruction;
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Tsify)]
+#[tsify(into_wasm_abi, from_wasm_abi, large_number_types_as_bigints)]
+#[serde(rename_all = "camelCase")]
+pub struct StakeInitializeIxUserAddrs {
+    pub stake: B58PK,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Tsify)]
+#[tsify(into_wasm_abi, from_wasm_abi, large_number_types_as_bigints)]
+#[serde(rename_all = "camelCase")]
+pub struct StakeInitializeIxArgs {
+    pub staker: B58PK,
+    pub withdrawer: B58PK,
+    pub lockup: Lockup,
+}
+
+/// @throws if invalid pubkey was provided
+#[wasm_bindgen(js_name = stakeInitializeIx)]
+pub fn stake_initialize_ix(
+    StakeInitializeIxUserAddrs { stake }: StakeInitializeIxUserAddrs,
+    StakeInitializeIxArgs {
+        staker,
+        withdrawer,
+        lockup,
+    }: StakeInitializeIxArgs,
+) -> Result<Instruction, JsError> {
+    Ok(stake_initialize_ix_internal(
+        &stake.0,
+        &staker.0,
+        &withdrawer.0,
+        &lockup.try_to_core()?,
+    ))
+}
+
+pub(crate) fn stake_initialize_ix_internal(
+    stake: &[u8; 32],
+    staker: &[u8; 32],
+    withdrawer: &[u8; 32],
+    lockup: &stake_pool_sdk::Lockup,
+) -> Instruction {
+    let accounts = stake_pool_sdk::StakeInitializeIxKeysOwned::default()
+        .with_stake(*stake)
+        .with_consts();
+    let data = stake_pool_sdk::StakeInitializeIxData::new(staker, withdrawer, lockup);
+
+    Instruction {
+        data: ByteBuf::from(data.to_buf()),
+        accounts: Box::new(keys_signer_writer_to_account_metas(
+            &accounts.as_borrowed().0,
+            &stake_pool_sdk::STAKE_INITIALIZE_IX_IS_SIGNER.0,
+            &stake_pool_sdk::STAKE_INITIALIZE_IX_IS_WRITER.0,
+        )),
+        program_address: B58PK::new(STAKE_PROGRAM),
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Tsify)]
+#[tsify(into_wasm_abi, from_wasm_abi, large_number_types_as_bigints)]
+#[serde(rename_all = "camelCase")]
+pub struct StakeAuthorizeIxUserAddrs {
+    pub stake: B58PK,
+    pub authority: B58PK,
+
+    /// Only required if the stake account's lockup is in force
+    /// and the withdrawer is being changed
+    pub custodian: Option<B58PK>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Tsify)]
+#[tsify(into_wasm_abi, from_wasm_abi, large_number_types_as_bigints)]
+#[serde(rename_all = "camelCase")]
+pub struct StakeAuthorizeIxArgs {
+    pub new_authority: B58PK,
+    pub stake_authorize: StakeAuthorize,
+}
+
+#[wasm_bindgen(js_name = stakeAuthorizeIx)]
+pub fn stake_authorize_ix(
+    StakeAuthorizeIxUserAddrs {
+        stake,
+        authority,
+        custodian,
+    }: StakeAuthorizeIxUserAddrs,
+    StakeAuthorizeIxArgs {
+        new_authority,
+        stake_authorize,
+    }: StakeAuthorizeIxArgs,
+) -> Instruction {
+    stake_authorize_ix_internal(
+        &stake.0,
+        &authority.0,
+        custodian.as_ref().map(|c| &c.0),
+        &new_authority.0,
+        stake_authorize,
+    )
+}
+
+pub(crate) fn stake_authorize_ix_internal(
+    stake: &[u8; 32],
+    authority: &[u8; 32],
+    custodian: Option<&[u8; 32]>,
+    new_authority: &[u8; 32],
+    stake_authorize: StakeAuthorize,
+) -> Instruction {
+    let accounts = stake_pool_sdk::StakeAuthorizeIxPrefixKeysOwned::default()
+        .with_stake(*stake)
+        .with_authority(*authority)
+        .with_consts();
+    let data = stake_pool_sdk::StakeAuthorizeIxData::new(new_authority, stake_authorize);
+
+    Instruction {
+        data: ByteBuf::from(data.to_buf()),
+        accounts: keys_signer_writer_to_account_metas(
+            &accounts.as_borrowed().0,
+            &stake_pool_sdk::STAKE_AUTHORIZE_IX_PREFIX_IS_SIGNER.0,
+            &stake_pool_sdk::STAKE_AUTHORIZE_IX_PREFIX_IS_WRITER.0,
+        )
+        .into_iter()
+        .chain(custodian.map(|c| AccountMeta::new(*c, Role::ReadonlySigner)))
+        .collect(),
+        program_address: B58PK::new(STAKE_PROGRAM),
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Tsify)]
+#[tsify(into_wasm_abi, from_wasm_abi, large_number_types_as_bigints)]
+#[serde(rename_all = "camelCase")]
+pub struct StakeAuthorizeCheckedIxUserAddrs {
+    pub stake: B58PK,
+    pub authority: B58PK,
+    pub new_authority: B58PK,
+
+    /// Only required if the stake account's lockup is in force
+    /// and the withdrawer is being changed
+    pub custodian: Option<B58PK>,
+}
+
+#[wasm_bindgen(js_name = stakeAuthorizeCheckedIx)]
+pub fn stake_authorize_checked_ix(
+    StakeAuthorizeCheckedIxUserAddrs {
+        stake,
+        authority,
+        new_authority,
+        custodian,
+    }: StakeAuthorizeCheckedIxUserAddrs,
+    stake_authorize: StakeAuthorize,
+) -> Instruction {
+    let accounts = stake_pool_sdk::StakeAuthorizeCheckedIxPrefixKeysOwned::default()
+        .with_stake(stake.0)
+        .with_authority(authority.0)
+        .with_new_authority(new_authority.0)
+        .with_consts();
+    let data = stake_pool_sdk::StakeAuthorizeCheckedIxData::new(stake_authorize);
+
+    Instruction {
+        data: ByteBuf::from(data.to_buf()),
+        accounts: keys_signer_writer_to_account_metas(
+            &accounts.as_borrowed().0,
+            &stake_pool_sdk::STAKE_AUTHORIZE_CHECKED_IX_PREFIX_IS_SIGNER.0,
+            &stake_pool_sdk::STAKE_AUTHORIZE_CHECKED_IX_PREFIX_IS_WRITER.0,
+        )
+        .into_iter()
+        .chain(custodian.map(|c| AccountMeta::new(c.0, Role::ReadonlySigner)))
+        .collect(),
+        program_address: B58PK::new(STAKE_PROGRAM),
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Tsify)]
+#[tsify(into_wasm_abi, from_wasm_abi, large_number_types_as_bigints)]
+#[serde(rename_all = "camelCase")]
+pub struct StakeDelegateStakeIxUserAddrs {
+    pub stake: B58PK,
+    pub vote: B58PK,
+    pub stake_authority: B58PK,
+}
+
+#[wasm_bindgen(js_name = stakeDelegateStakeIx)]
+pub fn stake_delegate_stake_ix(
+    StakeDelegateStakeIxUserAddrs {
+        stake,
+        vote,
+        stake_authority,
+    }: StakeDelegateStakeIxUserAddrs,
+) -> Instruction {
+    let accounts = stake_pool_sdk::StakeDelegateStakeIxKeysOwned::default()
+        .with_stake(stake.0)
+        .with_vote(vote.0)
+        .with_stake_authority(stake_authority.0)
+        .with_consts();
+
+    Instruction {
+        data: ByteBuf::from(stake_pool_sdk::StakeDelegateStakeIxData::new().to_buf()),
+        accounts: Box::new(keys_signer_writer_to_account_metas(
+            &accounts.as_borrowed().0,
+            &stake_pool_sdk::STAKE_DELEGATE_STAKE_IX_IS_SIGNER.0,
+            &stake_pool_sdk::STAKE_DELEGATE_STAKE_IX_IS_WRITER.0,
+        )),
+        program_address: B58PK::new(STAKE_PROGRAM),
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Tsify)]
+#[tsify(into_wasm_abi, from_wasm_abi, large_number_types_as_bigints)]
+#[serde(rename_all = "camelCase")]
+pub struct StakeDeactivateIxUserAddrs {
+    pub stake: B58PK,
+    pub stake_authority: B58PK,
+}
+
+#[wasm_bindgen(js_name = stakeDeactivateIx)]
+pub fn stake_deactivate_ix(
+    StakeDeactivateIxUserAddrs {
+        stake,
+        stake_authority,
+    }: StakeDeactivateIxUserAddrs,
+) -> Instruction {
+    stake_deactivate_ix_internal(&stake.0, &stake_authority.0)
+}
+
+pub(crate) fn stake_deactivate_ix_internal(
+    stake: &[u8; 32],
+    stake_authority: &[u8; 32],
+) -> Instruction {
+    let accounts = stake_pool_sdk::StakeDeactivateIxKeysOwned::default()
+        .with_stake(*stake)
+        .with_stake_authority(*stake_authority)
+        .with_consts();
+
+    Instruction {
+        data: ByteBuf::from(stake_pool_sdk::StakeDeactivateIxData::new().to_buf()),
+        accounts: Box::new(keys_signer_writer_to_account_metas(
+            &accounts.as_borrowed().0,
+            &stake_pool_sdk::STAKE_DEACTIVATE_IX_IS_SIGNER.0,
+            &stake_pool_sdk::STAKE_DEACTIVATE_IX_IS_WRITER.0,
+        )),
+        program_address: B58PK::new(STAKE_PROGRAM),
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Tsify)]
+#[tsify(into_wasm_abi, from_wasm_abi, large_number_types_as_bigints)]
+#[serde(rename_all = "camelCase")]
+pub struct StakeWithdrawIxUserAddrs {
+    pub stake: B58PK,
+    pub to: B58PK,
+    pub withdraw_authority: B58PK,
+
+    /// Only required if the stake account's lockup is in force
+    pub custodian: Option<B58PK>,
+}
+
+#[wasm_bindgen(js_name = stakeWithdrawIx)]
+pub fn stake_withdraw_ix(
+    StakeWithdrawIxUserAddrs {
+        stake,
+        to,
+        withdraw_authority,
+        custodian,
+    }: StakeWithdrawIxUserAddrs,
+    lamports: u64,
+) -> Instruction {
+    let accounts = stake_pool_sdk::StakeWithdrawIxPrefixKeysOwned::default()
+        .with_stake(stake.0)
+        .with_to(to.0)
+        .with_withdraw_authority(withdraw_authority.0)
+        .with_consts();
+
+    Instruction {
+        data: ByteBuf::from(stake_pool_sdk::StakeWithdrawIxData::new(lamports).to_buf()),
+        accounts: keys_signer_writer_to_account_metas(
+            &accounts.as_borrowed().0,
+            &stake_pool_sdk::STAKE_WITHDRAW_IX_PREFIX_IS_SIGNER.0,
+            &stake_pool_sdk::STAKE_WITHDRAW_IX_PREFIX_IS_WRITER.0,
+        )
+        .into_iter()
+        .chain(custodian.map(|c| AccountMeta::new(c.0, Role::ReadonlySigner)))
+        .collect(),
+        program_address: B58PK::new(STAKE_PROGRAM),
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Tsify)]
+#[tsify(into_wasm_abi, from_wasm_abi, large_number_types_as_bigints)]
+#[serde(rename_all = "camelCase")]
+pub struct StakeMergeIxUserAddrs {
+    pub destination: B58PK,
+    pub source: B58PK,
+    pub stake_authority: B58PK,
+}
+
+#[wasm_bindgen(js_name = stakeMergeIx)]
+pub fn stake_merge_ix(
+    StakeMergeIxUserAddrs {
+        destination,
+        source,
+        stake_authority,
+    }: StakeMergeIxUserAddrs,
+) -> Instruction {
+    let accounts = stake_pool_sdk::StakeMergeIxKeysOwned::default()
+        .with_destination(destination.0)
+        .with_source(source.0)
+        .with_stake_authority(stake_authority.0)
+        .with_consts();
+
+    Instruction {
+        data: ByteBuf::from(stake_pool_sdk::StakeMergeIxData::new().to_buf()),
+        accounts: Box::new(keys_signer_writer_to_account_metas(
+            &accounts.as_borrowed().0,
+            &stake_pool_sdk::STAKE_MERGE_IX_IS_SIGNER.0,
+            &stake_pool_sdk::STAKE_MERGE_IX_IS_WRITER.0,
+        )),
+        program_address: B58PK::new(STAKE_PROGRAM),
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Tsify)]
+#[tsify(into_wasm_abi, from_wasm_abi, large_number_types_as_bigints)]
+#[serde(rename_all = "camelCase")]
+pub struct StakeSplitIxUserAddrs {
+    pub stake: B58PK,
+    pub split_to: B58PK,
+    pub stake_authority: B58PK,
+}
+
+/// `split_to` must be a rent exempt, uninitialized stake account,
+/// see [`create_stake_account_ix`]
+#[wasm_bindgen(js_name = stakeSplitIx)]
+pub fn stake_split_ix(
+    StakeSplitIxUserAddrs {
+        stake,
+        split_to,
+        stake_authority,
+    }: StakeSplitIxUserAddrs,
+    lamports: u64,
+) -> Instruction {
+    let accounts = stake_pool_sdk::StakeSplitIxKeysOwned::default()
+        .with_stake(stake.0)
+        .with_split_to(split_to.0)
+        .with_stake_authority(stake_authority.0);
+
+    Instruction {
+        data: ByteBuf::from(stake_pool_sdk::StakeSplitIxData::new(lamports).to_buf()),
+        accounts: Box::new(keys_signer_writer_to_account_metas(
+            &accounts.as_borrowed().0,
+            &stake_pool_sdk::STAKE_SPLIT_IX_IS_SIGNER.0,
+            &stake_pool_sdk::STAKE_SPLIT_IX_IS_WRITER.0,
+        )),
+        program_address: B58PK::new(STAKE_PROGRAM),
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Tsify)]
+#[tsify(into_wasm_abi, from_wasm_abi, large_number_types_as_bigints)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateStakeAccountIxUserAddrs {
+    pub payer: B58PK,
+    pub stake: B58PK,
+}
+
+/// System program CreateAccount instruction that creates
+/// an uninitialized stake account, funded with exactly `lamports`.
+///
+/// Both `payer` and `stake` must sign.
+#[wasm_bindgen(js_name = createStakeAccountIx)]
+pub fn create_stake_account_ix(
+    CreateStakeAccountIxUserAddrs { payer, stake }: CreateStakeAccountIxUserAddrs,
+    lamports: u64,
+) -> Instruction {
+    create_stake_account_ix_internal(&payer.0, &stake.0, lamports)
+}
+
+pub(crate) fn create_stake_account_ix_internal(
+    payer: &[u8; 32],
+    stake: &[u8; 32],
+    lamports: u64,
+) -> Instruction {
+    create_account_ix_internal(payer, stake, lamports, STAKE_ACCOUNT_LEN, &STAKE_PROGRAM)
+}
+
+/// System program CreateAccount instruction.
+///
+/// Both `payer` and `to` must sign.
+pub(crate) fn create_account_ix_internal(
+    payer: &[u8; 32],
+    to: &[u8; 32],
+    lamports: u64,
+    space: usize,
+    owner: &[u8; 32],
+) -> Instruction {
+    let accounts = stake_pool_sdk::CreateAccountIxKeysOwned::default()
+        .with_from(*payer)
+        .with_to(*to);
+    let data = stake_pool_sdk::CreateAccountIxData::new(lamports, space as u64, owner);
+
+    Instruction {
+        data: ByteBuf::from(data.to_buf()),
+        accounts: Box::new(keys_signer_writer_to_account_metas(
+            &accounts.as_borrowed().0,
+            &stake_pool_sdk::CREATE_ACCOUNT_IX_IS_SIGNER.0,
+            &stake_pool_sdk::CREATE_ACCOUNT_IX_IS_WRITER.0,
+        )),
+        program_address: B58PK::new(SYSTEM_PROGRAM),
+    }
+}
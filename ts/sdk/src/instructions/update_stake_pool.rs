@@ -0,0 +1,122 @@
+use std::num::NonZeroUsize;
+
+use sanctum_spl_stake_pool_core::{
+    update_validator_list_balance_chunks_itr, UpdateStatus,
+    UPDATE_VALIDATOR_LIST_BALANCE_MAX_PAIRS_PER_TX,
+};
+use serde::{Deserialize, Serialize};
+use tsify_next::{declare, Tsify};
+use wasm_bindgen::{prelude::wasm_bindgen, JsError};
+
+use crate::{
+    cleanup_removed_validator_entries_ix_from_stake_pool, err::no_valid_pda,
+    find_withdraw_auth_pda_internal, update_stake_pool_balance_ix_from_stake_pool,
+    update_validator_list_balance_ix_internal, StakePoolHandle, UpdateValidatorListBalanceIxArgs,
+    ValidatorListHandle,
+};
+
+use super::{Instructions, ProgramAndStakePoolUserAddrs};
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Tsify)]
+#[tsify(into_wasm_abi, from_wasm_abi, large_number_types_as_bigints)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateStakePoolIxsArgs {
+    pub no_merge: bool,
+
+    /// Epoch to update the pool for, used to skip instructions that are not required
+    pub current_epoch: u64,
+
+    /// Max number of validators to update per UpdateValidatorListBalance instruction.
+    ///
+    /// Defaults to the max number that fits in a transaction if 0 or not provided.
+    pub max_validators_per_ix: Option<usize>,
+}
+
+#[declare]
+pub type UpdateStakePoolIxsUserAddrs = ProgramAndStakePoolUserAddrs;
+
+/// Returns the full ordered sequence of instructions to update the pool for `args.currentEpoch`:
+///
+/// 1. UpdateValidatorListBalance instructions covering the entire validator list,
+///    each of which should be sent in its own transaction,
+///    if any validator list entry is not yet updated for the epoch
+/// 2. UpdateStakePoolBalance, if the pool or any validator list entry
+///    is not yet updated for the epoch
+/// 3. CleanupRemovedValidatorEntries, if any entry is ready for removal
+///
+/// 2. and 3. can be sent in the same transaction,
+/// but only after all of 1. have been confirmed.
+///
+/// Returns no instructions if the pool is fully updated.
+/// The number of instructions and transactions always matches `getUpdateStatus()`
+/// for the same `currentEpoch` and `maxValidatorsPerIx`.
+///
+/// @throws if
+/// - invalid pubkey was provided
+/// - PDAs can't be found
+#[wasm_bindgen(js_name = updateStakePoolIxsFromStakePool)]
+pub fn update_stake_pool_ixs_from_stake_pool(
+    addrs: UpdateStakePoolIxsUserAddrs,
+    stake_pool_handle: &StakePoolHandle,
+    validator_list_handle: &ValidatorListHandle,
+    UpdateStakePoolIxsArgs {
+        no_merge,
+        current_epoch,
+        max_validators_per_ix,
+    }: UpdateStakePoolIxsArgs,
+) -> Result<Instructions, JsError> {
+    let validator_list = validator_list_handle.0.as_borrowed();
+    let max_validators_per_ix = max_validators_per_ix_or_default(max_validators_per_ix);
+    let status = UpdateStatus::new(
+        &stake_pool_handle.0,
+        &validator_list,
+        current_epoch,
+        max_validators_per_ix,
+    );
+    if !status.is_update_required() {
+        return Ok(Instructions(Box::new([])));
+    }
+
+    let withdraw_authority = find_withdraw_auth_pda_internal(&addrs.program.0, &addrs.stake_pool.0)
+        .ok_or_else(no_valid_pda)?
+        .0;
+
+    let uvlb_ixs = update_validator_list_balance_chunks_itr(
+        validator_list.validators.len(),
+        max_validators_per_ix,
+    )
+    .take(status.update_validator_list_balance_ixs)
+    .map(|(start_index, count)| {
+        update_validator_list_balance_ix_internal(
+            &addrs.program,
+            &addrs.stake_pool.0,
+            &withdraw_authority,
+            &stake_pool_handle.0,
+            &validator_list,
+            UpdateValidatorListBalanceIxArgs {
+                start_index,
+                no_merge,
+                count,
+            },
+        )
+    });
+    let uspb_ix = status
+        .is_update_stake_pool_balance_required()
+        .then(|| update_stake_pool_balance_ix_from_stake_pool(addrs.clone(), stake_pool_handle));
+    let cleanup_ix = status.cleanup_required.then(|| {
+        cleanup_removed_validator_entries_ix_from_stake_pool(addrs.clone(), stake_pool_handle)
+    });
+
+    let ixs: Result<Box<[_]>, JsError> = uvlb_ixs.chain(uspb_ix).chain(cleanup_ix).collect();
+    Ok(Instructions(ixs?))
+}
+
+/// Treats 0 as not provided
+pub(crate) fn max_validators_per_ix_or_default(
+    max_validators_per_ix: Option<usize>,
+) -> NonZeroUsize {
+    max_validators_per_ix.and_then(NonZeroUsize::new).unwrap_or(
+        // unwrap-safety: const is nonzero
+        NonZeroUsize::new(UPDATE_VALIDATOR_LIST_BALANCE_MAX_PAIRS_PER_TX).unwrap(),
+    )
+}
@@ -2,7 +2,7 @@ use crate::{
     err::{no_valid_pda, validator_idx_oob},
     find_pda, find_withdraw_auth_pda_internal,
     utils::{keys_signer_writer_to_account_metas, AccountMeta, Role},
-    StakePoolHandle, ValidatorListHandle,
+    StakePoolHandle, ValidatorListHandle, B58PK,
 };
 use core::convert::TryFrom;
 use sanctum_spl_stake_pool_core::{
@@ -62,17 +62,36 @@ pub fn update_validator_list_balance_ix_from_stake_pool(
         return Err(validator_idx_oob());
     }
 
+    update_validator_list_balance_ix_internal(
+        &program,
+        &stake_pool.0,
+        &withdraw_authority,
+        &stake_pool_handle.0,
+        &validator_list,
+        args,
+    )
+}
+
+/// Does not check that `args.start_index` and `args.count` are in bounds
+pub(crate) fn update_validator_list_balance_ix_internal(
+    program: &B58PK,
+    stake_pool_addr: &[u8; 32],
+    withdraw_authority: &[u8; 32],
+    stake_pool: &stake_pool_sdk::StakePool,
+    validator_list: &stake_pool_sdk::ValidatorList<'_>,
+    args: UpdateValidatorListBalanceIxArgs,
+) -> Result<Instruction, JsError> {
     let accounts = UpdateValidatorListBalanceIxPrefixKeysHandle(
         stake_pool_sdk::UpdateValidatorListBalanceIxPrefixKeysOwned::default()
-            .with_keys_from_stake_pool(&stake_pool_handle.0)
-            .with_stake_pool(stake_pool.0)
-            .with_withdraw_auth(withdraw_authority)
+            .with_keys_from_stake_pool(stake_pool)
+            .with_stake_pool(*stake_pool_addr)
+            .with_withdraw_auth(*withdraw_authority)
             .with_consts(),
     )
     .to_account_metas();
 
     let vsa_tsa_pairs = validator_list
-        .account_pair_seeds_itr(&stake_pool.0)
+        .account_pair_seeds_itr(stake_pool_addr)
         .skip(args.start_index)
         .take(args.count)
         .flat_map(|((v1, v2, v3), (t1, t2, t3, t4))| {
@@ -101,7 +120,7 @@ pub fn update_validator_list_balance_ix_from_stake_pool(
     Ok(Instruction {
         data: ByteBuf::from(data),
         accounts: accounts?,
-        program_address: program,
+        program_address: program.clone(),
     })
 }
 
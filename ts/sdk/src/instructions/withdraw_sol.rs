@@ -8,11 +8,11 @@ use sanctum_spl_stake_pool_core as stake_pool_sdk;
 use crate::{
     err::no_valid_pda,
     find_withdraw_auth_pda_internal,
-    utils::{keys_signer_writer_to_account_metas, AccountMeta, Role},
+    utils::{keys_signer_writer_iter_to_account_metas, AccountMeta},
     StakePoolHandle, B58PK,
 };
 
-use super::Instruction;
+use super::{Instruction, StakePoolDeployment};
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Tsify)]
 #[tsify(into_wasm_abi, from_wasm_abi, large_number_types_as_bigints)]
@@ -29,8 +29,9 @@ pub struct WithdrawSolIxPrefixKeysHandle(stake_pool_sdk::WithdrawSolIxPrefixKeys
 #[tsify(into_wasm_abi, from_wasm_abi, large_number_types_as_bigints)]
 #[serde(rename_all = "camelCase")]
 pub struct WithdrawSolIxUserAddrs {
-    pub program: B58PK,
-    pub stake_pool: B58PK,
+    #[serde(flatten)]
+    pub deployment: StakePoolDeployment,
+
     pub user_transfer_auth: B58PK,
     pub pool_tokens_from: B58PK,
     pub lamports_to: B58PK,
@@ -42,8 +43,10 @@ pub struct WithdrawSolIxUserAddrs {
 #[wasm_bindgen(js_name = withdrawSolIxFromStakePool)]
 pub fn withdraw_sol_ix_from_stake_pool(
     WithdrawSolIxUserAddrs {
-        program,
-        stake_pool,
+        deployment: StakePoolDeployment {
+            program,
+            stake_pool,
+        },
         user_transfer_auth,
         pool_tokens_from,
         lamports_to,
@@ -65,32 +68,25 @@ pub fn withdraw_sol_ix_from_stake_pool(
             .with_lamports_to(lamports_to.0)
             .with_consts(),
     )
-    .to_account_metas();
+    .to_account_metas(&stake_pool_handle.0);
 
     let data = stake_pool_sdk::WithdrawSolIxData::new(args.pool_tokens_in);
 
     Ok(Instruction {
         data: ByteBuf::from(data.to_buf()),
-        accounts: accounts
-            .into_iter()
-            .chain(
-                stake_pool_handle
-                    .0
-                    .sol_withdraw_authority
-                    .into_iter()
-                    .map(|auth| AccountMeta::new(auth, Role::ReadonlySigner)),
-            )
-            .collect(),
+        accounts,
         program_address: program,
     })
 }
 
 impl WithdrawSolIxPrefixKeysHandle {
-    fn to_account_metas(&self) -> [AccountMeta; stake_pool_sdk::WITHDRAW_SOL_IX_PREFIX_ACCS_LEN] {
-        keys_signer_writer_to_account_metas(
-            &self.0.as_borrowed().0,
-            &stake_pool_sdk::WITHDRAW_SOL_IX_PREFIX_IS_SIGNER.0,
-            &stake_pool_sdk::WITHDRAW_SOL_IX_PREFIX_IS_WRITER.0,
+    /// Appends the suffix accounts required by `stake_pool`, if any
+    fn to_account_metas(&self, stake_pool: &stake_pool_sdk::StakePool) -> Box<[AccountMeta]> {
+        keys_signer_writer_iter_to_account_metas(
+            stake_pool_sdk::WithdrawSolIxFullKeysOwned::from_prefix_and_stake_pool(
+                self.0, stake_pool,
+            )
+            .keys_signer_writer(),
         )
     }
 }
@@ -2,7 +2,9 @@ use serde::{Deserialize, Serialize};
 use serde_bytes::ByteBuf;
 use tsify_next::Tsify;
 
-use sanctum_spl_stake_pool_core as stake_pool_sdk;
+use sanctum_spl_stake_pool_core::{
+    self as stake_pool_sdk, stake_account_rent_exempt_lamports, Rent,
+};
 use wasm_bindgen::{prelude::wasm_bindgen, JsError};
 
 use crate::{
@@ -12,7 +14,10 @@ use crate::{
     StakePoolHandle, B58PK,
 };
 
-use super::Instruction;
+use super::{
+    create_stake_account_ix_internal, stake_deactivate_ix_internal, Instruction, Instructions,
+    StakePoolDeployment,
+};
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Tsify)]
 #[tsify(into_wasm_abi, from_wasm_abi, large_number_types_as_bigints)]
@@ -29,8 +34,9 @@ pub struct WithdrawStakeIxPrefixKeysHandle(stake_pool_sdk::WithdrawStakeIxKeysOw
 #[tsify(into_wasm_abi, from_wasm_abi, large_number_types_as_bigints)]
 #[serde(rename_all = "camelCase")]
 pub struct WithdrawStakeIxUserAddrs {
-    pub program: B58PK,
-    pub stake_pool: B58PK,
+    #[serde(flatten)]
+    pub deployment: StakePoolDeployment,
+
     pub stake_to_split: B58PK,
     pub stake_to_receive: B58PK,
     pub user_stake_auth: B58PK,
@@ -44,8 +50,10 @@ pub struct WithdrawStakeIxUserAddrs {
 #[wasm_bindgen(js_name = withdrawStakeIxFromStakePool)]
 pub fn withdraw_stake_ix_from_stake_pool(
     WithdrawStakeIxUserAddrs {
-        program,
-        stake_pool,
+        deployment: StakePoolDeployment {
+            program,
+            stake_pool,
+        },
         stake_to_split,
         stake_to_receive,
         user_stake_auth,
@@ -82,6 +90,102 @@ pub fn withdraw_stake_ix_from_stake_pool(
     })
 }
 
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Tsify)]
+#[tsify(into_wasm_abi, from_wasm_abi, large_number_types_as_bigints)]
+#[serde(rename_all = "camelCase")]
+pub struct WithdrawStakeIxsArgs {
+    pub pool_tokens_in: u64,
+
+    /// Whether to deactivate the newly split off stake account.
+    ///
+    /// Must be false if withdrawing from the reserve,
+    /// since the received stake account will not be delegated.
+    pub deactivate: bool,
+
+    /// Contents of the rent sysvar, used to determine the rent-exempt
+    /// lamports to create `stake_to_receive` with.
+    /// Defaults to mainnet-beta's rent parameters if not provided.
+    #[tsify(optional)]
+    pub rent: Option<Rent>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Tsify)]
+#[tsify(into_wasm_abi, from_wasm_abi, large_number_types_as_bigints)]
+#[serde(rename_all = "camelCase")]
+pub struct WithdrawStakeIxsUserAddrs {
+    #[serde(flatten)]
+    pub deployment: StakePoolDeployment,
+
+    pub stake_to_split: B58PK,
+
+    /// Fresh account to create as the new stake account. Must sign.
+    pub stake_to_receive: B58PK,
+
+    pub user_stake_auth: B58PK,
+    pub user_transfer_auth: B58PK,
+    pub pool_tokens_from: B58PK,
+
+    /// Pays for the rent of `stake_to_receive`. Must sign.
+    pub payer: B58PK,
+}
+
+/// Returns the ordered instructions to withdraw stake to a fresh stake account:
+///
+/// 1. System program CreateAccount to create `stake_to_receive`
+///    with the stake account rent-exempt minimum
+/// 2. WithdrawStake
+/// 3. Stake program Deactivate on `stake_to_receive`, if `args.deactivate`.
+///    `user_stake_auth` must sign in this case.
+///
+/// Once the stake account has deactivated, it can be withdrawn from
+/// using the stake program Withdraw instruction.
+///
+/// @throws if
+/// - invalid pubkey was provided
+/// - PDAs can't be found
+#[wasm_bindgen(js_name = withdrawStakeIxsFromStakePool)]
+pub fn withdraw_stake_ixs_from_stake_pool(
+    WithdrawStakeIxsUserAddrs {
+        deployment,
+        stake_to_split,
+        stake_to_receive,
+        user_stake_auth,
+        user_transfer_auth,
+        pool_tokens_from,
+        payer,
+    }: WithdrawStakeIxsUserAddrs,
+    stake_pool_handle: &StakePoolHandle,
+    WithdrawStakeIxsArgs {
+        pool_tokens_in,
+        deactivate,
+        rent,
+    }: WithdrawStakeIxsArgs,
+) -> Result<Instructions, JsError> {
+    let create = create_stake_account_ix_internal(
+        &payer.0,
+        &stake_to_receive.0,
+        stake_account_rent_exempt_lamports(rent.as_ref()),
+    );
+    let deactivate =
+        deactivate.then(|| stake_deactivate_ix_internal(&stake_to_receive.0, &user_stake_auth.0));
+    let withdraw = withdraw_stake_ix_from_stake_pool(
+        WithdrawStakeIxUserAddrs {
+            deployment,
+            stake_to_split,
+            stake_to_receive,
+            user_stake_auth,
+            user_transfer_auth,
+            pool_tokens_from,
+        },
+        stake_pool_handle,
+        WithdrawStakeIxArgs { pool_tokens_in },
+    )?;
+
+    Ok(Instructions(
+        [create, withdraw].into_iter().chain(deactivate).collect(),
+    ))
+}
+
 impl WithdrawStakeIxPrefixKeysHandle {
     fn to_account_metas(&self) -> [AccountMeta; stake_pool_sdk::WITHDRAW_STAKE_IX_ACCS_LEN] {
         keys_signer_writer_to_account_metas(
@@ -0,0 +1,65 @@
+use serde::{Deserialize, Serialize};
+use tsify_next::Tsify;
+use wasm_bindgen::prelude::*;
+
+use crate::{StakePoolDeployment, B58PK};
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Tsify)]
+#[tsify(into_wasm_abi, from_wasm_abi, large_number_types_as_bigints)]
+#[serde(rename_all = "camelCase")]
+pub struct KnownStakePool {
+    /// Symbol of the pool's LST
+    pub symbol: String,
+    pub deployment: StakePoolDeployment,
+    pub pool_mint: B58PK,
+}
+
+impl KnownStakePool {
+    pub(crate) fn from_core(
+        sanctum_spl_stake_pool_core::KnownStakePool {
+            symbol,
+            deployment,
+            pool_mint,
+        }: &sanctum_spl_stake_pool_core::KnownStakePool,
+    ) -> Self {
+        Self {
+            symbol: (*symbol).to_owned(),
+            deployment: StakePoolDeployment {
+                program: B58PK::new(deployment.program),
+                stake_pool: B58PK::new(deployment.stake_pool),
+            },
+            pool_mint: B58PK::new(*pool_mint),
+        }
+    }
+}
+
+/// Program IDs of all known deploys of the SPL stake pool program
+#[wasm_bindgen(js_name = knownStakePoolPrograms)]
+pub fn known_stake_pool_programs() -> Vec<B58PK> {
+    sanctum_spl_stake_pool_core::STAKE_POOL_PROGRAMS
+        .into_iter()
+        .map(B58PK::new)
+        .collect()
+}
+
+#[wasm_bindgen(js_name = knownStakePools)]
+pub fn known_stake_pools() -> Vec<KnownStakePool> {
+    sanctum_spl_stake_pool_core::KNOWN_STAKE_POOLS
+        .iter()
+        .map(KnownStakePool::from_core)
+        .collect()
+}
+
+/// Returns `undefined` if `poolMint` is not the LST of a known stake pool
+#[wasm_bindgen(js_name = knownStakePoolByMint)]
+pub fn known_stake_pool_by_mint(pool_mint: B58PK) -> Option<KnownStakePool> {
+    sanctum_spl_stake_pool_core::known_stake_pool_by_mint(&pool_mint.0)
+        .map(KnownStakePool::from_core)
+}
+
+/// Returns `undefined` if `stakePool` is not a known stake pool
+#[wasm_bindgen(js_name = knownStakePoolByAddr)]
+pub fn known_stake_pool_by_addr(stake_pool: B58PK) -> Option<KnownStakePool> {
+    sanctum_spl_stake_pool_core::known_stake_pool_by_addr(&stake_pool.0)
+        .map(KnownStakePool::from_core)
+}
@@ -1,13 +1,19 @@
 mod err;
+mod filters;
 mod instructions;
+mod known_pools;
 mod pda;
 mod state;
+mod sysvars;
 mod typedefs;
 mod utils;
 
 pub use err::*;
+pub use filters::*;
 pub use instructions::*;
+pub use known_pools::*;
 pub use pda::*;
 pub use state::*;
+pub use sysvars::*;
 pub use typedefs::*;
 pub use utils::*;
@@ -2,14 +2,14 @@ use std::{iter::once, num::NonZeroU32};
 
 use ed25519_compact::{PublicKey, Signature};
 use sanctum_spl_stake_pool_core::{
-    deposit_auth_seeds, ephemeral_stake_seeds, transient_stake_seeds, validator_stake_seeds,
-    withdraw_auth_seeds,
+    ata_seeds, deposit_auth_seeds, ephemeral_stake_seeds, pool_token_ata_seeds,
+    transient_stake_seeds, validator_stake_seeds, withdraw_auth_seeds, ASSOCIATED_TOKEN_PROGRAM,
 };
 use serde::{Deserialize, Serialize};
 use tsify_next::Tsify;
 use wasm_bindgen::prelude::*;
 
-use crate::{err::no_valid_pda, B58PK};
+use crate::{err::no_valid_pda, StakePoolHandle, B58PK};
 
 #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize, Tsify)]
 #[tsify(into_wasm_abi, from_wasm_abi, large_number_types_as_bigints)]
@@ -96,6 +96,32 @@ pub fn find_ephemeral_stake_account_pda(
         .ok_or_else(no_valid_pda)
 }
 
+/// @throws
+/// - if pubkey params are not valid pubkey strings
+/// - if no valid PDA was found
+#[wasm_bindgen(js_name = findAta)]
+pub fn find_ata(owner: B58PK, token_program: B58PK, mint: B58PK) -> Result<FoundPda, JsError> {
+    find_ata_internal(&owner.0, &token_program.0, &mint.0)
+        .map(|(pk, bump)| FoundPda(B58PK::new(pk), bump))
+        .ok_or_else(no_valid_pda)
+}
+
+/// Finds `owner`'s associated token account for the pool's LST,
+/// using the pool's token program.
+///
+/// @throws
+/// - if pubkey params are not valid pubkey strings
+/// - if no valid PDA was found
+#[wasm_bindgen(js_name = findPoolTokenAta)]
+pub fn find_pool_token_ata(
+    stake_pool_handle: &StakePoolHandle,
+    owner: B58PK,
+) -> Result<FoundPda, JsError> {
+    find_pool_token_ata_internal(&stake_pool_handle.0, &owner.0)
+        .map(|(pk, bump)| FoundPda(B58PK::new(pk), bump))
+        .ok_or_else(no_valid_pda)
+}
+
 pub fn find_withdraw_auth_pda_internal(
     program_id: &[u8; 32],
     stake_pool_addr: &[u8; 32],
@@ -143,6 +169,29 @@ pub(crate) fn find_ephemeral_stake_account_pda_internal(
     find_pda(&[s1.as_slice(), s2.as_slice(), s3.as_slice()], program_id)
 }
 
+pub fn find_ata_internal(
+    owner: &[u8; 32],
+    token_program: &[u8; 32],
+    mint: &[u8; 32],
+) -> Option<([u8; 32], u8)> {
+    let (s1, s2, s3) = ata_seeds(owner, token_program, mint);
+    find_pda(
+        &[s1.as_slice(), s2.as_slice(), s3.as_slice()],
+        &ASSOCIATED_TOKEN_PROGRAM,
+    )
+}
+
+pub fn find_pool_token_ata_internal(
+    stake_pool: &sanctum_spl_stake_pool_core::StakePool,
+    owner: &[u8; 32],
+) -> Option<([u8; 32], u8)> {
+    let (s1, s2, s3) = pool_token_ata_seeds(owner, stake_pool);
+    find_pda(
+        &[s1.as_slice(), s2.as_slice(), s3.as_slice()],
+        &ASSOCIATED_TOKEN_PROGRAM,
+    )
+}
+
 /// maximum length of derived `Pubkey` seed
 const MAX_SEED_LEN: usize = 32;
 /// Maximum number of seeds
@@ -242,4 +291,28 @@ mod tests {
             }
         }
     }
+
+    proptest! {
+        #[test]
+        fn check_find_ata_against_solana(
+            owner: [u8; 32],
+            token_program: [u8; 32],
+            mint: [u8; 32],
+        ) {
+            let us = find_ata_internal(&owner, &token_program, &mint);
+            let sol = Pubkey::try_find_program_address(
+                &[owner.as_slice(), token_program.as_slice(), mint.as_slice()],
+                &Pubkey::new_from_array(ASSOCIATED_TOKEN_PROGRAM),
+            );
+
+            match (us, sol) {
+                (Some(us), Some(sol)) => {
+                    prop_assert_eq!(us.0, sol.0.to_bytes());
+                    prop_assert_eq!(us.1, sol.1);
+                }
+                (None, None) => (),
+                (us, sol) => panic!("us: {:#?}. sol: {:#?}", us, sol),
+            }
+        }
+    }
 }
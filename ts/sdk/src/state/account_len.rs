@@ -0,0 +1,48 @@
+use sanctum_spl_stake_pool_core::{Rent, StakePool, ValidatorList};
+use wasm_bindgen::prelude::*;
+
+use crate::err::arithmetic_overflow_err;
+
+/// Size of a stake pool account's data in bytes
+#[wasm_bindgen(js_name = stakePoolAccountLen)]
+pub fn stake_pool_account_len() -> usize {
+    StakePool::ACCOUNT_LEN
+}
+
+/// Size in bytes of a validator list account's data
+/// that can hold up to `maxValidators` validators
+///
+/// @throws if the size overflows
+#[wasm_bindgen(js_name = validatorListAccountLen)]
+pub fn validator_list_account_len(max_validators: u32) -> Result<usize, JsError> {
+    ValidatorList::account_len(max_validators).ok_or_else(arithmetic_overflow_err)
+}
+
+/// Max number of validators a validator list account with `accountLen` bytes of data can hold
+#[wasm_bindgen(js_name = maxValidatorsForAccountLen)]
+pub fn max_validators_for_account_len(account_len: usize) -> u32 {
+    ValidatorList::max_validators_for_account_len(account_len)
+}
+
+/// Minimum lamports a stake pool account must hold to be rent-exempt.
+///
+/// `rent` defaults to mainnet-beta's rent parameters if not provided
+#[wasm_bindgen(js_name = stakePoolRentExemptLamports)]
+pub fn stake_pool_rent_exempt_lamports(rent: Option<Rent>) -> u64 {
+    StakePool::rent_exempt_lamports(rent.as_ref())
+}
+
+/// Minimum lamports a validator list account that can hold up to `maxValidators`
+/// validators must hold to be rent-exempt.
+///
+/// `rent` defaults to mainnet-beta's rent parameters if not provided
+///
+/// @throws if the account size overflows
+#[wasm_bindgen(js_name = validatorListRentExemptLamports)]
+pub fn validator_list_rent_exempt_lamports(
+    max_validators: u32,
+    rent: Option<Rent>,
+) -> Result<u64, JsError> {
+    ValidatorList::rent_exempt_lamports(max_validators, rent.as_ref())
+        .ok_or_else(arithmetic_overflow_err)
+}
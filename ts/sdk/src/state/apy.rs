@@ -0,0 +1,85 @@
+use sanctum_spl_stake_pool_core::{self as stake_pool_sdk, RateChange};
+use serde::{Deserialize, Serialize};
+use tsify_next::Tsify;
+use wasm_bindgen::prelude::*;
+
+use crate::{StakePool, StakePoolHandle};
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Tsify)]
+#[tsify(into_wasm_abi, from_wasm_abi, large_number_types_as_bigints)]
+#[serde(rename_all = "camelCase")]
+pub struct StakePoolSnapshot {
+    pub epoch: u64,
+    pub stake_pool: StakePool,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Tsify)]
+#[tsify(into_wasm_abi, from_wasm_abi, large_number_types_as_bigints)]
+pub struct StakePoolSnapshots(pub Vec<StakePoolSnapshot>);
+
+/// Epoch-over-epoch change in SOL-per-LST exchange rate
+/// of the last UpdateStakePoolBalance, net of epoch fees,
+/// e.g. `0.0005` for a 0.05% increase.
+///
+/// Returns `undefined` if the pool has no recorded last epoch exchange rate
+#[wasm_bindgen(js_name = epochRateChange)]
+pub fn epoch_rate_change(this: &StakePoolHandle) -> Option<f64> {
+    this.0.epoch_rate_change().map(|r| r.rate_change)
+}
+
+/// Annualized `epochRateChange()`, compounding every epoch,
+/// given the average duration of an epoch in seconds.
+///
+/// Returns `undefined` if the pool has no recorded last epoch exchange rate
+#[wasm_bindgen(js_name = apy)]
+pub fn apy(this: &StakePoolHandle, epoch_duration_secs: f64) -> Option<f64> {
+    this.0.apy(epoch_duration_secs)
+}
+
+/// Projects the SOL-per-LST exchange rate change of the `n`th UpdateStakePoolBalance
+/// from now (starting from 1) given the gross (before epoch fee) staking rewards rate
+/// for that epoch, taking scheduled epoch fee changes into account.
+///
+/// Returns `undefined` if the epoch fee that will be levied is invalid
+#[wasm_bindgen(js_name = projectedEpochRateChange)]
+pub fn projected_epoch_rate_change(
+    this: &StakePoolHandle,
+    gross_reward_rate: f64,
+    n: u64,
+) -> Option<f64> {
+    this.0.projected_epoch_rate_change(gross_reward_rate, n)
+}
+
+/// Change in SOL-per-LST exchange rate between the snapshots
+/// with the earliest and latest epoch.
+///
+/// Returns `undefined` if there are no snapshots with nonzero total lamports and pool token supply
+///
+/// @throws if any snapshot contains invalid pubkeys
+#[wasm_bindgen(js_name = rateChangeFromSnapshots)]
+pub fn rate_change_from_snapshots(
+    StakePoolSnapshots(snapshots): StakePoolSnapshots,
+) -> Result<Option<RateChange>, JsError> {
+    let snapshots: Vec<_> = snapshots
+        .iter()
+        .map(|s| Ok((s.epoch, s.stake_pool.try_to_core()?)))
+        .collect::<Result<_, JsError>>()?;
+    Ok(stake_pool_sdk::rate_change_from_snapshots(
+        snapshots.iter().map(|(epoch, pool)| (*epoch, pool)),
+    ))
+}
+
+/// Annualized `rateChangeFromSnapshots()`, compounding every epoch,
+/// given the average duration of an epoch in seconds.
+///
+/// Returns `undefined` if there are less than 2 snapshots at different epochs
+/// with nonzero total lamports and pool token supply
+///
+/// @throws if any snapshot contains invalid pubkeys
+#[wasm_bindgen(js_name = apyFromSnapshots)]
+pub fn apy_from_snapshots(
+    snapshots: StakePoolSnapshots,
+    epoch_duration_secs: f64,
+) -> Result<Option<f64>, JsError> {
+    Ok(rate_change_from_snapshots(snapshots)?.and_then(|r| r.apy(epoch_duration_secs)))
+}
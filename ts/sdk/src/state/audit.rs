@@ -0,0 +1,57 @@
+use sanctum_spl_stake_pool_core::{
+    audit_pool_itr, PoolAuditFinding, ValidatorStakeAccountsLamports,
+};
+use serde::{Deserialize, Serialize};
+use tsify_next::Tsify;
+use wasm_bindgen::prelude::wasm_bindgen;
+
+use crate::{StakePoolHandle, ValidatorListHandle};
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Tsify)]
+#[tsify(into_wasm_abi, from_wasm_abi, large_number_types_as_bigints)]
+#[serde(rename_all = "camelCase")]
+pub struct AuditPoolArgs {
+    /// Lamports of the reserve stake account, including rent
+    pub reserve_stake_lamports: u64,
+
+    /// Supply of the pool mint
+    pub pool_mint_supply: u64,
+
+    /// Lamports of each validator's stake accounts, in validator list order.
+    /// Validators without an entry, or with a `null` entry, are not checked against their stake accounts.
+    #[tsify(optional)]
+    pub stake_accounts_lamports: Option<Vec<Option<ValidatorStakeAccountsLamports>>>,
+}
+
+/// Checks the internal consistency of a pool's on-chain state, returning
+/// every inconsistency found. Returns an empty array if the state is consistent.
+///
+/// - reserve lamports minus rent plus all validators' active and transient lamports must equal `totalLamports`
+/// - pool mint supply must equal `poolTokenSupply`
+/// - referral fees must be at most 100%
+/// - fees with non-zero numerators must have non-zero denominators
+/// - fees must be at most 100%
+/// - vote accounts must not be duplicated
+/// - validators must not have been updated for an epoch after the pool's
+/// - validators' recorded lamports must match their stake accounts', if provided
+/// - validators' statuses must be consistent with which of their stake accounts exist, if provided
+#[wasm_bindgen(js_name = auditPool)]
+pub fn audit_pool(
+    stake_pool_handle: &StakePoolHandle,
+    validator_list_handle: &ValidatorListHandle,
+    AuditPoolArgs {
+        reserve_stake_lamports,
+        pool_mint_supply,
+        stake_accounts_lamports,
+    }: AuditPoolArgs,
+) -> Vec<PoolAuditFinding> {
+    let stake_accounts_lamports = stake_accounts_lamports.unwrap_or_default();
+    audit_pool_itr(
+        &stake_pool_handle.0,
+        &validator_list_handle.0.as_borrowed(),
+        reserve_stake_lamports,
+        pool_mint_supply,
+        |i, _| stake_accounts_lamports.get(i).copied().flatten(),
+    )
+    .collect()
+}
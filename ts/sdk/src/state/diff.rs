@@ -0,0 +1,122 @@
+use sanctum_spl_stake_pool_core::{
+    stake_pool_diff_itr, validator_list_diff_itr, Fee, PoolFeeField, PoolReferralFeeField,
+    PreferredValidatorField, StakePoolAuthorityField, ValidatorListChange,
+};
+use serde::{Deserialize, Serialize};
+use tsify_next::Tsify;
+use wasm_bindgen::prelude::wasm_bindgen;
+
+use crate::{StakePoolHandle, ValidatorListHandle, B58PK};
+
+/// A single change between two snapshots of a stake pool
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Tsify)]
+#[tsify(into_wasm_abi, from_wasm_abi, large_number_types_as_bigints)]
+#[serde(rename_all = "camelCase", rename_all_fields = "camelCase")]
+pub enum StakePoolChange {
+    /// `undefined` for optional authorities that are not set
+    Authority {
+        field: StakePoolAuthorityField,
+        old: Option<B58PK>,
+        new: Option<B58PK>,
+    },
+
+    /// `undefined` for next fees that are not set
+    Fee {
+        field: PoolFeeField,
+        old: Option<Fee>,
+        new: Option<Fee>,
+    },
+
+    ReferralFee {
+        field: PoolReferralFeeField,
+        old: u8,
+        new: u8,
+    },
+
+    TotalLamports {
+        old: u64,
+        new: u64,
+    },
+
+    PoolTokenSupply {
+        old: u64,
+        new: u64,
+    },
+
+    PreferredValidator {
+        field: PreferredValidatorField,
+        old: Option<B58PK>,
+        new: Option<B58PK>,
+    },
+}
+
+impl StakePoolChange {
+    pub(crate) fn from_core(change: sanctum_spl_stake_pool_core::StakePoolChange) -> Self {
+        use sanctum_spl_stake_pool_core::StakePoolChange as C;
+
+        match change {
+            C::Authority { field, old, new } => Self::Authority {
+                field,
+                old: old.map(B58PK::new),
+                new: new.map(B58PK::new),
+            },
+            C::Fee { field, old, new } => Self::Fee { field, old, new },
+            C::ReferralFee { field, old, new } => Self::ReferralFee { field, old, new },
+            C::TotalLamports { old, new } => Self::TotalLamports { old, new },
+            C::PoolTokenSupply { old, new } => Self::PoolTokenSupply { old, new },
+            C::PreferredValidator { field, old, new } => Self::PreferredValidator {
+                field,
+                old: old.map(B58PK::new),
+                new: new.map(B58PK::new),
+            },
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Tsify)]
+#[tsify(into_wasm_abi, from_wasm_abi, large_number_types_as_bigints)]
+#[serde(rename_all = "camelCase")]
+pub struct ValidatorListChangeWithVote {
+    pub vote_account: B58PK,
+    pub change: ValidatorListChange,
+}
+
+/// Returns every change from `old` to `new`:
+/// authority, fee and referral fee changes, total lamports and
+/// pool token supply movement, and preferred validator changes.
+///
+/// Scheduled next fees are compared by value only.
+#[wasm_bindgen(js_name = stakePoolDiff)]
+pub fn stake_pool_diff(old: &StakePoolHandle, new: &StakePoolHandle) -> Vec<StakePoolChange> {
+    stake_pool_diff_itr(&old.0, &new.0)
+        .map(StakePoolChange::from_core)
+        .collect()
+}
+
+/// Returns every change from `old` to `new`, with validators matched by vote account:
+/// removals in old list order, then additions, status changes
+/// and active/transient stake lamports changes in new list order.
+#[wasm_bindgen(js_name = validatorListDiff)]
+pub fn validator_list_diff(
+    old: &ValidatorListHandle,
+    new: &ValidatorListHandle,
+) -> Vec<ValidatorListChangeWithVote> {
+    let old = old.0.as_borrowed();
+    let new = new.0.as_borrowed();
+    validator_list_diff_itr(&old, &new)
+        .map(|change| {
+            let vsi = match change {
+                ValidatorListChange::Removed { old_index } => &old.validators[old_index],
+                ValidatorListChange::Added { new_index }
+                | ValidatorListChange::StatusChanged { new_index, .. }
+                | ValidatorListChange::LamportsChanged { new_index, .. } => {
+                    &new.validators[new_index]
+                }
+            };
+            ValidatorListChangeWithVote {
+                vote_account: B58PK::new(*vsi.vote_account_address()),
+                change,
+            }
+        })
+        .collect()
+}
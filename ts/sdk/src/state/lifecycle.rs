@@ -0,0 +1,87 @@
+use std::collections::HashSet;
+
+use sanctum_spl_stake_pool_core::{
+    validator_adds_plan_itr, validator_lifecycle_plan_itr, ValidatorAddStatus, ValidatorAddStep,
+    ValidatorLifecycleStep,
+};
+use serde::{Deserialize, Serialize};
+use tsify_next::Tsify;
+use wasm_bindgen::prelude::wasm_bindgen;
+
+use crate::{ValidatorListHandle, B58PK};
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Tsify)]
+#[tsify(into_wasm_abi, from_wasm_abi, large_number_types_as_bigints)]
+#[serde(rename_all = "camelCase")]
+pub struct ValidatorLifecycleArgs {
+    /// Lamports of the reserve stake account that may be used for adds and decreases
+    pub reserve_stake_lamports: u64,
+    pub current_epoch: u64,
+
+    /// Vote accounts of the validators the pool should contain
+    pub target_vote_accounts: Vec<B58PK>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Tsify)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+#[serde(rename_all = "camelCase")]
+pub struct ValidatorAddPlan {
+    pub vote_account: B58PK,
+    pub status: ValidatorAddStatus,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Tsify)]
+#[tsify(into_wasm_abi, from_wasm_abi, large_number_types_as_bigints)]
+#[serde(rename_all = "camelCase")]
+pub struct ValidatorLifecyclePlan {
+    /// One step for each validator on the validator list, in validator list order
+    pub steps: Vec<ValidatorLifecycleStep>,
+
+    /// One entry for each target validator that is not yet active on the validator list.
+    /// Repeats of a fundable target vote account are omitted.
+    pub adds: Vec<ValidatorAddPlan>,
+}
+
+/// Plans this epoch's actions that move the pool towards containing
+/// exactly the validators in `args.targetVoteAccounts`.
+///
+/// - non-target validators with more than the minimum stake are decreased before removal
+/// - removed validators are awaited through deactivation, then cleaned up
+/// - adds are funded from the reserve after the rent of planned decreases, keeping it rent-exempt
+///
+/// Each step's `nextStatus` is the validator's expected status after
+/// the validator list is updated for the next epoch.
+#[wasm_bindgen(js_name = validatorLifecyclePlan)]
+pub fn validator_lifecycle_plan(
+    validator_list_handle: &ValidatorListHandle,
+    ValidatorLifecycleArgs {
+        reserve_stake_lamports,
+        current_epoch,
+        target_vote_accounts,
+    }: ValidatorLifecycleArgs,
+) -> ValidatorLifecyclePlan {
+    let validator_list = validator_list_handle.0.as_borrowed();
+    let targets: HashSet<[u8; 32]> = target_vote_accounts.iter().map(|pk| pk.0).collect();
+
+    let steps: Vec<_> = validator_lifecycle_plan_itr(&validator_list, current_epoch, |vote| {
+        targets.contains(vote)
+    })
+    .collect();
+    let reserve_stake_lamports = steps.iter().fold(reserve_stake_lamports, |reserve, step| {
+        reserve.saturating_sub(step.reserve_lamports_cost())
+    });
+
+    let adds = validator_adds_plan_itr(
+        &validator_list,
+        reserve_stake_lamports,
+        target_vote_accounts.iter().map(|pk| &pk.0),
+    )
+    .filter(|ValidatorAddStep { status, .. }| *status != ValidatorAddStatus::AlreadyAdded)
+    .map(|ValidatorAddStep { add_index, status }| ValidatorAddPlan {
+        vote_account: B58PK::new(target_vote_accounts[add_index].0),
+        status,
+    })
+    .collect();
+
+    ValidatorLifecyclePlan { steps, adds }
+}
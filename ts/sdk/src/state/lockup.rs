@@ -0,0 +1,67 @@
+use sanctum_spl_stake_pool_core::Clock;
+use serde::{Deserialize, Serialize};
+use tsify_next::Tsify;
+use wasm_bindgen::prelude::*;
+
+use crate::{err::not_stake_account_with_lockup, Lockup, StakePoolHandle, B58PK};
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Tsify)]
+#[tsify(into_wasm_abi, from_wasm_abi, large_number_types_as_bigints)]
+#[serde(rename_all = "camelCase")]
+pub struct LockupCheckArgs {
+    pub clock: Clock,
+
+    /// Custodian signing the transaction, if any
+    #[tsify(optional)]
+    pub custodian: Option<B58PK>,
+}
+
+/// Whether `lockup` prevents withdrawals from a stake account
+///
+/// @throws if `lockup` contains invalid pubkeys
+#[wasm_bindgen(js_name = lockupIsInForce)]
+pub fn lockup_is_in_force(
+    lockup: Lockup,
+    LockupCheckArgs { clock, custodian }: LockupCheckArgs,
+) -> Result<bool, JsError> {
+    Ok(lockup
+        .try_to_core()?
+        .is_in_force(&clock, custodian.as_ref().map(|c| &c.0)))
+}
+
+/// Returns the lockup of an initialized or delegated stake account
+///
+/// @throws if `stakeAccountData` is not that of an initialized or delegated stake account
+#[wasm_bindgen(js_name = stakeAccountLockup)]
+pub fn stake_account_lockup(stake_account_data: &[u8]) -> Result<Lockup, JsError> {
+    sanctum_spl_stake_pool_core::Lockup::from_stake_account_data(stake_account_data)
+        .map(|l| Lockup::from_core(&l))
+        .ok_or_else(not_stake_account_with_lockup)
+}
+
+/// @throws if
+/// - `stakeAccountData` is not that of an initialized or delegated stake account
+/// - the stake account's lockup is not the same as the pool's
+#[wasm_bindgen(js_name = checkDepositStakeLockup)]
+pub fn check_deposit_stake_lockup(
+    this: &StakePoolHandle,
+    stake_account_data: &[u8],
+) -> Result<(), JsError> {
+    let lockup = sanctum_spl_stake_pool_core::Lockup::from_stake_account_data(stake_account_data)
+        .ok_or_else(not_stake_account_with_lockup)?;
+    Ok(this.0.check_deposit_stake_lockup(&lockup)?)
+}
+
+/// Stake accounts split off by WithdrawStake inherit the pool's lockup.
+///
+/// @throws if the pool's lockup is in force, in which case the withdrawn stake account
+/// cannot be withdrawn from until it expires
+#[wasm_bindgen(js_name = checkWithdrawnStakeLockup)]
+pub fn check_withdrawn_stake_lockup(
+    this: &StakePoolHandle,
+    LockupCheckArgs { clock, custodian }: LockupCheckArgs,
+) -> Result<(), JsError> {
+    Ok(this
+        .0
+        .check_withdrawn_stake_lockup(&clock, custodian.as_ref().map(|c| &c.0))?)
+}
@@ -1,5 +1,23 @@
+mod account_len;
+mod apy;
+mod audit;
+mod diff;
+mod lifecycle;
+mod lockup;
 mod pool;
+mod report;
+mod transient;
+mod update_status;
 mod validator_list;
 
+pub use account_len::*;
+pub use apy::*;
+pub use audit::*;
+pub use diff::*;
+pub use lifecycle::*;
+pub use lockup::*;
 pub use pool::*;
+pub use report::*;
+pub use transient::*;
+pub use update_status::*;
 pub use validator_list::*;
@@ -1,6 +1,6 @@
 use sanctum_spl_stake_pool_core::{
     AccountType, DepositSolQuote, DepositStakeQuote, Fee, FutureEpoch, StakeAccountLamports,
-    WithdrawSolQuote, WithdrawStakeQuote,
+    StakePoolFees, WithdrawSolQuote, WithdrawStakeQuote,
 };
 use serde::{Deserialize, Serialize};
 use tsify_next::Tsify;
@@ -54,6 +54,17 @@ pub fn quote_deposit_sol(
         .ok_or_else(arithmetic_overflow_err)
 }
 
+/// @throws on arithmetic overflow
+#[wasm_bindgen(js_name = quoteRevDepositSol)]
+pub fn quote_rev_deposit_sol(
+    this: &StakePoolHandle,
+    pool_tokens: u64,
+) -> Result<DepositSolQuote, JsError> {
+    this.0
+        .quote_rev_deposit_sol_unchecked(pool_tokens)
+        .ok_or_else(arithmetic_overflow_err)
+}
+
 /// @throws on arithmetic overflow
 #[wasm_bindgen(js_name = quoteDepositStake)]
 pub fn quote_deposit_stake(
@@ -109,6 +120,24 @@ pub fn quote_rev_withdraw_stake(
         .ok_or_else(arithmetic_overflow_err)
 }
 
+/// Fees that will be in effect at `epoch` once the pool has been updated for it,
+/// assuming it is updated every epoch and the manager does not schedule any more fee changes.
+#[wasm_bindgen(js_name = effectiveFeesAt)]
+pub fn effective_fees_at(this: &StakePoolHandle, epoch: u64) -> StakePoolFees {
+    this.0.effective_fees_at(epoch)
+}
+
+/// Returns a copy of the pool as it will be once updated for `epoch`,
+/// with fees set to `effectiveFeesAt(epoch)`, so that the `quote*()` functions
+/// can be evaluated against the future fee schedule.
+///
+/// Total lamports and pool token supply are left unchanged since
+/// the epoch's staking rewards are unknown.
+#[wasm_bindgen(js_name = projectedToEpoch)]
+pub fn projected_to_epoch(this: &StakePoolHandle, epoch: u64) -> StakePoolHandle {
+    StakePoolHandle(this.0.projected_to_epoch(epoch))
+}
+
 #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize, Tsify)]
 #[tsify(into_wasm_abi, from_wasm_abi, large_number_types_as_bigints)]
 #[serde(rename_all = "camelCase")]
@@ -0,0 +1,59 @@
+use sanctum_spl_stake_pool_core::{validators_near_min_stake_itr, PoolHealthReport};
+use serde::{Deserialize, Serialize};
+use tsify_next::Tsify;
+use wasm_bindgen::prelude::wasm_bindgen;
+
+use crate::{StakePoolHandle, ValidatorListHandle};
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Tsify)]
+#[tsify(into_wasm_abi, from_wasm_abi, large_number_types_as_bigints)]
+#[serde(rename_all = "camelCase")]
+pub struct PoolHealthReportArgs {
+    /// Lamports of the reserve stake account, including rent
+    pub reserve_stake_lamports: u64,
+
+    /// Number of largest validators to compute the stake share of
+    pub top_n: u32,
+
+    /// Validators with active stake within this many lamports of the minimum
+    /// are reported as near the minimum. Defaults to 0 if not provided.
+    #[tsify(optional)]
+    pub near_min_stake_margin_lamports: Option<u64>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Tsify)]
+#[tsify(into_wasm_abi, from_wasm_abi, large_number_types_as_bigints)]
+#[serde(rename_all = "camelCase")]
+pub struct PoolHealthReportWithValidators {
+    pub report: PoolHealthReport,
+
+    /// Validator list indices of active validators at or near the minimum active stake
+    pub validators_near_min_stake_indices: Vec<usize>,
+}
+
+/// Computes stake concentration, reserve ratio, transient stake in flight,
+/// validator counts by status and estimated withdrawal liquidity of a pool.
+#[wasm_bindgen(js_name = poolHealthReport)]
+pub fn pool_health_report(
+    stake_pool_handle: &StakePoolHandle,
+    validator_list_handle: &ValidatorListHandle,
+    PoolHealthReportArgs {
+        reserve_stake_lamports,
+        top_n,
+        near_min_stake_margin_lamports,
+    }: PoolHealthReportArgs,
+) -> PoolHealthReportWithValidators {
+    let validator_list = validator_list_handle.0.as_borrowed();
+    let margin = near_min_stake_margin_lamports.unwrap_or(0);
+    PoolHealthReportWithValidators {
+        report: PoolHealthReport::new(
+            &stake_pool_handle.0,
+            &validator_list,
+            reserve_stake_lamports,
+            top_n,
+            margin,
+        ),
+        validators_near_min_stake_indices: validators_near_min_stake_itr(&validator_list, margin)
+            .collect(),
+    }
+}
@@ -0,0 +1,60 @@
+use sanctum_spl_stake_pool_core::{AdditionalValidatorStakeCheckArgs, TransientStakeState};
+use wasm_bindgen::prelude::*;
+
+use crate::{
+    err::{not_delegated_stake_account, validator_idx_oob},
+    ValidatorListHandle,
+};
+
+/// Returns whether a validator's existing transient stake account can be topped up this epoch
+/// or must wait to be merged by UpdateValidatorListBalance.
+///
+/// @throws if `stakeAccountData` is not that of a delegated stake account
+#[wasm_bindgen(js_name = transientStakeState)]
+pub fn transient_stake_state(
+    stake_account_data: &[u8],
+    current_epoch: u64,
+) -> Result<TransientStakeState, JsError> {
+    TransientStakeState::from_stake_account_data(stake_account_data, current_epoch)
+        .ok_or_else(not_delegated_stake_account)
+}
+
+/// Returns the transient stake seed an IncreaseAdditionalValidatorStake instruction
+/// for the validator at `validatorIdx` should use.
+///
+/// @throws if
+/// - validator index is out of bounds
+/// - the instruction would fail this epoch, with the reason as the error message
+#[wasm_bindgen(js_name = checkIncreaseAdditionalValidatorStake)]
+pub fn check_increase_additional_validator_stake(
+    validator_list_handle: &ValidatorListHandle,
+    validator_idx: usize,
+    args: AdditionalValidatorStakeCheckArgs,
+) -> Result<u64, JsError> {
+    let validator_list = validator_list_handle.0.as_borrowed();
+    let vsi = validator_list
+        .validators
+        .get(validator_idx)
+        .ok_or_else(validator_idx_oob)?;
+    Ok(vsi.check_increase_additional_stake(&args)?)
+}
+
+/// Returns the transient stake seed a DecreaseAdditionalValidatorStake instruction
+/// for the validator at `validatorIdx` should use.
+///
+/// @throws if
+/// - validator index is out of bounds
+/// - the instruction would fail this epoch, with the reason as the error message
+#[wasm_bindgen(js_name = checkDecreaseAdditionalValidatorStake)]
+pub fn check_decrease_additional_validator_stake(
+    validator_list_handle: &ValidatorListHandle,
+    validator_idx: usize,
+    args: AdditionalValidatorStakeCheckArgs,
+) -> Result<u64, JsError> {
+    let validator_list = validator_list_handle.0.as_borrowed();
+    let vsi = validator_list
+        .validators
+        .get(validator_idx)
+        .ok_or_else(validator_idx_oob)?;
+    Ok(vsi.check_decrease_additional_stake(&args)?)
+}
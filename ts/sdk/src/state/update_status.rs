@@ -0,0 +1,47 @@
+use sanctum_spl_stake_pool_core::UpdateStatus;
+use serde::{Deserialize, Serialize};
+use tsify_next::Tsify;
+use wasm_bindgen::prelude::*;
+
+use crate::{max_validators_per_ix_or_default, StakePoolHandle, ValidatorListHandle};
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Tsify)]
+#[tsify(into_wasm_abi, from_wasm_abi, large_number_types_as_bigints)]
+#[serde(rename_all = "camelCase")]
+pub struct StakePoolUpdateStatus {
+    /// Indices of the validator list entries not yet updated for the current epoch
+    pub stale_validator_indices: Vec<usize>,
+
+    #[serde(flatten)]
+    pub status: UpdateStatus,
+}
+
+/// Checks whether the pool needs to be updated for `current_epoch`
+/// and how many instructions and transactions that will take.
+///
+/// The counts match the instructions returned by `updateStakePoolIxsFromStakePool`
+/// with the same `currentEpoch` and `maxValidatorsPerIx`.
+///
+/// `maxValidatorsPerIx` defaults to the max number that fits in a transaction if 0 or not provided.
+#[wasm_bindgen(js_name = getUpdateStatus)]
+pub fn get_update_status(
+    stake_pool_handle: &StakePoolHandle,
+    validator_list_handle: &ValidatorListHandle,
+    current_epoch: u64,
+    max_validators_per_ix: Option<usize>,
+) -> StakePoolUpdateStatus {
+    let validator_list = validator_list_handle.0.as_borrowed();
+    let max_validators_per_ix = max_validators_per_ix_or_default(max_validators_per_ix);
+
+    StakePoolUpdateStatus {
+        stale_validator_indices: validator_list
+            .stale_validator_indices_itr(current_epoch)
+            .collect(),
+        status: UpdateStatus::new(
+            &stake_pool_handle.0,
+            &validator_list,
+            current_epoch,
+            max_validators_per_ix,
+        ),
+    }
+}
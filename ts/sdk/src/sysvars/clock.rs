@@ -0,0 +1,36 @@
+use sanctum_spl_stake_pool_core::{Clock, EpochSchedule, DEFAULT_MS_PER_SLOT};
+use wasm_bindgen::{prelude::wasm_bindgen, JsError};
+
+use crate::err::invalid_epoch_schedule;
+
+/// @throws if bytes do not make up a valid clock sysvar account
+#[wasm_bindgen(js_name = deserClock)]
+pub fn deser_clock(bytes: &[u8]) -> Result<Clock, JsError> {
+    Ok(Clock::borsh_de(bytes)?)
+}
+
+/// Index of `clock.slot` in the current epoch
+///
+/// @throws if `epochSchedule.slotsPerEpoch` is 0
+#[wasm_bindgen(js_name = slotIndex)]
+pub fn slot_index(clock: Clock, epoch_schedule: EpochSchedule) -> Result<u64, JsError> {
+    clock
+        .slot_index(&epoch_schedule)
+        .ok_or_else(invalid_epoch_schedule)
+}
+
+/// Estimated number of seconds until the next epoch boundary.
+///
+/// `msPerSlot` defaults to 400 if not provided
+///
+/// @throws if `epochSchedule.slotsPerEpoch` is 0
+#[wasm_bindgen(js_name = estSecsUntilNextEpoch)]
+pub fn est_secs_until_next_epoch(
+    clock: Clock,
+    epoch_schedule: EpochSchedule,
+    ms_per_slot: Option<u64>,
+) -> Result<u64, JsError> {
+    clock
+        .est_secs_until_next_epoch(&epoch_schedule, ms_per_slot.unwrap_or(DEFAULT_MS_PER_SLOT))
+        .ok_or_else(invalid_epoch_schedule)
+}
@@ -0,0 +1,32 @@
+use sanctum_spl_stake_pool_core::EpochSchedule;
+use wasm_bindgen::{prelude::wasm_bindgen, JsError};
+
+use crate::err::invalid_epoch_schedule;
+
+/// Epoch schedule of mainnet-beta
+#[wasm_bindgen(js_name = defaultEpochSchedule)]
+pub fn default_epoch_schedule() -> EpochSchedule {
+    EpochSchedule::DEFAULT
+}
+
+/// @throws if bytes do not make up a valid epoch schedule sysvar account
+#[wasm_bindgen(js_name = deserEpochSchedule)]
+pub fn deser_epoch_schedule(bytes: &[u8]) -> Result<EpochSchedule, JsError> {
+    Ok(EpochSchedule::borsh_de(bytes)?)
+}
+
+/// Epoch that `slot` is in
+///
+/// @throws if `epochSchedule.slotsPerEpoch` is 0
+#[wasm_bindgen(js_name = epochOfSlot)]
+pub fn epoch_of_slot(epoch_schedule: EpochSchedule, slot: u64) -> Result<u64, JsError> {
+    epoch_schedule
+        .epoch(slot)
+        .ok_or_else(invalid_epoch_schedule)
+}
+
+/// First slot of `epoch`
+#[wasm_bindgen(js_name = firstSlotInEpoch)]
+pub fn first_slot_in_epoch(epoch_schedule: EpochSchedule, epoch: u64) -> u64 {
+    epoch_schedule.first_slot_in_epoch(epoch)
+}
@@ -0,0 +1,20 @@
+use sanctum_spl_stake_pool_core::Rent;
+use wasm_bindgen::{prelude::wasm_bindgen, JsError};
+
+/// Rent parameters of mainnet-beta
+#[wasm_bindgen(js_name = defaultRent)]
+pub fn default_rent() -> Rent {
+    Rent::DEFAULT
+}
+
+/// @throws if bytes do not make up a valid rent sysvar account
+#[wasm_bindgen(js_name = deserRent)]
+pub fn deser_rent(bytes: &[u8]) -> Result<Rent, JsError> {
+    Ok(Rent::borsh_de(bytes)?)
+}
+
+/// Minimum lamports an account with `dataLen` bytes of data must hold to be rent-exempt
+#[wasm_bindgen(js_name = rentMinimumBalance)]
+pub fn rent_minimum_balance(rent: Rent, data_len: usize) -> u64 {
+    rent.minimum_balance(data_len)
+}
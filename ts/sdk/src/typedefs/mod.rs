@@ -1,5 +1,7 @@
 mod lockup;
+mod stake_status;
 mod validator_stake_info;
 
 pub use lockup::*;
+pub use stake_status::*;
 pub use validator_stake_info::*;
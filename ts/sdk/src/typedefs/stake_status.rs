@@ -0,0 +1,31 @@
+use sanctum_spl_stake_pool_core::{StakeStatus, StakeStatusEvent, ValidatorStakeAccounts};
+use wasm_bindgen::{prelude::wasm_bindgen, JsError};
+
+use crate::invalid_stake_status_transition;
+
+/// Returns the status of a validator list entry with status `status` after `event`,
+/// or `undefined` if the entry is deleted.
+///
+/// `accounts` are the validator's stake accounts that exist at the time of
+/// `RemoveValidator` and `Cleanup`, or after `Update` has merged
+/// fully deactivated stake accounts into the reserve.
+///
+/// @throws if the program never performs the transition,
+/// e.g. `accounts` is impossible for `status`, or removing a validator that is not active
+#[wasm_bindgen(js_name = stakeStatusTransition)]
+pub fn stake_status_transition(
+    status: StakeStatus,
+    event: StakeStatusEvent,
+    accounts: ValidatorStakeAccounts,
+) -> Result<Option<StakeStatus>, JsError> {
+    status
+        .transition(event, accounts)
+        .map_err(|_| invalid_stake_status_transition())
+}
+
+/// Returns true if `accounts` are the stake accounts that can exist
+/// for a validator list entry with status `status`
+#[wasm_bindgen(js_name = isStakeStatusConsistent)]
+pub fn is_stake_status_consistent(status: StakeStatus, accounts: ValidatorStakeAccounts) -> bool {
+    status.is_consistent_with(accounts)
+}
@@ -40,6 +40,15 @@ pub fn keys_signer_writer_to_account_metas<const N: usize>(
     })
 }
 
+pub fn keys_signer_writer_iter_to_account_metas<'a>(
+    keys_signer_writer: impl IntoIterator<Item = (&'a [u8; 32], bool, bool)>,
+) -> Box<[AccountMeta]> {
+    keys_signer_writer
+        .into_iter()
+        .map(|(k, signer, writer)| AccountMeta::new(*k, Role::from_signer_writable(signer, writer)))
+        .collect()
+}
+
 pub enum Role {
     Readonly,
     Writable,
@@ -0,0 +1,60 @@
+import { describe, it, assert } from "vitest";
+import {
+  jupsolStakePool,
+  jupsolValidatorList,
+  STAKE_ACCOUNT_RENT_EXEMPT_LAMPORTS,
+} from "./utils";
+import {
+  auditPool,
+  getStakePool,
+  getValidatorList,
+  initSyncEmbed,
+  setStakePool,
+} from "@sanctumso/spl-stake-pool";
+
+initSyncEmbed();
+
+describe("audit", () => {
+  it("audit-pool", () => {
+    const stakePoolHandle = jupsolStakePool();
+    const validatorListHandle = jupsolValidatorList();
+    const validators = getValidatorList(validatorListHandle).validators;
+
+    // fixture accounts were fetched at different times,
+    // make total lamports consistent with the validator list and an empty reserve
+    const stakePool = getStakePool(stakePoolHandle);
+    stakePool.totalLamports = validators.reduce(
+      (sum, v) => sum + v.activeStakeLamports + v.transientStakeLamports,
+      0n
+    );
+    setStakePool(stakePoolHandle, stakePool);
+
+    const args = {
+      reserveStakeLamports: STAKE_ACCOUNT_RENT_EXEMPT_LAMPORTS,
+      poolMintSupply: stakePool.poolTokenSupply,
+    };
+    assert.deepStrictEqual(
+      auditPool(stakePoolHandle, validatorListHandle, args),
+      []
+    );
+
+    const findings = auditPool(stakePoolHandle, validatorListHandle, {
+      ...args,
+      poolMintSupply: stakePool.poolTokenSupply + 1n,
+      stakeAccountsLamports: [
+        { validator: validators[0].activeStakeLamports, transient: 0n },
+        null,
+        { validator: 0n, transient: 0n },
+      ],
+    });
+    assert.equal(findings.length, 3);
+    assert.deepStrictEqual(findings[0], {
+      poolTokenSupplyMismatch: {
+        mintSupply: stakePool.poolTokenSupply + 1n,
+        poolTokenSupply: stakePool.poolTokenSupply,
+      },
+    });
+    assert.hasAllKeys(findings[1], ["stakeAccountLamportsMismatch"]);
+    assert.hasAllKeys(findings[2], ["inconsistentStakeStatus"]);
+  });
+});
@@ -0,0 +1,170 @@
+import { describe, it, assert } from "vitest";
+import { fetchStakePool, readTestFixturesKeypair } from "./utils";
+import {
+  createStakePoolIxs,
+  findAta,
+  findWithdrawAuthPda,
+  getStakePool,
+  initSyncEmbed,
+  maxValidatorsForAccountLen,
+  validatorListAccountLen,
+  validatorListRentExemptLamports,
+} from "@sanctumso/spl-stake-pool";
+import {
+  address,
+  addSignersToTransactionMessage,
+  appendTransactionMessageInstructions,
+  createSolanaRpc,
+  createSolanaRpcSubscriptions,
+  createTransactionMessage,
+  generateKeyPairSigner,
+  pipe,
+  sendAndConfirmTransactionFactory,
+  setTransactionMessageFeePayerSigner,
+  setTransactionMessageLifetimeUsingBlockhash,
+  signTransactionMessageWithSigners,
+  type IInstruction,
+  type TransactionSigner,
+} from "@solana/kit";
+
+initSyncEmbed();
+
+const PROGRAM = "SP12tWFxD9oJsVWNavTTBZvMbA6gkAmxtVgxdqvyvhY";
+const TOKEN_PROGRAM = "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA";
+
+describe("create-stake-pool", async () => {
+  // Requires a local validator running with test fixtures.
+  // Creates a brand new pool with freshly generated keypairs,
+  // so it does not depend on any pre-existing pool accounts.
+  it("create-stake-pool-local", async () => {
+    const keypair = await readTestFixturesKeypair("signer");
+    const [stakePool, validatorList, reserve, poolMint] = await Promise.all([
+      generateKeyPairSigner(),
+      generateKeyPairSigner(),
+      generateKeyPairSigner(),
+      generateKeyPairSigner(),
+    ]);
+    const maxValidators = 10;
+    const reserveLamports = 1_000_000n;
+
+    const ixs = createStakePoolIxs(
+      {
+        program: PROGRAM,
+        stakePool: stakePool.address,
+        payer: keypair.address,
+        manager: keypair.address,
+        staker: keypair.address,
+        validatorList: validatorList.address,
+        reserve: reserve.address,
+        poolMint: poolMint.address,
+        poolTokenProgram: TOKEN_PROGRAM,
+      },
+      {
+        fee: {
+          denominator: 100n,
+          numerator: 1n,
+        },
+        withdrawalFee: {
+          denominator: 100n,
+          numerator: 1n,
+        },
+        depositFee: {
+          denominator: 100n,
+          numerator: 1n,
+        },
+        referralFee: 0,
+        maxValidators,
+        poolMintDecimals: 9,
+        reserveLamports,
+      }
+    ) as unknown as IInstruction[];
+    assert.strictEqual(ixs.length, 8);
+
+    const rpcClient = createSolanaRpc("http://localhost:8899");
+    const rpcClientSubscriptions = createSolanaRpcSubscriptions(
+      "ws://localhost:8900"
+    );
+    const sendAndConfirmTx = sendAndConfirmTransactionFactory({
+      rpc: rpcClient,
+      rpcSubscriptions: rpcClientSubscriptions,
+    });
+
+    const send = async (
+      txIxs: IInstruction[],
+      signers: TransactionSigner[]
+    ) => {
+      const { value: blockhash } = await rpcClient.getLatestBlockhash().send();
+      const tx = pipe(
+        createTransactionMessage({
+          version: 0,
+        }),
+        (txm) => appendTransactionMessageInstructions(txIxs, txm),
+        (txm) => setTransactionMessageFeePayerSigner(keypair, txm),
+        (txm) => setTransactionMessageLifetimeUsingBlockhash(blockhash, txm),
+        (txm) => addSignersToTransactionMessage(signers, txm)
+      );
+      const signedTx = await signTransactionMessageWithSigners(tx);
+      await sendAndConfirmTx(signedTx, {
+        commitment: "confirmed",
+      });
+    };
+
+    // 1-4: create stake pool, validator list and reserve accounts
+    await send(ixs.slice(0, 4), [stakePool, validatorList, reserve]);
+    // 5-8: create mint and manager fee account, then Initialize
+    await send(ixs.slice(4), [poolMint]);
+
+    const pool = getStakePool(
+      await fetchStakePool(rpcClient, stakePool.address)
+    );
+    assert.strictEqual(pool.manager, keypair.address);
+    assert.strictEqual(pool.staker, keypair.address);
+    assert.strictEqual(pool.validatorList, validatorList.address);
+    assert.strictEqual(pool.reserveStake, reserve.address);
+    assert.strictEqual(pool.poolMint, poolMint.address);
+    // Initialize mints the reserve's lamports above rent 1:1 to the manager fee account
+    assert.strictEqual(pool.poolTokenSupply, reserveLamports);
+    assert.strictEqual(pool.totalLamports, reserveLamports);
+
+    const [managerFee] = findAta(
+      keypair.address,
+      TOKEN_PROGRAM,
+      poolMint.address
+    );
+    assert.strictEqual(pool.managerFeeAccount, managerFee);
+    const { value: managerFeeBalance } = await rpcClient
+      .getTokenAccountBalance(address(managerFee))
+      .send();
+    assert.strictEqual(BigInt(managerFeeBalance.amount), reserveLamports);
+
+    const { value: validatorListAcc } = await rpcClient
+      .getAccountInfo(validatorList.address, { encoding: "base64" })
+      .send();
+    assert.strictEqual(
+      BigInt(validatorListAcc!.space),
+      BigInt(validatorListAccountLen(maxValidators))
+    );
+
+    const [withdrawAuth] = findWithdrawAuthPda(PROGRAM, stakePool.address);
+    const { value: mintAcc } = await rpcClient
+      .getAccountInfo(poolMint.address, { encoding: "jsonParsed" })
+      .send();
+    assert.strictEqual(
+      (mintAcc!.data as any).parsed.info.mintAuthority,
+      withdrawAuth
+    );
+  });
+
+  it("validator-list-account-len", () => {
+    assert.strictEqual(validatorListAccountLen(1), 9 + 73);
+    assert.strictEqual(
+      maxValidatorsForAccountLen(validatorListAccountLen(10)),
+      10
+    );
+
+    // 73 * u32::MAX overflows wasm32's usize
+    const maxU32 = 2 ** 32 - 1;
+    assert.throws(() => validatorListAccountLen(maxU32));
+    assert.throws(() => validatorListRentExemptLamports(maxU32));
+  });
+});
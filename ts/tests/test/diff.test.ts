@@ -0,0 +1,73 @@
+import { describe, it, assert } from "vitest";
+import { jupsolStakePool, jupsolValidatorList, randPubkey } from "./utils";
+import {
+  getStakePool,
+  getValidatorList,
+  initSyncEmbed,
+  setStakePool,
+  setValidatorList,
+  stakePoolDiff,
+  validatorListDiff,
+} from "@sanctumso/spl-stake-pool";
+
+initSyncEmbed();
+
+describe("diff", () => {
+  it("stake-pool-diff", () => {
+    const old = jupsolStakePool();
+    assert.deepStrictEqual(stakePoolDiff(old, jupsolStakePool()), []);
+
+    const newHandle = jupsolStakePool();
+    const stakePool = getStakePool(newHandle);
+    const oldManager = stakePool.manager;
+    const oldTotalLamports = stakePool.totalLamports;
+    stakePool.manager = randPubkey();
+    stakePool.totalLamports += 1n;
+    setStakePool(newHandle, stakePool);
+
+    assert.deepStrictEqual(stakePoolDiff(old, newHandle), [
+      {
+        authority: {
+          field: "Manager",
+          old: oldManager,
+          new: stakePool.manager,
+        },
+      },
+      {
+        totalLamports: {
+          old: oldTotalLamports,
+          new: oldTotalLamports + 1n,
+        },
+      },
+    ]);
+  });
+
+  it("validator-list-diff", () => {
+    const old = jupsolValidatorList();
+    assert.deepStrictEqual(validatorListDiff(old, jupsolValidatorList()), []);
+
+    const newHandle = jupsolValidatorList();
+    const validatorList = getValidatorList(newHandle);
+    const removed = validatorList.validators.pop()!;
+    const [first] = validatorList.validators;
+    first.activeStakeLamports += 1n;
+    setValidatorList(newHandle, validatorList);
+
+    const changes = validatorListDiff(old, newHandle);
+    assert.equal(changes.length, 2);
+
+    const [removal, lamportsChange] = changes;
+    assert.equal(removal.voteAccount, removed.voteAccountAddress);
+    assert.hasAllKeys(removal.change, ["removed"]);
+
+    assert.equal(lamportsChange.voteAccount, first.voteAccountAddress);
+    const { change } = lamportsChange;
+    if (!("lamportsChanged" in change)) {
+      assert.fail(`expected lamportsChanged, got ${Object.keys(change)}`);
+    }
+    assert.equal(
+      change.lamportsChanged.newActiveStakeLamports,
+      change.lamportsChanged.oldActiveStakeLamports + 1n
+    );
+  });
+});
@@ -0,0 +1,66 @@
+import { describe, it, assert } from "vitest";
+import { readTestFixturesJsonFile } from "./utils";
+import {
+  deserStakePool,
+  getStakePool,
+  initSyncEmbed,
+  stakePoolPrefixMatches,
+  stakePoolsManagedByFilters,
+  stakePoolsStakedByFilters,
+  stakePoolsWithMintFilters,
+  type MemcmpFilter,
+} from "@sanctumso/spl-stake-pool";
+import {
+  address,
+  createSolanaRpc,
+  getBase64Encoder,
+  type Address,
+} from "@solana/kit";
+
+initSyncEmbed();
+
+/**
+ * Requires a local validator running with test fixtures.
+ */
+describe("filters-local", async () => {
+  const rpcClient = createSolanaRpc("http://localhost:8899");
+
+  const accountJson = readTestFixturesJsonFile("jupsol-stake-pool");
+  const program = address(accountJson.account.owner);
+  const bytes = new Uint8Array(
+    Buffer.from(accountJson.account.data[0], "base64")
+  );
+  const stakePool = getStakePool(deserStakePool(bytes));
+
+  async function matchingPools(filters: MemcmpFilter[]): Promise<Address[]> {
+    const accounts = await rpcClient
+      .getProgramAccounts(program, { encoding: "base64", filters })
+      .send();
+    for (const { account } of accounts) {
+      const data = new Uint8Array(getBase64Encoder().encode(account.data[0]));
+      assert.isTrue(stakePoolPrefixMatches(data, filters));
+    }
+    return accounts.map(({ pubkey }) => pubkey);
+  }
+
+  it("filters-match-fixture", async () => {
+    const filtersList = [
+      stakePoolsWithMintFilters(stakePool.poolMint),
+      stakePoolsManagedByFilters(stakePool.manager),
+      stakePoolsStakedByFilters(stakePool.staker),
+    ];
+    for (const filters of filtersList) {
+      assert.isTrue(stakePoolPrefixMatches(bytes, filters));
+      assert.deepStrictEqual(await matchingPools(filters), [
+        accountJson.pubkey,
+      ]);
+    }
+  });
+
+  it("filters-no-match", async () => {
+    // the validator list is not the mint of any stake pool
+    const filters = stakePoolsWithMintFilters(stakePool.validatorList);
+    assert.isFalse(stakePoolPrefixMatches(bytes, filters));
+    assert.deepStrictEqual(await matchingPools(filters), []);
+  });
+});
@@ -0,0 +1,63 @@
+import { describe, it, assert } from "vitest";
+import { readTestFixturesJsonFile } from "./utils";
+import {
+  deserStakePool,
+  getStakePool,
+  initSyncEmbed,
+  knownStakePoolByAddr,
+  knownStakePoolByMint,
+  knownStakePoolPrograms,
+  knownStakePools,
+} from "@sanctumso/spl-stake-pool";
+
+initSyncEmbed();
+
+describe("known-pools", () => {
+  it("known-pools-match-fixtures", () => {
+    const fixtures: Record<string, string> = {
+      jupSOL: "jupsol-stake-pool",
+      picoSOL: "picosol-stake-pool",
+      bpSOL: "bpsol-stake-pool",
+    };
+    // bpSOL's stake pool fixture is left uninitialized for the initialize test,
+    // so check its pool mint against its mint fixture instead of decoding it
+    const uninitializedPoolMints: Record<string, string> = {
+      bpSOL: "bpsol-mint",
+    };
+    const pools = knownStakePools();
+    assert.deepStrictEqual(
+      pools.map(({ symbol }) => symbol).sort(),
+      Object.keys(fixtures).sort()
+    );
+
+    for (const pool of pools) {
+      const accountJson = readTestFixturesJsonFile(fixtures[pool.symbol]);
+      assert.equal(pool.deployment.stakePool, accountJson.pubkey);
+      assert.equal(pool.deployment.program, accountJson.account.owner);
+
+      const mintFixture = uninitializedPoolMints[pool.symbol];
+      if (mintFixture === undefined) {
+        const bytes = new Uint8Array(
+          Buffer.from(accountJson.account.data[0], "base64")
+        );
+        const stakePool = getStakePool(deserStakePool(bytes));
+        assert.equal(pool.poolMint, stakePool.poolMint);
+      } else {
+        const { pubkey } = readTestFixturesJsonFile(mintFixture);
+        assert.equal(pool.poolMint, pubkey);
+      }
+      assert.include(knownStakePoolPrograms(), pool.deployment.program);
+      assert.deepStrictEqual(knownStakePoolByMint(pool.poolMint), pool);
+      assert.deepStrictEqual(
+        knownStakePoolByAddr(pool.deployment.stakePool),
+        pool
+      );
+    }
+  });
+
+  it("unknown-pool", () => {
+    const { pubkey } = readTestFixturesJsonFile("validator-list");
+    assert.isUndefined(knownStakePoolByMint(pubkey));
+    assert.isUndefined(knownStakePoolByAddr(pubkey));
+  });
+});
@@ -0,0 +1,48 @@
+import { describe, it, assert } from "vitest";
+import { jupsolValidatorList, randPubkey } from "./utils";
+import {
+  getValidatorList,
+  initSyncEmbed,
+  validatorLifecyclePlan,
+} from "@sanctumso/spl-stake-pool";
+
+initSyncEmbed();
+
+describe("lifecycle", () => {
+  it("lifecycle-plan", () => {
+    const validators = getValidatorList(jupsolValidatorList()).validators;
+    const newVote = randPubkey();
+
+    const { steps, adds } = validatorLifecyclePlan(jupsolValidatorList(), {
+      reserveStakeLamports: 1_000_000_000_000n,
+      currentEpoch: 751n,
+      targetVoteAccounts: [
+        validators[0].voteAccountAddress,
+        validators[1].voteAccountAddress,
+        newVote,
+        // repeats are only planned once
+        newVote,
+      ],
+    });
+
+    assert.deepStrictEqual(
+      steps.map(({ action }) => action),
+      ["Keep", "Keep", "Decrease"]
+    );
+    assert.equal(Number(steps[2].validatorIndex), 2);
+    assert.isTrue(steps[2].lamports > 0n);
+    assert.equal(steps[2].nextStatus, "Active");
+    assert.deepStrictEqual(adds, [
+      { voteAccount: newVote, status: "Fundable" },
+    ]);
+  });
+
+  it("lifecycle-plan-stale-list", () => {
+    const { steps } = validatorLifecyclePlan(jupsolValidatorList(), {
+      reserveStakeLamports: 0n,
+      currentEpoch: 752n,
+      targetVoteAccounts: [],
+    });
+    assert.isTrue(steps.every(({ action }) => action === "AwaitUpdate"));
+  });
+});
@@ -0,0 +1,41 @@
+import { describe, it, assert } from "vitest";
+import {
+  jupsolStakePool,
+  jupsolValidatorList,
+  STAKE_ACCOUNT_RENT_EXEMPT_LAMPORTS,
+} from "./utils";
+import {
+  getValidatorList,
+  initSyncEmbed,
+  poolHealthReport,
+} from "@sanctumso/spl-stake-pool";
+
+initSyncEmbed();
+
+describe("pool-health-report", () => {
+  it("pool-health-report", () => {
+    const validators = getValidatorList(jupsolValidatorList()).validators;
+    const totalActive = validators.reduce(
+      (sum, v) => sum + v.activeStakeLamports,
+      0n
+    );
+
+    const { report, validatorsNearMinStakeIndices } = poolHealthReport(
+      jupsolStakePool(),
+      jupsolValidatorList(),
+      {
+        reserveStakeLamports: STAKE_ACCOUNT_RENT_EXEMPT_LAMPORTS + 1000n,
+        topN: validators.length,
+      }
+    );
+    assert.equal(report.reserveLamports, 1000n);
+    assert.equal(report.withdrawSolLiquidityLamports, 1000n);
+    assert.equal(report.totalActiveStakeLamports, totalActive);
+    assert.equal(report.totalTransientStakeLamports, 0n);
+    assert.equal(report.topNShare, 1);
+    assert.isTrue(report.hhi > 1 / validators.length && report.hhi <= 1);
+    assert.equal(report.statusCounts.active, validators.length);
+    assert.equal(report.validatorsNearMinStake, 0);
+    assert.deepStrictEqual(validatorsNearMinStakeIndices, []);
+  });
+});
@@ -0,0 +1,42 @@
+import { describe, it, assert } from "vitest";
+import {
+  initSyncEmbed,
+  isStakeStatusConsistent,
+  stakeStatusTransition,
+} from "@sanctumso/spl-stake-pool";
+
+initSyncEmbed();
+
+describe("stake-status", () => {
+  it("stake-status-transition", () => {
+    assert.equal(
+      stakeStatusTransition("Active", "RemoveValidator", {
+        validator: true,
+        transient: false,
+      }),
+      "DeactivatingValidator"
+    );
+    assert.equal(
+      stakeStatusTransition("DeactivatingAll", "Update", {
+        validator: false,
+        transient: true,
+      }),
+      "DeactivatingTransient"
+    );
+    assert.isUndefined(
+      stakeStatusTransition("ReadyForRemoval", "Cleanup", {
+        validator: false,
+        transient: false,
+      })
+    );
+    assert.throws(() =>
+      stakeStatusTransition("ReadyForRemoval", "RemoveValidator", {
+        validator: false,
+        transient: false,
+      })
+    );
+    assert.isFalse(
+      isStakeStatusConsistent("Active", { validator: false, transient: true })
+    );
+  });
+});
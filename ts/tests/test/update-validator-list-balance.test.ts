@@ -4,7 +4,9 @@ import {
   deserStakePool,
   deserValidatorList,
   getStakePool,
+  getUpdateStatus,
   initSyncEmbed,
+  updateStakePoolIxsFromStakePool,
   updateValidatorListBalanceIxFromStakePool,
 } from "@sanctumso/spl-stake-pool";
 import {
@@ -86,4 +88,69 @@ describe("update-validator-list-balance", async () => {
 
     assert.strictEqual(simulation.value.err, null);
   });
+
+  it("update-stake-pool-ixs-chunks", () => {
+    const accountJson = readTestFixturesJsonFile("jupsol-stake-pool");
+    const stakePoolHandle = deserStakePool(
+      new Uint8Array(Buffer.from(accountJson.account.data[0], "base64"))
+    );
+    const validatorListJson = readTestFixturesJsonFile("validator-list");
+    const validatorListHandle = deserValidatorList(
+      new Uint8Array(Buffer.from(validatorListJson.account.data[0], "base64"))
+    );
+
+    const addrs = {
+      program: "SPMBzsVUuoHA4Jm6KunbsotaahvVikZs1JyTW6iJvbn",
+      stakePool: readTestFixturesAccPk("jupsol-stake-pool"),
+    };
+
+    // fixtures were fetched after the pool was updated for epoch 751
+    const upToDate = getUpdateStatus(
+      stakePoolHandle,
+      validatorListHandle,
+      751n,
+      2
+    );
+    assert.strictEqual(upToDate.ixs, 0);
+    assert.strictEqual(
+      updateStakePoolIxsFromStakePool(
+        addrs,
+        stakePoolHandle,
+        validatorListHandle,
+        {
+          noMerge: false,
+          currentEpoch: 751n,
+          maxValidatorsPerIx: 2,
+        }
+      ).length,
+      0
+    );
+
+    const ixs = updateStakePoolIxsFromStakePool(
+      addrs,
+      stakePoolHandle,
+      validatorListHandle,
+      {
+        noMerge: false,
+        currentEpoch: 752n,
+        maxValidatorsPerIx: 2,
+      }
+    );
+
+    // 3 validators: 2 UVLB chunks, then UpdateStakePoolBalance.
+    // No CleanupRemovedValidatorEntries since no entry is ready for removal
+    const status = getUpdateStatus(
+      stakePoolHandle,
+      validatorListHandle,
+      752n,
+      2
+    );
+    assert.strictEqual(ixs.length, status.ixs);
+    assert.strictEqual(ixs.length, 3);
+    assert.strictEqual(ixs[0].accounts.length, 7 + 2 * 2);
+    assert.deepStrictEqual(Array.from(ixs[0].data), [6, 0, 0, 0, 0, 0]);
+    assert.strictEqual(ixs[1].accounts.length, 7 + 2 * 1);
+    assert.deepStrictEqual(Array.from(ixs[1].data), [6, 2, 0, 0, 0, 0]);
+    assert.deepStrictEqual(Array.from(ixs[2].data), [7]);
+  });
 });
@@ -1,6 +1,8 @@
 import {
   deserStakePool,
+  deserValidatorList,
   type StakePoolHandle,
+  type ValidatorListHandle,
 } from "@sanctumso/spl-stake-pool";
 import {
   address,
@@ -40,6 +42,21 @@ export function readTestFixturesKeypair(
   return createKeyPairSignerFromBytes(new Uint8Array(bytes));
 }
 
+/** Mainnet-beta's rent-exempt minimum of a stake account */
+export const STAKE_ACCOUNT_RENT_EXEMPT_LAMPORTS = 2282880n;
+
+export function jupsolStakePool(): StakePoolHandle {
+  const { account } = readTestFixturesJsonFile("jupsol-stake-pool");
+  return deserStakePool(new Uint8Array(Buffer.from(account.data[0], "base64")));
+}
+
+export function jupsolValidatorList(): ValidatorListHandle {
+  const { account } = readTestFixturesJsonFile("validator-list");
+  return deserValidatorList(
+    new Uint8Array(Buffer.from(account.data[0], "base64"))
+  );
+}
+
 export function randPubkey(): Address {
   const b = crypto.getRandomValues(new Uint8Array(32));
   return getAddressDecoder().decode(b);